@@ -0,0 +1,236 @@
+use chrom::{Colour, ColourStop, ConversionCache, CssColourSpace, Gradient, Srgb};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+/// Colours spread over the whole range, from a simple random number generator
+fn colours() -> Vec<Colour> {
+    let mut seed = 1_u32;
+    (0..1024)
+        .map(|_| {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            Colour::new(seed >> 8)
+        })
+        .collect()
+}
+
+/// The simplest way to parse a hex colour, to compare the parser against
+fn parse_naive(s: &str) -> Option<Colour> {
+    let digits = s.trim().strip_prefix('#')?;
+    match digits.len() {
+        3 => {
+            let channel = |i: usize| u8::from_str_radix(&digits[i..i + 1], 16).map(|c| c * 17);
+            Some(Colour::from_rgb(
+                channel(0).ok()?,
+                channel(1).ok()?,
+                channel(2).ok()?,
+            ))
+        }
+        6 => u32::from_str_radix(digits, 16).ok().map(Colour::new),
+        _ => None,
+    }
+}
+
+fn parse(c: &mut Criterion) {
+    let long: Vec<_> = colours().iter().map(Colour::to_string).collect();
+    let short: Vec<_> = long
+        .iter()
+        .map(|s| format!("#{}{}{}", &s[1..2], &s[3..4], &s[5..6]))
+        .collect();
+
+    let mut group = c.benchmark_group("parse");
+    group.bench_function("hex", |b| {
+        b.iter(|| {
+            for s in &long {
+                black_box(black_box(s).parse::<Colour>().unwrap());
+            }
+        })
+    });
+    group.bench_function("hex naive", |b| {
+        b.iter(|| {
+            for s in &long {
+                black_box(parse_naive(black_box(s)).unwrap());
+            }
+        })
+    });
+    group.bench_function("short hex", |b| {
+        b.iter(|| {
+            for s in &short {
+                black_box(black_box(s).parse::<Colour>().unwrap());
+            }
+        })
+    });
+    group.bench_function("short hex naive", |b| {
+        b.iter(|| {
+            for s in &short {
+                black_box(parse_naive(black_box(s)).unwrap());
+            }
+        })
+    });
+    group.bench_function("color()", |b| {
+        b.iter(|| black_box("color(display-p3 0.2 0.6 0.86)").parse::<Colour>())
+    });
+    group.finish();
+}
+
+fn format(c: &mut Criterion) {
+    let colours = colours();
+
+    let mut group = c.benchmark_group("format");
+    group.bench_function("to_string", |b| {
+        b.iter(|| {
+            for colour in &colours {
+                black_box(black_box(colour).to_string());
+            }
+        })
+    });
+    group.bench_function("to_css_hex", |b| {
+        b.iter(|| {
+            for colour in &colours {
+                black_box(black_box(colour).to_css_hex());
+            }
+        })
+    });
+    group.bench_function("to_css_rgb", |b| {
+        b.iter(|| {
+            for colour in &colours {
+                black_box(black_box(colour).to_css_rgb());
+            }
+        })
+    });
+    group.bench_function("to_css_color", |b| {
+        b.iter(|| black_box(Colour::BLUE).to_css_color(CssColourSpace::DisplayP3))
+    });
+    group.finish();
+}
+
+fn convert(c: &mut Criterion) {
+    let colours = colours();
+
+    let mut group = c.benchmark_group("convert");
+    group.bench_function("to_hsl", |b| {
+        b.iter(|| {
+            for colour in &colours {
+                black_box(black_box(colour).to_hsl());
+            }
+        })
+    });
+    group.bench_function("to_lab", |b| {
+        b.iter(|| {
+            for colour in &colours {
+                black_box(black_box(colour).to_lab());
+            }
+        })
+    });
+    group.bench_function("to_oklch", |b| {
+        b.iter(|| {
+            for colour in &colours {
+                black_box(black_box(colour).to_oklch());
+            }
+        })
+    });
+    group.bench_function("from_oklch", |b| {
+        b.iter(|| Colour::from_oklch(black_box([0.7, 0.1, 240.0])))
+    });
+    group.finish();
+}
+
+fn cache(c: &mut Criterion) {
+    let palette = &colours()[..16];
+    let cache = ConversionCache::new(palette);
+    let mut frame = [Colour::BLACK; 16];
+
+    let mut group = c.benchmark_group("cache");
+    group.bench_function("fade", |b| {
+        b.iter(|| {
+            let target = Srgb(Colour::BLACK).decode();
+            for (output, &colour) in frame.iter_mut().zip(palette) {
+                *output = Srgb(colour).decode().mix(target, black_box(0.5)).encode().0;
+            }
+            black_box(&frame);
+        })
+    });
+    group.bench_function("fade cached", |b| {
+        b.iter(|| {
+            cache.fade_linear_into(Colour::BLACK, black_box(0.5), &mut frame);
+            black_box(&frame);
+        })
+    });
+    group.bench_function("nearest", |b| {
+        b.iter(|| {
+            let target = black_box(Colour::DARK_RED).to_oklab();
+            let distance = |colour: &Colour| {
+                let lab = colour.to_oklab();
+                (0..3).map(|i| (lab[i] - target[i]).powi(2)).sum::<f32>()
+            };
+            palette
+                .iter()
+                .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+        })
+    });
+    group.bench_function("nearest cached", |b| {
+        b.iter(|| cache.nearest(black_box(Colour::DARK_RED)))
+    });
+    group.finish();
+}
+
+fn gradient(c: &mut Criterion) {
+    let stops: Vec<_> = colours()[..6]
+        .iter()
+        .enumerate()
+        .map(|(i, &colour)| ColourStop::new(i as f32 / 5.0, colour))
+        .collect();
+    let gradient = Gradient::from_stops(stops).unwrap();
+    let (width, height) = (256, 256);
+    let mut frame = vec![Colour::BLACK; width * height];
+    let mut bytes = vec![0; width * height * 3];
+
+    let mut group = c.benchmark_group("gradient");
+    group.bench_function("linear at", |b| {
+        b.iter(|| {
+            let (sin, cos) = black_box(30.0f32).to_radians().sin_cos();
+            let (w, h) = (width as f32, height as f32);
+            let length = (w * sin).abs() + (h * cos).abs();
+            for (i, pixel) in frame.iter_mut().enumerate() {
+                let (x, y) = ((i % width) as f32 + 0.5, (i / width) as f32 + 0.5);
+                *pixel = gradient.at(((x - w / 2.0) * sin - (y - h / 2.0) * cos) / length + 0.5);
+            }
+            black_box(&frame);
+        })
+    });
+    group.bench_function("fill_linear", |b| {
+        b.iter(|| {
+            gradient
+                .fill_linear(&mut frame, width, height, black_box(30.0))
+                .unwrap();
+            black_box(&frame);
+        })
+    });
+    group.bench_function("fill_linear vertical", |b| {
+        b.iter(|| {
+            gradient
+                .fill_linear(&mut frame, width, height, black_box(0.0))
+                .unwrap();
+            black_box(&frame);
+        })
+    });
+    group.bench_function("fill_radial", |b| {
+        b.iter(|| {
+            gradient
+                .fill_radial(&mut frame, width, height, 128.0, 128.0, black_box(128.0))
+                .unwrap();
+            black_box(&frame);
+        })
+    });
+    group.bench_function("fill_linear_rgb24", |b| {
+        b.iter(|| {
+            gradient
+                .fill_linear_rgb24(&mut bytes, width, height, black_box(30.0))
+                .unwrap();
+            black_box(&bytes);
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse, format, convert, cache, gradient);
+criterion_main!(benches);
@@ -0,0 +1,19 @@
+#![no_main]
+
+use chrom::codec;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(colours) = codec::decode_rgb24(data) {
+        assert_eq!(data, codec::encode_rgb24(&colours));
+    }
+    if let Ok(colours) = codec::decode_rgba32(data) {
+        assert_eq!(data.len(), codec::encode_rgba32(&colours, 0).len());
+    }
+    if let Ok(colours) = codec::decode_rgb565_le(data) {
+        assert_eq!(data, codec::encode_rgb565_le(&colours));
+    }
+    if let Ok(colours) = codec::decode_rgb565_be(data) {
+        assert_eq!(data, codec::encode_rgb565_be(&colours));
+    }
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use chrom::{Colour, ParsedColour};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // Invalid UTF-8 is replaced rather than skipped, so the parser still sees
+    // the rest of the input
+    let s = String::from_utf8_lossy(data);
+
+    if let Ok(colour) = s.parse::<Colour>() {
+        assert_eq!(Ok(colour), format!("#{}", colour.hex()).parse());
+    }
+    let _ = s.parse::<ParsedColour>();
+    let _ = Colour::from_html_legacy(&s);
+});
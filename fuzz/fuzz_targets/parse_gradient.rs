@@ -0,0 +1,12 @@
+#![no_main]
+
+use chrom::Gradient;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let s = String::from_utf8_lossy(data);
+
+    if let Ok(gradient) = s.parse::<Gradient>() {
+        let _ = gradient.at(0.5);
+    }
+});
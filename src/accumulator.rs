@@ -0,0 +1,202 @@
+use crate::Colour;
+use alloc::boxed::Box;
+
+/// The number of bins in each channel of the histogram
+const BINS: usize = 16;
+
+/// A running summary of colours, for when there are too many to keep
+///
+/// The accumulator takes the same amount of memory however many colours are
+/// pushed. Accumulators can be filled on separate threads and merged.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ColourAccumulator};
+///
+/// let mut accumulator = ColourAccumulator::new();
+/// accumulator.push(Colour::BLACK);
+/// accumulator.push_slice(&[Colour::WHITE, Colour::WHITE, Colour::WHITE]);
+/// assert_eq!(4, accumulator.count());
+/// assert_eq!(Some(Colour::new(0xbfbfbf)), accumulator.mean());
+/// assert_eq!(Some(Colour::WHITE), accumulator.dominant());
+///
+/// assert_eq!(None, ColourAccumulator::new().mean());
+/// # fn assert_send<T: Send>() {}
+/// # assert_send::<ColourAccumulator>();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ColourAccumulator {
+    count: u64,
+    sums: [u64; 3],
+    linear_sums: [f64; 3],
+    histogram: Box<[u64; BINS * BINS * BINS]>,
+}
+
+impl ColourAccumulator {
+    /// Make a new accumulator with no colours
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            sums: [0; 3],
+            linear_sums: [0.0; 3],
+            histogram: Box::new([0; BINS * BINS * BINS]),
+        }
+    }
+
+    /// Get the number of colours pushed
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Add a colour
+    pub fn push(&mut self, colour: Colour) {
+        let channels = [colour.red(), colour.green(), colour.blue()];
+        let linear = colour.to_linear_f64();
+        for i in 0..3 {
+            self.sums[i] += u64::from(channels[i]);
+            self.linear_sums[i] += linear[i];
+        }
+
+        let [red, green, blue] = channels.map(|c| usize::from(c) * BINS / 256);
+        self.histogram[(red * BINS + green) * BINS + blue] += 1;
+        self.count += 1;
+    }
+
+    /// Add every colour in a slice
+    pub fn push_slice(&mut self, colours: &[Colour]) {
+        for &colour in colours {
+            self.push(colour);
+        }
+    }
+
+    /// Add every colour from another accumulator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourAccumulator};
+    ///
+    /// let colours = [Colour::RED, Colour::BLUE, Colour::GOLD, Colour::BLUE];
+    /// let (mut left, mut right) = (ColourAccumulator::new(), ColourAccumulator::new());
+    /// left.push_slice(&colours[..1]);
+    /// right.push_slice(&colours[1..]);
+    /// left.merge(right);
+    ///
+    /// let mut all = ColourAccumulator::new();
+    /// all.push_slice(&colours);
+    /// assert_eq!(all.count(), left.count());
+    /// assert_eq!(all.mean(), left.mean());
+    /// assert_eq!(all.mean_linear(), left.mean_linear());
+    /// assert_eq!(all.dominant(), left.dominant());
+    /// ```
+    pub fn merge(&mut self, other: Self) {
+        self.count += other.count;
+        for i in 0..3 {
+            self.sums[i] += other.sums[i];
+            self.linear_sums[i] += other.linear_sums[i];
+        }
+        for (bin, count) in self.histogram.iter_mut().zip(other.histogram.iter()) {
+            *bin += count;
+        }
+    }
+
+    /// Get the average of the colours channel by channel, see
+    /// [`Colour::average`]
+    ///
+    /// Returns `None` if no colours have been pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourAccumulator};
+    ///
+    /// // Colours from a simple random number generator
+    /// let mut seed = 1_u32;
+    /// let colours: Vec<_> = (0..10_000)
+    ///     .map(|_| {
+    ///         seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+    ///         Colour::new(seed >> 8)
+    ///     })
+    ///     .collect();
+    ///
+    /// let mut accumulator = ColourAccumulator::new();
+    /// for chunk in colours.chunks(64) {
+    ///     accumulator.push_slice(chunk);
+    /// }
+    /// assert_eq!(Colour::average(&colours), accumulator.mean());
+    /// ```
+    pub fn mean(&self) -> Option<Colour> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let [red, green, blue] = self
+            .sums
+            .map(|sum| ((sum + self.count / 2) / self.count) as u8);
+        Some(Colour::from_rgb(red, green, blue))
+    }
+
+    /// Get the average of the colours in linear light
+    ///
+    /// This is the colour the pixels blend into when viewed from a distance,
+    /// which is lighter than [`ColourAccumulator::mean`]. Returns `None` if no
+    /// colours have been pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourAccumulator};
+    ///
+    /// let mut accumulator = ColourAccumulator::new();
+    /// accumulator.push_slice(&[Colour::BLACK, Colour::WHITE]);
+    /// assert_eq!(Some(Colour::new(0xbcbcbc)), accumulator.mean_linear());
+    /// ```
+    pub fn mean_linear(&self) -> Option<Colour> {
+        if self.count == 0 {
+            return None;
+        }
+
+        Some(Colour::from_linear_f64(
+            self.linear_sums.map(|sum| sum / self.count as f64),
+        ))
+    }
+
+    /// Get an approximation of the most common colour
+    ///
+    /// Colours are counted in 4096 bins, 16 for each channel, and the fullest
+    /// bin is returned as a colour with each channel rounded to a multiple of
+    /// 17. Ties go to the bin with the lowest red, then green, then blue.
+    /// Returns `None` if no colours have been pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourAccumulator};
+    ///
+    /// let mut accumulator = ColourAccumulator::new();
+    /// accumulator.push_slice(&[Colour::BLUE, Colour::from_rgb(50, 150, 220), Colour::RED]);
+    /// assert_eq!(Some(Colour::new(0x3399dd)), accumulator.dominant());
+    /// ```
+    pub fn dominant(&self) -> Option<Colour> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let (bin, _) = self
+            .histogram
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|&(_, count)| count)?;
+        let [red, green, blue] =
+            [bin / (BINS * BINS), bin / BINS % BINS, bin % BINS].map(|c| (c * 17) as u8);
+        Some(Colour::from_rgb(red, green, blue))
+    }
+}
+
+impl Default for ColourAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{Colour, Rgba};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A single step of a [`ColourAdjustment`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Adjustment {
+    /// See [`Colour::lighten`]
+    Lighten(f32),
+    /// See [`Colour::darken`]
+    Darken(f32),
+    /// See [`Colour::saturate`]
+    Saturate(f32),
+    /// See [`Colour::desaturate`]
+    Desaturate(f32),
+    /// See [`Colour::rotate_hue`]
+    RotateHue(f32),
+    /// See [`Colour::mix`]
+    MixWith { colour: Colour, weight: f32 },
+    /// Set the alpha, which only changes an [`Rgba`], see
+    /// [`Adjustment::apply_rgba`]
+    SetAlpha(u8),
+}
+
+impl Adjustment {
+    /// Apply the adjustment to a colour
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Adjustment, Colour};
+    ///
+    /// assert_eq!(Colour::BLUE.darken(0.1), Adjustment::Darken(0.1).apply(Colour::BLUE));
+    /// ```
+    pub fn apply(self, colour: Colour) -> Colour {
+        match self {
+            Self::SetAlpha(_) => colour,
+            Self::Lighten(amount) => colour.lighten(amount),
+            Self::Darken(amount) => colour.darken(amount),
+            Self::Saturate(amount) => colour.saturate(amount),
+            Self::Desaturate(amount) => colour.desaturate(amount),
+            Self::RotateHue(degrees) => colour.rotate_hue(degrees),
+            Self::MixWith {
+                colour: other,
+                weight,
+            } => colour.mix(other, weight),
+        }
+    }
+
+    /// Apply the adjustment to a colour with an alpha
+    ///
+    /// Every adjustment apart from [`Adjustment::SetAlpha`] changes the colour
+    /// like [`Adjustment::apply`] and keeps the alpha.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Adjustment, Colour};
+    ///
+    /// let overlay = Colour::BLUE.with_alpha(0x80);
+    /// assert_eq!(Colour::BLUE.with_alpha(0x40), Adjustment::SetAlpha(0x40).apply_rgba(overlay));
+    /// assert_eq!(
+    ///     Colour::BLUE.darken(0.1).with_alpha(0x80),
+    ///     Adjustment::Darken(0.1).apply_rgba(overlay),
+    /// );
+    /// ```
+    pub fn apply_rgba(self, rgba: Rgba) -> Rgba {
+        match self {
+            Self::SetAlpha(alpha) => rgba.colour.with_alpha(alpha),
+            _ => self.apply(rgba.colour).with_alpha(rgba.alpha),
+        }
+    }
+}
+
+/// A list of adjustments applied to a colour in order
+///
+/// Adjustments are plain data, so with the `serde` feature they can be stored in
+/// configuration files.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ColourAdjustment};
+///
+/// let hover = ColourAdjustment::new()
+///     .darken(0.1)
+///     .desaturate(0.05)
+///     .rotate_hue(-8.0);
+///
+/// let expected = Colour::BLUE.darken(0.1).desaturate(0.05).rotate_hue(-8.0);
+/// assert_eq!(expected, hover.apply(Colour::BLUE));
+/// assert_eq!(Colour::BLUE, ColourAdjustment::new().apply(Colour::BLUE));
+/// ```
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use chrom::{Colour, ColourAdjustment};
+///
+/// let json = r#"[{"darken": 0.1}, {"mix_with": {"colour": 16777215, "weight": 0.5}}, {"set_alpha": 128}]"#;
+/// let adjustment: ColourAdjustment = serde_json::from_str(json).unwrap();
+/// assert_eq!(
+///     ColourAdjustment::new().darken(0.1).mix_with(Colour::WHITE, 0.5).set_alpha(128),
+///     adjustment,
+/// );
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct ColourAdjustment {
+    steps: Vec<Adjustment>,
+}
+
+#[cfg(feature = "alloc")]
+impl ColourAdjustment {
+    /// Make a new adjustment that leaves colours unchanged
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the steps of the adjustment
+    pub fn steps(&self) -> &[Adjustment] {
+        &self.steps
+    }
+
+    /// Add a step to the adjustment
+    pub fn then(mut self, adjustment: Adjustment) -> Self {
+        self.steps.push(adjustment);
+        self
+    }
+
+    /// Lighten the colour, see [`Colour::lighten`]
+    pub fn lighten(self, amount: f32) -> Self {
+        self.then(Adjustment::Lighten(amount))
+    }
+
+    /// Darken the colour, see [`Colour::darken`]
+    pub fn darken(self, amount: f32) -> Self {
+        self.then(Adjustment::Darken(amount))
+    }
+
+    /// Saturate the colour, see [`Colour::saturate`]
+    pub fn saturate(self, amount: f32) -> Self {
+        self.then(Adjustment::Saturate(amount))
+    }
+
+    /// Desaturate the colour, see [`Colour::desaturate`]
+    pub fn desaturate(self, amount: f32) -> Self {
+        self.then(Adjustment::Desaturate(amount))
+    }
+
+    /// Rotate the hue of the colour, see [`Colour::rotate_hue`]
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        self.then(Adjustment::RotateHue(degrees))
+    }
+
+    /// Mix another colour in, see [`Colour::mix`]
+    pub fn mix_with(self, colour: Colour, weight: f32) -> Self {
+        self.then(Adjustment::MixWith { colour, weight })
+    }
+
+    /// Set the alpha, see [`ColourAdjustment::apply_rgba`]
+    pub fn set_alpha(self, alpha: u8) -> Self {
+        self.then(Adjustment::SetAlpha(alpha))
+    }
+
+    /// Make an adjustment that applies `self` and then `other`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourAdjustment};
+    ///
+    /// let darken = ColourAdjustment::new().darken(0.1);
+    /// let rotate = ColourAdjustment::new().rotate_hue(30.0);
+    /// assert_eq!(
+    ///     rotate.apply(darken.apply(Colour::GOLD)),
+    ///     darken.compose(&rotate).apply(Colour::GOLD),
+    /// );
+    /// ```
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            steps: self.steps.iter().chain(&other.steps).copied().collect(),
+        }
+    }
+
+    /// Apply the adjustment to a colour
+    ///
+    /// Colours are opaque, so [`Adjustment::SetAlpha`] steps are skipped.
+    pub fn apply(&self, colour: Colour) -> Colour {
+        self.steps
+            .iter()
+            .fold(colour, |colour, step| step.apply(colour))
+    }
+
+    /// Apply the adjustment to a colour with an alpha
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourAdjustment, Rgba};
+    ///
+    /// let disabled = ColourAdjustment::new().desaturate(0.5).set_alpha(0x60);
+    /// assert_eq!(
+    ///     Colour::BLUE.desaturate(0.5).with_alpha(0x60),
+    ///     disabled.apply_rgba(Rgba::from(Colour::BLUE)),
+    /// );
+    /// assert_eq!(Colour::BLUE.desaturate(0.5), disabled.apply(Colour::BLUE));
+    /// ```
+    pub fn apply_rgba(&self, rgba: Rgba) -> Rgba {
+        self.steps
+            .iter()
+            .fold(rgba, |rgba, step| step.apply_rgba(rgba))
+    }
+}
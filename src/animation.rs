@@ -0,0 +1,119 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{Colour, ColourOps};
+use core::f32::consts::TAU;
+
+/// How dark [`breathe`] gets, as a proportion of the lightness of the colour
+const BREATHE_FLOOR: f32 = 0.2;
+
+/// Get how far through the current period `t_seconds` is, in the range
+/// `0.0..1.0`
+///
+/// A period which isn't positive, or a time which isn't finite, is always at
+/// the start.
+fn phase(t_seconds: f32, period: f32) -> f32 {
+    if period > 0.0 && t_seconds.is_finite() {
+        // `rem_euclid` is exact, so a whole number of periods later gives
+        // exactly the same phase
+        t_seconds.rem_euclid(period) / period
+    } else {
+        0.0
+    }
+}
+
+/// Get a smooth wave from `0.0` at the start of each period up to `1.0`
+/// halfway through and back
+fn wave(t_seconds: f32, period: f32) -> f32 {
+    (1.0 - (TAU * phase(t_seconds, period)).cos()) / 2.0
+}
+
+/// Get the colour of a "breathing" light at `t_seconds`, which fades from
+/// `base` down and back up every `period` seconds
+///
+/// The HSL lightness follows a sine wave down to a fifth of the lightness of
+/// `base` and back, keeping the hue and saturation. It never reaches black
+/// unless `base` is black. The result only depends on the arguments, so it
+/// can be called with the time of each frame at any frame rate. A `period`
+/// which isn't positive always gives `base`.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{breathe, Colour};
+///
+/// assert_eq!(Colour::BLUE, breathe(Colour::BLUE, 0.0, 2.0));
+/// assert_eq!(Colour::BLUE, breathe(Colour::BLUE, 4.0, 2.0));
+/// let dimmest = breathe(Colour::BLUE, 1.0, 2.0);
+/// assert!((dimmest.lightness() - Colour::BLUE.lightness() / 5.0).abs() < 0.01);
+/// assert_eq!(breathe(Colour::BLUE, 0.5, 2.0), breathe(Colour::BLUE, 2.5, 2.0));
+///
+/// // Even a barely lit colour doesn't go out
+/// let dim = Colour::from_rgb(1, 0, 0);
+/// assert_ne!(Colour::BLACK, breathe(dim, 1.0, 2.0));
+/// assert_eq!(Colour::BLACK, breathe(Colour::BLACK, 1.0, 2.0));
+/// ```
+pub fn breathe(base: Colour, t_seconds: f32, period: f32) -> Colour {
+    let hsl = base.to_hsl();
+    if hsl.lightness == 0.0 {
+        return base;
+    }
+
+    let scale = 1.0 - (1.0 - BREATHE_FLOOR) * wave(t_seconds, period);
+    // The largest channel is at least the lightness, so this keeps it from
+    // rounding down to 0
+    let lightness = (hsl.lightness * scale).max(1.0 / 255.0);
+    if lightness >= hsl.lightness {
+        return base;
+    }
+    Colour::from_hsl(hsl.hue, hsl.saturation, lightness)
+}
+
+/// Get the colour at `t_seconds` of a cycle through every hue, which goes
+/// round once every `period` seconds
+///
+/// The hue starts at red and goes through yellow, green, cyan, blue and
+/// magenta, with the given HSL `saturation` and `lightness`. A `period` which
+/// isn't positive always gives the red.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{rainbow_cycle, Colour};
+///
+/// assert_eq!(Colour::from_rgb(255, 0, 0), rainbow_cycle(0.0, 3.0, 1.0, 0.5));
+/// assert_eq!(Colour::from_rgb(0, 255, 0), rainbow_cycle(1.0, 3.0, 1.0, 0.5));
+/// assert_eq!(Colour::from_rgb(0, 0, 255), rainbow_cycle(2.0, 3.0, 1.0, 0.5));
+/// assert_eq!(Colour::from_rgb(255, 0, 0), rainbow_cycle(3.0, 3.0, 1.0, 0.5));
+/// ```
+pub fn rainbow_cycle(t_seconds: f32, period: f32, saturation: f32, lightness: f32) -> Colour {
+    Colour::from_hsl(360.0 * phase(t_seconds, period), saturation, lightness)
+}
+
+/// Get the colour at `t_seconds` of a pulse from `a` to `b` and back every
+/// `period` seconds
+///
+/// The colours are mixed with [`ColourOps::lerp`], with the weight of `b`
+/// following a sine wave, so the pulse is at `a` at the start of each period
+/// and at `b` halfway through. A `period` which isn't positive always gives
+/// `a`.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{pulse_between, Colour, LinearRgb};
+///
+/// assert_eq!(Colour::RED, pulse_between(Colour::RED, Colour::GOLD, 0.0, 1.0));
+/// assert_eq!(Colour::GOLD, pulse_between(Colour::RED, Colour::GOLD, 0.5, 1.0));
+/// assert_eq!(
+///     Colour::RED.mix(Colour::GOLD, 0.5),
+///     pulse_between(Colour::RED, Colour::GOLD, 0.25, 1.0),
+/// );
+/// assert_eq!(Colour::RED, pulse_between(Colour::RED, Colour::GOLD, 0.5, 0.0));
+///
+/// // Any colour type can pulse
+/// let [black, white] = [LinearRgb([0.0; 3]), LinearRgb([1.0; 3])];
+/// assert_eq!(LinearRgb([0.5; 3]), pulse_between(black, white, 0.25, 1.0));
+/// ```
+pub fn pulse_between<C: ColourOps>(a: C, b: C, t_seconds: f32, period: f32) -> C {
+    a.lerp(b, wave(t_seconds, period))
+}
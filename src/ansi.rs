@@ -0,0 +1,70 @@
+use crate::Colour;
+use std::fmt;
+
+/// Text painted with colours for a terminal, made by [`Colour::paint`]
+///
+/// It displays as the text between ANSI escape sequences setting the colours
+/// and resetting them to the defaults, using 24-bit colour.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+///
+/// let warning = Colour::BLACK.paint("warning").on(Colour::GOLD);
+/// assert_eq!(
+///     "\x1b[38;2;0;0;0m\x1b[48;2;241;196;15mwarning\x1b[49m\x1b[39m",
+///     warning.to_string(),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Painted<T> {
+    text: T,
+    foreground: Colour,
+    background: Option<Colour>,
+}
+
+impl<T> Painted<T> {
+    /// Paint the background of the text too
+    pub fn on(self, background: Colour) -> Self {
+        Self {
+            background: Some(background),
+            ..self
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Painted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.foreground.write_ansi_fg(f)?;
+        if let Some(background) = self.background {
+            background.write_ansi_bg(f)?;
+        }
+        self.text.fmt(f)?;
+        if self.background.is_some() {
+            f.write_str("\x1b[49m")?;
+        }
+        f.write_str("\x1b[39m")
+    }
+}
+
+impl Colour {
+    /// Paint text in the colour for a terminal, see [`Painted`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let line = format!("{} done", Colour::GREEN.paint("✓"));
+    /// assert_eq!("\x1b[38;2;87;242;135m✓\x1b[39m done", line);
+    /// assert_eq!("\x1b[38;2;52;152;219m42\x1b[39m", Colour::BLUE.paint(42).to_string());
+    /// ```
+    pub fn paint<T>(self, text: T) -> Painted<T> {
+        Painted {
+            text,
+            foreground: self,
+            background: None,
+        }
+    }
+}
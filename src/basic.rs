@@ -0,0 +1,133 @@
+use crate::Colour;
+use core::fmt;
+
+/// One of the eleven basic colour terms of English, used by
+/// [`Colour::classify`]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum BasicColour {
+    Black,
+    White,
+    Grey,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+    Pink,
+    Brown,
+}
+
+impl BasicColour {
+    /// Get a typical colour for the term, for displaying it
+    ///
+    /// This is the CSS colour of the same name, except for brown which is CSS
+    /// `saddlebrown`, as CSS `brown` is a dark red.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{BasicColour, Colour};
+    ///
+    /// assert_eq!(Colour::new(0xffa500), BasicColour::Orange.representative());
+    /// assert_eq!(Colour::new(0x8b4513), BasicColour::Brown.representative());
+    /// ```
+    pub const fn representative(self) -> Colour {
+        Colour::new(match self {
+            Self::Black => 0x000000,
+            Self::White => 0xffffff,
+            Self::Grey => 0x808080,
+            Self::Red => 0xff0000,
+            Self::Orange => 0xffa500,
+            Self::Yellow => 0xffff00,
+            Self::Green => 0x008000,
+            Self::Blue => 0x0000ff,
+            Self::Purple => 0x800080,
+            Self::Pink => 0xffc0cb,
+            Self::Brown => 0x8b4513,
+        })
+    }
+}
+
+impl fmt::Display for BasicColour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Black => "black",
+            Self::White => "white",
+            Self::Grey => "grey",
+            Self::Red => "red",
+            Self::Orange => "orange",
+            Self::Yellow => "yellow",
+            Self::Green => "green",
+            Self::Blue => "blue",
+            Self::Purple => "purple",
+            Self::Pink => "pink",
+            Self::Brown => "brown",
+        })
+    }
+}
+
+impl Colour {
+    /// Get the basic colour term which best fits the colour
+    ///
+    /// Colours with a chroma below `0.08` or a saturation below `0.15` are
+    /// black, grey or white by lightness, as are colours with a lightness below
+    /// `0.08` or above `0.95`. Otherwise the hue picks the term, and lightness
+    /// and saturation split off the terms which share a hue: light reds are
+    /// pink, light magentas are pink and dark ones purple, and dark or
+    /// desaturated oranges and dark yellows are brown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{BasicColour, Colour};
+    ///
+    /// assert_eq!(BasicColour::Blue, Colour::BLUE.classify());
+    /// assert_eq!(BasicColour::Brown, Colour::new(0xa0522d).classify());
+    /// assert_eq!(BasicColour::Pink, Colour::new(0xff69b4).classify());
+    /// ```
+    pub fn classify(self) -> BasicColour {
+        let hsl = self.to_hsl();
+        let chroma = f32::from(self.max_channel().1 - self.min_channel().1) / 255.0;
+
+        if chroma < 0.08 || hsl.saturation < 0.15 || hsl.lightness < 0.08 || hsl.lightness > 0.95 {
+            return if hsl.lightness < 0.15 {
+                BasicColour::Black
+            } else if hsl.lightness > 0.9 {
+                BasicColour::White
+            } else {
+                BasicColour::Grey
+            };
+        }
+
+        match hsl.hue {
+            hue if !(15.0..345.0).contains(&hue) => {
+                if hsl.lightness > 0.7 {
+                    BasicColour::Pink
+                } else {
+                    BasicColour::Red
+                }
+            }
+            hue if hue < 45.0 => {
+                if (hsl.lightness < 0.45 && hsl.saturation < 0.9)
+                    || (hsl.saturation < 0.6 && hsl.lightness < 0.75)
+                {
+                    BasicColour::Brown
+                } else {
+                    BasicColour::Orange
+                }
+            }
+            hue if hue < 65.0 => {
+                if hsl.lightness < 0.3 {
+                    BasicColour::Brown
+                } else {
+                    BasicColour::Yellow
+                }
+            }
+            hue if hue < 170.0 => BasicColour::Green,
+            hue if hue < 260.0 => BasicColour::Blue,
+            hue if hue < 285.0 || hsl.lightness < 0.5 => BasicColour::Purple,
+            _ => BasicColour::Pink,
+        }
+    }
+}
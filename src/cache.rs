@@ -0,0 +1,208 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{Colour, LinearRgb};
+use alloc::vec::Vec;
+
+/// A palette with its colours converted to linear light and Oklab ahead of
+/// time
+///
+/// This is for when the same colours are converted over and over again, like
+/// when rendering frames. Lookups and mixes use the stored values, and give
+/// exactly the same results as converting each time.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ConversionCache, LinearRgb};
+///
+/// let palette = [Colour::RED, Colour::GOLD, Colour::BLUE];
+/// let mut cache = ConversionCache::new(&palette);
+/// assert_eq!(3, cache.len());
+/// assert_eq!(Colour::GOLD, cache.colour(1));
+/// assert_eq!(LinearRgb(Colour::BLUE.to_linear()), cache.linear(2));
+/// assert_eq!(Colour::BLUE.to_oklab(), cache.oklab(2));
+///
+/// cache.rebuild(&[Colour::WHITE]);
+/// assert_eq!(1, cache.len());
+/// assert_eq!(Colour::WHITE.to_oklab(), cache.oklab(0));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConversionCache {
+    colours: Vec<Colour>,
+    linear: Vec<LinearRgb>,
+    oklab: Vec<[f32; 3]>,
+}
+
+impl ConversionCache {
+    /// Convert every colour in a palette
+    pub fn new(palette: &[Colour]) -> Self {
+        let mut cache = Self::default();
+        cache.rebuild(palette);
+        cache
+    }
+
+    /// Replace the palette, converting every colour in the new one
+    ///
+    /// The existing allocations are reused.
+    pub fn rebuild(&mut self, palette: &[Colour]) {
+        self.colours.clear();
+        self.linear.clear();
+        self.oklab.clear();
+        for &colour in palette {
+            let linear = colour.to_linear();
+            self.colours.push(colour);
+            self.linear.push(LinearRgb(linear));
+            self.oklab.push(crate::oklab::linear_to_oklab(linear));
+        }
+    }
+
+    /// Get the number of colours in the palette
+    pub fn len(&self) -> usize {
+        self.colours.len()
+    }
+
+    /// Check if the palette has no colours
+    pub fn is_empty(&self) -> bool {
+        self.colours.is_empty()
+    }
+
+    /// Get the colours in the palette
+    pub fn colours(&self) -> &[Colour] {
+        &self.colours
+    }
+
+    /// Get a colour in the palette
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn colour(&self, index: usize) -> Colour {
+        self.colours[index]
+    }
+
+    /// Get a colour in the palette in linear light, see [`Colour::to_linear`]
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn linear(&self, index: usize) -> LinearRgb {
+        self.linear[index]
+    }
+
+    /// Get the Oklab components of a colour in the palette, see
+    /// [`Colour::to_oklab`]
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn oklab(&self, index: usize) -> [f32; 3] {
+        self.oklab[index]
+    }
+
+    /// Mix two colours in the palette in linear light, see [`LinearRgb::mix`]
+    ///
+    /// # Panics
+    ///
+    /// If either index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ConversionCache, Srgb};
+    ///
+    /// let cache = ConversionCache::new(&[Colour::RED, Colour::BLUE]);
+    /// let direct = Srgb(Colour::RED).decode().mix(Srgb(Colour::BLUE).decode(), 0.3).encode();
+    /// assert_eq!(direct.0, cache.mix_linear(0, 1, 0.3));
+    /// ```
+    pub fn mix_linear(&self, a: usize, b: usize, t: f32) -> Colour {
+        self.linear[a].mix(self.linear[b], t).encode().0
+    }
+
+    /// Mix two colours in the palette in Oklab
+    ///
+    /// `t` is the proportion of the second colour in the result. Mixing in
+    /// Oklab keeps the lightness changing evenly, without the dark or grey
+    /// middle of mixing in sRGB.
+    ///
+    /// # Panics
+    ///
+    /// If either index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ConversionCache};
+    ///
+    /// let cache = ConversionCache::new(&[Colour::BLACK, Colour::WHITE]);
+    /// assert_eq!(Colour::BLACK, cache.mix_oklab(0, 1, 0.0));
+    /// assert_eq!(Colour::WHITE, cache.mix_oklab(0, 1, 1.0));
+    /// let grey = cache.mix_oklab(0, 1, 0.5);
+    /// assert!((grey.to_oklab()[0] - 0.5).abs() < 5e-3);
+    /// ```
+    pub fn mix_oklab(&self, a: usize, b: usize, t: f32) -> Colour {
+        let (a, b) = (self.oklab[a], self.oklab[b]);
+        Colour::from_oklab([0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * t))
+    }
+
+    /// Mix every colour in the palette with another colour in linear light,
+    /// writing the results into `output`
+    ///
+    /// This is for fading a whole palette, like dimming LEDs towards black.
+    ///
+    /// # Panics
+    ///
+    /// If `output` has a different length to the palette.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ConversionCache, Srgb};
+    ///
+    /// let palette = [Colour::RED, Colour::GOLD, Colour::BLUE];
+    /// let cache = ConversionCache::new(&palette);
+    /// let mut frame = [Colour::BLACK; 3];
+    /// cache.fade_linear_into(Colour::BLACK, 0.5, &mut frame);
+    ///
+    /// let black = Srgb(Colour::BLACK).decode();
+    /// for (colour, faded) in palette.iter().zip(frame) {
+    ///     assert_eq!(Srgb(*colour).decode().mix(black, 0.5).encode().0, faded);
+    /// }
+    /// ```
+    pub fn fade_linear_into(&self, target: Colour, t: f32, output: &mut [Colour]) {
+        assert_eq!(
+            self.len(),
+            output.len(),
+            "output length doesn't match the palette"
+        );
+
+        let target = LinearRgb(target.to_linear());
+        for (output, linear) in output.iter_mut().zip(&self.linear) {
+            *output = linear.mix(target, t).encode().0;
+        }
+    }
+
+    /// Get the index of the colour in the palette closest to `colour`, by
+    /// distance in Oklab
+    ///
+    /// Returns `None` if the palette is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ConversionCache};
+    ///
+    /// let cache = ConversionCache::new(&[Colour::RED, Colour::GOLD, Colour::BLUE]);
+    /// assert_eq!(Some(0), cache.nearest(Colour::DARK_RED));
+    /// assert_eq!(Some(2), cache.nearest(Colour::NAVY));
+    /// assert_eq!(None, ConversionCache::new(&[]).nearest(Colour::RED));
+    /// ```
+    pub fn nearest(&self, colour: Colour) -> Option<usize> {
+        let target = colour.to_oklab();
+        let distance = |lab: &[f32; 3]| (0..3).map(|i| (lab[i] - target[i]).powi(2)).sum::<f32>();
+        self.oklab
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)))
+            .map(|(i, _)| i)
+    }
+}
@@ -0,0 +1,459 @@
+use crate::Colour;
+
+/// One of the RGB channels of a colour
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Channel {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Colour {
+    pub const RED_MASK: u32 = 0xff0000;
+    pub const GREEN_MASK: u32 = 0x00ff00;
+    pub const BLUE_MASK: u32 = 0x0000ff;
+
+    /// Get a channel of the colour
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Channel, Colour};
+    ///
+    /// assert_eq!(Colour::BLUE.green(), Colour::BLUE.channel(Channel::Green));
+    /// ```
+    pub const fn channel(self, channel: Channel) -> u8 {
+        match channel {
+            Channel::Red => self.red(),
+            Channel::Green => self.green(),
+            Channel::Blue => self.blue(),
+        }
+    }
+
+    /// Get the colour with its red channel replaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0xff98db), Colour::new(0x3498db).with_red(0xff));
+    /// ```
+    pub const fn with_red(self, red: u8) -> Self {
+        Self::from_rgb(red, self.green(), self.blue())
+    }
+
+    /// Get the colour with its green channel replaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x34ffdb), Colour::new(0x3498db).with_green(0xff));
+    /// ```
+    pub const fn with_green(self, green: u8) -> Self {
+        Self::from_rgb(self.red(), green, self.blue())
+    }
+
+    /// Get the colour with its blue channel replaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x3498ff), Colour::new(0x3498db).with_blue(0xff));
+    /// ```
+    pub const fn with_blue(self, blue: u8) -> Self {
+        Self::from_rgb(self.red(), self.green(), blue)
+    }
+
+    /// Get `width` bits of the colour value, starting `offset` bits from the
+    /// least significant bit
+    ///
+    /// Bits 16 to 23 are the red channel, 8 to 15 green and 0 to 7 blue.
+    ///
+    /// # Panics
+    ///
+    /// If `offset + width` is more than 24, which is a compile error in a
+    /// `const` context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let colour = Colour::new(0x3498db);
+    /// assert_eq!(0x98, colour.get_bits(8, 8));
+    /// assert_eq!(0x3, colour.get_bits(20, 4));
+    /// assert_eq!(1, colour.get_bits(0, 1));
+    /// assert_eq!(0, colour.get_bits(23, 1));
+    /// assert_eq!(0x3498db, colour.get_bits(0, 24));
+    /// assert_eq!(0, colour.get_bits(24, 0));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// const BITS: u32 = chrom::Colour::BLUE.get_bits(20, 8);
+    /// ```
+    pub const fn get_bits(self, offset: u8, width: u8) -> u32 {
+        let mask = Self::bit_mask(offset, width);
+        (self.0 >> offset) & mask
+    }
+
+    /// Get the colour with `width` bits, starting `offset` bits from the least
+    /// significant bit, replaced with the lowest bits of `value`
+    ///
+    /// Bits of `value` past `width` are ignored, so the result always fits in
+    /// 24 bits. See [`Colour::get_bits`] for where the channels are.
+    ///
+    /// # Panics
+    ///
+    /// If `offset + width` is more than 24, which is a compile error in a
+    /// `const` context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let colour = Colour::new(0x3498db);
+    /// assert_eq!(Colour::new(0x3400db), colour.with_bits(8, 8, 0));
+    /// assert_eq!(Colour::new(0xf498db), colour.with_bits(20, 4, 0xf));
+    /// assert_eq!(Colour::new(0x3498da), colour.with_bits(0, 1, 0));
+    /// assert_eq!(Colour::new(0xb498db), colour.with_bits(23, 1, 1));
+    /// assert_eq!(Colour::new(0x3498d5), colour.with_bits(0, 4, 0x12345));
+    /// assert_eq!(colour, colour.with_bits(24, 0, 1));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// const COLOUR: chrom::Colour = chrom::Colour::BLUE.with_bits(23, 2, 0);
+    /// ```
+    pub const fn with_bits(self, offset: u8, width: u8, value: u32) -> Self {
+        let mask = Self::bit_mask(offset, width);
+        Self((self.0 & !(mask << offset)) | ((value & mask) << offset))
+    }
+
+    /// Get a mask of the lowest `width` bits, checking they fit in 24 bits
+    /// when shifted by `offset`
+    const fn bit_mask(offset: u8, width: u8) -> u32 {
+        assert!(
+            offset as u32 + width as u32 <= 24,
+            "bits past the 24 bits of a colour"
+        );
+        (1 << width) - 1
+    }
+
+    /// Get the high 4 bits of the red channel
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(0x3, Colour::new(0x3498db).high_nibble_of_red());
+    /// ```
+    pub const fn high_nibble_of_red(self) -> u8 {
+        self.red() >> 4
+    }
+
+    /// Get the low 4 bits of the red channel
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(0x4, Colour::new(0x3498db).low_nibble_of_red());
+    /// ```
+    pub const fn low_nibble_of_red(self) -> u8 {
+        self.red() & 0xf
+    }
+
+    /// Get the high 4 bits of the green channel
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(0x9, Colour::new(0x3498db).high_nibble_of_green());
+    /// ```
+    pub const fn high_nibble_of_green(self) -> u8 {
+        self.green() >> 4
+    }
+
+    /// Get the low 4 bits of the green channel
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(0x8, Colour::new(0x3498db).low_nibble_of_green());
+    /// ```
+    pub const fn low_nibble_of_green(self) -> u8 {
+        self.green() & 0xf
+    }
+
+    /// Get the high 4 bits of the blue channel
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(0xd, Colour::new(0x3498db).high_nibble_of_blue());
+    /// ```
+    pub const fn high_nibble_of_blue(self) -> u8 {
+        self.blue() >> 4
+    }
+
+    /// Get the low 4 bits of the blue channel
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(0xb, Colour::new(0x3498db).low_nibble_of_blue());
+    /// ```
+    pub const fn low_nibble_of_blue(self) -> u8 {
+        self.blue() & 0xf
+    }
+
+    /// Get the colour with only its red channel
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x340000), Colour::new(0x3498db).only_red());
+    /// ```
+    pub const fn only_red(self) -> Self {
+        Self(self.0 & Self::RED_MASK)
+    }
+
+    /// Get the colour with only its green channel
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x009800), Colour::new(0x3498db).only_green());
+    /// ```
+    pub const fn only_green(self) -> Self {
+        Self(self.0 & Self::GREEN_MASK)
+    }
+
+    /// Get the colour with only its blue channel
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x0000db), Colour::new(0x3498db).only_blue());
+    /// ```
+    pub const fn only_blue(self) -> Self {
+        Self(self.0 & Self::BLUE_MASK)
+    }
+
+    /// Get the colour with every channel inverted, like a photo negative
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0xcb6724), Colour::new(0x3498db).invert());
+    /// assert_eq!(Colour::WHITE, Colour::BLACK.invert());
+    /// ```
+    pub const fn invert(self) -> Self {
+        Self(self.0 ^ 0xffffff)
+    }
+
+    /// Add `amount` to every channel, saturating at 255
+    ///
+    /// This is a cheap integer alternative to [`Colour::lighten`], but it
+    /// shifts the hue of saturated colours.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x3ea2e5), Colour::new(0x3498db).brighten_by(10));
+    /// assert_eq!(Colour::new(0xffff0a), Colour::new(0xfaf500).brighten_by(10));
+    /// assert_eq!(Colour::WHITE, Colour::WHITE.brighten_by(1));
+    /// assert_eq!(Colour::WHITE, Colour::BLACK.brighten_by(255));
+    /// ```
+    pub const fn brighten_by(self, amount: u8) -> Self {
+        Self::from_rgb(
+            self.red().saturating_add(amount),
+            self.green().saturating_add(amount),
+            self.blue().saturating_add(amount),
+        )
+    }
+
+    /// Subtract `amount` from every channel, saturating at 0
+    ///
+    /// This is a cheap integer alternative to [`Colour::darken`], but it
+    /// shifts the hue of saturated colours.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x2a8ed1), Colour::new(0x3498db).dim_by(10));
+    /// assert_eq!(Colour::new(0x0000f5), Colour::new(0x0500ff).dim_by(10));
+    /// assert_eq!(Colour::BLACK, Colour::BLACK.dim_by(200));
+    /// assert_eq!(Colour::BLACK, Colour::WHITE.dim_by(255));
+    /// ```
+    pub const fn dim_by(self, amount: u8) -> Self {
+        Self::from_rgb(
+            self.red().saturating_sub(amount),
+            self.green().saturating_sub(amount),
+            self.blue().saturating_sub(amount),
+        )
+    }
+
+    /// Add `amount` to every channel, or `None` if any channel would go over
+    /// 255
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Some(Colour::new(0x3ea2e5)), Colour::new(0x3498db).checked_brighten_by(10));
+    /// assert_eq!(Some(Colour::WHITE), Colour::new(0xfefefe).checked_brighten_by(1));
+    /// assert_eq!(None, Colour::new(0xfefeff).checked_brighten_by(1));
+    /// assert_eq!(Some(Colour::WHITE), Colour::WHITE.checked_brighten_by(0));
+    /// ```
+    pub const fn checked_brighten_by(self, amount: u8) -> Option<Self> {
+        match (
+            self.red().checked_add(amount),
+            self.green().checked_add(amount),
+            self.blue().checked_add(amount),
+        ) {
+            (Some(red), Some(green), Some(blue)) => Some(Self::from_rgb(red, green, blue)),
+            _ => None,
+        }
+    }
+
+    /// Subtract `amount` from every channel, or `None` if any channel would go
+    /// under 0
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Some(Colour::new(0x2a8ed1)), Colour::new(0x3498db).checked_dim_by(10));
+    /// assert_eq!(Some(Colour::BLACK), Colour::new(0x010101).checked_dim_by(1));
+    /// assert_eq!(None, Colour::new(0x010100).checked_dim_by(1));
+    /// assert_eq!(Some(Colour::BLACK), Colour::BLACK.checked_dim_by(0));
+    /// ```
+    pub const fn checked_dim_by(self, amount: u8) -> Option<Self> {
+        match (
+            self.red().checked_sub(amount),
+            self.green().checked_sub(amount),
+            self.blue().checked_sub(amount),
+        ) {
+            (Some(red), Some(green), Some(blue)) => Some(Self::from_rgb(red, green, blue)),
+            _ => None,
+        }
+    }
+
+    /// Add `amount` to every channel, wrapping around past 255
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x3ea2e5), Colour::new(0x3498db).wrapping_brighten_by(10));
+    /// assert_eq!(Colour::BLACK, Colour::WHITE.wrapping_brighten_by(1));
+    /// assert_eq!(Colour::new(0x0904ff), Colour::new(0xfaf5f0).wrapping_brighten_by(15));
+    /// ```
+    pub const fn wrapping_brighten_by(self, amount: u8) -> Self {
+        Self::from_rgb(
+            self.red().wrapping_add(amount),
+            self.green().wrapping_add(amount),
+            self.blue().wrapping_add(amount),
+        )
+    }
+
+    /// Subtract `amount` from every channel, wrapping around past 0
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x2a8ed1), Colour::new(0x3498db).wrapping_dim_by(10));
+    /// assert_eq!(Colour::WHITE, Colour::BLACK.wrapping_dim_by(1));
+    /// assert_eq!(Colour::new(0xfb000a), Colour::new(0x0a0f19).wrapping_dim_by(15));
+    /// ```
+    pub const fn wrapping_dim_by(self, amount: u8) -> Self {
+        Self::from_rgb(
+            self.red().wrapping_sub(amount),
+            self.green().wrapping_sub(amount),
+            self.blue().wrapping_sub(amount),
+        )
+    }
+
+    /// Get the channel with the largest value
+    ///
+    /// Ties are broken in RGB order, so red wins over green and green over blue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Channel, Colour};
+    ///
+    /// assert_eq!((Channel::Blue, 219), Colour::BLUE.max_channel());
+    /// assert_eq!((Channel::Red, 255), Colour::from_rgb(255, 255, 0).max_channel());
+    /// assert_eq!((Channel::Green, 255), Colour::from_rgb(0, 255, 255).max_channel());
+    /// assert_eq!((Channel::Red, 0), Colour::BLACK.max_channel());
+    /// ```
+    pub const fn max_channel(self) -> (Channel, u8) {
+        let (red, green, blue) = (self.red(), self.green(), self.blue());
+        if red >= green && red >= blue {
+            (Channel::Red, red)
+        } else if green >= blue {
+            (Channel::Green, green)
+        } else {
+            (Channel::Blue, blue)
+        }
+    }
+
+    /// Get the channel with the smallest value
+    ///
+    /// Ties are broken in RGB order, so red wins over green and green over blue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Channel, Colour};
+    ///
+    /// assert_eq!((Channel::Red, 52), Colour::BLUE.min_channel());
+    /// assert_eq!((Channel::Green, 0), Colour::from_rgb(255, 0, 0).min_channel());
+    /// assert_eq!((Channel::Red, 0), Colour::from_rgb(0, 0, 255).min_channel());
+    /// assert_eq!((Channel::Red, 255), Colour::WHITE.min_channel());
+    /// ```
+    pub const fn min_channel(self) -> (Channel, u8) {
+        let (red, green, blue) = (self.red(), self.green(), self.blue());
+        if red <= green && red <= blue {
+            (Channel::Red, red)
+        } else if green <= blue {
+            (Channel::Green, green)
+        } else {
+            (Channel::Blue, blue)
+        }
+    }
+}
@@ -0,0 +1,325 @@
+//! Packing colours into byte buffers
+//!
+//! Every format has an allocating encoder, an `_into` encoder which writes
+//! into a buffer and returns the number of bytes written, and a decoder.
+//!
+//! # Examples
+//!
+//! ```
+//! use chrom::{codec, Colour};
+//!
+//! let colours = [Colour::BLUE, Colour::WHITE];
+//! let bytes = codec::encode_rgb24(&colours);
+//! assert_eq!([0x34, 0x98, 0xdb, 0xff, 0xff, 0xff], bytes[..]);
+//! assert_eq!(Ok(colours.to_vec()), codec::decode_rgb24(&bytes));
+//! ```
+
+use crate::Colour;
+use alloc::vec::Vec;
+use core::{error, fmt};
+
+/// An error returned when colours can't be encoded or decoded
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CodecError {
+    /// The input length isn't a multiple of the number of bytes per colour
+    InvalidLength { length: usize, stride: usize },
+    /// The output buffer is too small to fit every colour
+    BufferTooSmall { needed: usize, available: usize },
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { length, stride } => {
+                write!(f, "input of {length} bytes isn't a multiple of {stride}")
+            }
+            Self::BufferTooSmall { needed, available } => {
+                write!(
+                    f,
+                    "buffer of {available} bytes is too small, needed {needed}"
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for CodecError {}
+
+/// Encode colours into `buffer` with `encode` writing `STRIDE` bytes for each
+fn encode_into<const STRIDE: usize>(
+    colours: &[Colour],
+    buffer: &mut [u8],
+    encode: impl Fn(Colour) -> [u8; STRIDE],
+) -> Result<usize, CodecError> {
+    let (needed, available) = (colours.len() * STRIDE, buffer.len());
+    let buffer = buffer
+        .get_mut(..needed)
+        .ok_or(CodecError::BufferTooSmall { needed, available })?;
+    for (bytes, &colour) in buffer.chunks_exact_mut(STRIDE).zip(colours) {
+        bytes.copy_from_slice(&encode(colour));
+    }
+    Ok(needed)
+}
+
+fn encode<const STRIDE: usize>(
+    colours: &[Colour],
+    encode: impl Fn(Colour) -> [u8; STRIDE],
+) -> Vec<u8> {
+    colours.iter().flat_map(|&colour| encode(colour)).collect()
+}
+
+fn decode<const STRIDE: usize>(
+    bytes: &[u8],
+    decode: impl Fn([u8; STRIDE]) -> Colour,
+) -> Result<Vec<Colour>, CodecError> {
+    if !bytes.len().is_multiple_of(STRIDE) {
+        return Err(CodecError::InvalidLength {
+            length: bytes.len(),
+            stride: STRIDE,
+        });
+    }
+
+    Ok(bytes
+        .chunks_exact(STRIDE)
+        .map(|chunk| decode(chunk.try_into().unwrap()))
+        .collect())
+}
+
+pub(crate) fn to_rgb24(colour: Colour) -> [u8; 3] {
+    [colour.red(), colour.green(), colour.blue()]
+}
+
+fn from_rgb24([red, green, blue]: [u8; 3]) -> Colour {
+    Colour::from_rgb(red, green, blue)
+}
+
+fn to_rgb565(colour: Colour) -> u16 {
+    let [red, green, blue] = to_rgb24(colour).map(u16::from);
+    (red >> 3) << 11 | (green >> 2) << 5 | blue >> 3
+}
+
+fn from_rgb565(value: u16) -> Colour {
+    let red = (value >> 11) as u8;
+    let green = (value >> 5 & 0x3f) as u8;
+    let blue = (value & 0x1f) as u8;
+    Colour::from_rgb(
+        red << 3 | red >> 2,
+        green << 2 | green >> 4,
+        blue << 3 | blue >> 2,
+    )
+}
+
+/// Encode colours as 3 bytes each, in RGB order
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{codec, Colour};
+///
+/// assert_eq!(vec![0x34, 0x98, 0xdb], codec::encode_rgb24(&[Colour::BLUE]));
+/// assert!(codec::encode_rgb24(&[]).is_empty());
+/// ```
+pub fn encode_rgb24(colours: &[Colour]) -> Vec<u8> {
+    encode(colours, to_rgb24)
+}
+
+/// Encode colours as 3 bytes each into `buffer`, see [`encode_rgb24`]
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{codec::{self, CodecError}, Colour};
+///
+/// let mut buffer = [0; 8];
+/// assert_eq!(Ok(6), codec::encode_rgb24_into(&[Colour::BLUE, Colour::WHITE], &mut buffer));
+/// assert_eq!([0x34, 0x98, 0xdb, 0xff, 0xff, 0xff, 0, 0], buffer);
+/// assert_eq!(Ok(0), codec::encode_rgb24_into(&[], &mut []));
+/// assert_eq!(
+///     Err(CodecError::BufferTooSmall { needed: 9, available: 8 }),
+///     codec::encode_rgb24_into(&[Colour::BLUE; 3], &mut buffer),
+/// );
+/// ```
+pub fn encode_rgb24_into(colours: &[Colour], buffer: &mut [u8]) -> Result<usize, CodecError> {
+    encode_into(colours, buffer, to_rgb24)
+}
+
+/// Decode colours stored as 3 bytes each, in RGB order
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{codec::{self, CodecError}, Colour};
+///
+/// assert_eq!(Ok(vec![Colour::BLUE]), codec::decode_rgb24(&[0x34, 0x98, 0xdb]));
+/// assert_eq!(Ok(vec![]), codec::decode_rgb24(&[]));
+/// assert_eq!(
+///     Err(CodecError::InvalidLength { length: 4, stride: 3 }),
+///     codec::decode_rgb24(&[0x34, 0x98, 0xdb, 0xff]),
+/// );
+/// ```
+pub fn decode_rgb24(bytes: &[u8]) -> Result<Vec<Colour>, CodecError> {
+    decode(bytes, from_rgb24)
+}
+
+/// Encode colours as 4 bytes each, in RGBA order with the same `alpha` for
+/// every colour
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{codec, Colour};
+///
+/// assert_eq!(vec![0x34, 0x98, 0xdb, 0xff], codec::encode_rgba32(&[Colour::BLUE], 0xff));
+/// assert!(codec::encode_rgba32(&[], 0xff).is_empty());
+/// ```
+pub fn encode_rgba32(colours: &[Colour], alpha: u8) -> Vec<u8> {
+    encode(colours, |colour| {
+        let [red, green, blue] = to_rgb24(colour);
+        [red, green, blue, alpha]
+    })
+}
+
+/// Encode colours as 4 bytes each into `buffer`, see [`encode_rgba32`]
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{codec, Colour};
+///
+/// let mut buffer = [0; 4];
+/// assert_eq!(Ok(4), codec::encode_rgba32_into(&[Colour::BLUE], 0x80, &mut buffer));
+/// assert_eq!([0x34, 0x98, 0xdb, 0x80], buffer);
+/// assert!(codec::encode_rgba32_into(&[Colour::BLUE; 2], 0x80, &mut buffer).is_err());
+/// ```
+pub fn encode_rgba32_into(
+    colours: &[Colour],
+    alpha: u8,
+    buffer: &mut [u8],
+) -> Result<usize, CodecError> {
+    encode_into(colours, buffer, |colour| {
+        let [red, green, blue] = to_rgb24(colour);
+        [red, green, blue, alpha]
+    })
+}
+
+/// Decode colours stored as 4 bytes each, in RGBA order
+///
+/// The alpha channel is discarded.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{codec::{self, CodecError}, Colour};
+///
+/// assert_eq!(Ok(vec![Colour::BLUE]), codec::decode_rgba32(&[0x34, 0x98, 0xdb, 0x80]));
+/// assert_eq!(Ok(vec![]), codec::decode_rgba32(&[]));
+/// assert_eq!(
+///     Err(CodecError::InvalidLength { length: 3, stride: 4 }),
+///     codec::decode_rgba32(&[0x34, 0x98, 0xdb]),
+/// );
+/// ```
+pub fn decode_rgba32(bytes: &[u8]) -> Result<Vec<Colour>, CodecError> {
+    decode(bytes, |[red, green, blue, _]| {
+        Colour::from_rgb(red, green, blue)
+    })
+}
+
+/// Encode colours as 16-bit RGB565 values in little endian
+///
+/// The low bits of each channel are discarded, and restored by repeating the
+/// high bits when decoding, so every RGB565 value survives a round trip.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{codec, Colour};
+///
+/// assert_eq!(vec![0xdb, 0x34], codec::encode_rgb565_le(&[Colour::BLUE]));
+/// assert!(codec::encode_rgb565_le(&[]).is_empty());
+/// ```
+pub fn encode_rgb565_le(colours: &[Colour]) -> Vec<u8> {
+    encode(colours, |colour| to_rgb565(colour).to_le_bytes())
+}
+
+/// Encode colours as 16-bit RGB565 values in little endian into `buffer`, see
+/// [`encode_rgb565_le`]
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{codec, Colour};
+///
+/// let mut buffer = [0; 2];
+/// assert_eq!(Ok(2), codec::encode_rgb565_le_into(&[Colour::BLUE], &mut buffer));
+/// assert_eq!([0xdb, 0x34], buffer);
+/// ```
+pub fn encode_rgb565_le_into(colours: &[Colour], buffer: &mut [u8]) -> Result<usize, CodecError> {
+    encode_into(colours, buffer, |colour| to_rgb565(colour).to_le_bytes())
+}
+
+/// Decode colours stored as 16-bit RGB565 values in little endian
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{codec, Colour};
+///
+/// assert_eq!(Ok(vec![Colour::from_rgb(49, 154, 222)]), codec::decode_rgb565_le(&[0xdb, 0x34]));
+/// assert_eq!(Ok(vec![Colour::WHITE]), codec::decode_rgb565_le(&[0xff, 0xff]));
+/// assert_eq!(Ok(vec![]), codec::decode_rgb565_le(&[]));
+/// assert!(codec::decode_rgb565_le(&[0xdb]).is_err());
+/// # let bytes: Vec<_> = (0..=u16::MAX).flat_map(u16::to_le_bytes).collect();
+/// # assert_eq!(bytes, codec::encode_rgb565_le(&codec::decode_rgb565_le(&bytes).unwrap()));
+/// ```
+pub fn decode_rgb565_le(bytes: &[u8]) -> Result<Vec<Colour>, CodecError> {
+    decode(bytes, |bytes| from_rgb565(u16::from_le_bytes(bytes)))
+}
+
+/// Encode colours as 16-bit RGB565 values in big endian
+///
+/// See [`encode_rgb565_le`] for how channels are rounded.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{codec, Colour};
+///
+/// assert_eq!(vec![0x34, 0xdb], codec::encode_rgb565_be(&[Colour::BLUE]));
+/// assert!(codec::encode_rgb565_be(&[]).is_empty());
+/// ```
+pub fn encode_rgb565_be(colours: &[Colour]) -> Vec<u8> {
+    encode(colours, |colour| to_rgb565(colour).to_be_bytes())
+}
+
+/// Encode colours as 16-bit RGB565 values in big endian into `buffer`, see
+/// [`encode_rgb565_be`]
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{codec, Colour};
+///
+/// let mut buffer = [0; 2];
+/// assert_eq!(Ok(2), codec::encode_rgb565_be_into(&[Colour::BLUE], &mut buffer));
+/// assert_eq!([0x34, 0xdb], buffer);
+/// ```
+pub fn encode_rgb565_be_into(colours: &[Colour], buffer: &mut [u8]) -> Result<usize, CodecError> {
+    encode_into(colours, buffer, |colour| to_rgb565(colour).to_be_bytes())
+}
+
+/// Decode colours stored as 16-bit RGB565 values in big endian
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{codec, Colour};
+///
+/// assert_eq!(Ok(vec![Colour::from_rgb(49, 154, 222)]), codec::decode_rgb565_be(&[0x34, 0xdb]));
+/// assert_eq!(Ok(vec![]), codec::decode_rgb565_be(&[]));
+/// assert!(codec::decode_rgb565_be(&[0x34]).is_err());
+/// # let bytes: Vec<_> = (0..=u16::MAX).flat_map(u16::to_be_bytes).collect();
+/// # assert_eq!(bytes, codec::encode_rgb565_be(&codec::decode_rgb565_be(&bytes).unwrap()));
+/// ```
+pub fn decode_rgb565_be(bytes: &[u8]) -> Result<Vec<Colour>, CodecError> {
+    decode(bytes, |bytes| from_rgb565(u16::from_be_bytes(bytes)))
+}
@@ -0,0 +1,110 @@
+use crate::Colour;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// A type which is a colour, like a newtype around [`Colour`]
+///
+/// Implementing the trait gives the type the most common colour methods, and
+/// lets it be used with the crate's functions that take colours, like
+/// [`group_by_hue`](crate::group_by_hue) and [`Gradient`](crate::Gradient).
+/// Methods which make a new colour return the same type.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ColourLike};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct BrandColour(Colour);
+///
+/// impl ColourLike for BrandColour {
+///     fn as_colour(&self) -> Colour {
+///         self.0
+///     }
+///
+///     fn from_colour(colour: Colour) -> Self {
+///         Self(colour)
+///     }
+/// }
+///
+/// let brand = BrandColour(Colour::BLUE);
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!("rgb(52, 152, 219)", brand.to_css_rgb());
+/// assert!(brand.contrast_ratio(Colour::WHITE) > 2.0);
+/// assert_eq!(BrandColour(Colour::BLUE.lighten(0.1)), brand.lighten(0.1));
+///
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// let palette = [brand, BrandColour(Colour::DARK_BLUE), BrandColour(Colour::GOLD)];
+/// assert_eq!(2, chrom::group_by_hue(&palette, 30.0).len());
+/// # }
+/// ```
+pub trait ColourLike: Copy {
+    /// Get the colour
+    fn as_colour(&self) -> Colour;
+
+    /// Make a new value from a colour
+    fn from_colour(colour: Colour) -> Self;
+
+    /// See [`Colour::to_css_hex`]
+    #[cfg(feature = "alloc")]
+    fn to_css_hex(self) -> String {
+        self.as_colour().to_css_hex()
+    }
+
+    /// See [`Colour::to_css_rgb`]
+    #[cfg(feature = "alloc")]
+    fn to_css_rgb(self) -> String {
+        self.as_colour().to_css_rgb()
+    }
+
+    /// See [`Colour::to_hsl`]
+    fn to_hsl(self) -> crate::Hsl {
+        self.as_colour().to_hsl()
+    }
+
+    /// See [`Colour::to_oklch`]
+    fn to_oklch(self) -> [f32; 3] {
+        self.as_colour().to_oklch()
+    }
+
+    /// See [`Colour::contrast_ratio`]
+    fn contrast_ratio(self, other: impl ColourLike) -> f32 {
+        self.as_colour().contrast_ratio(other.as_colour())
+    }
+
+    /// See [`Colour::delta_e`]
+    fn delta_e(self, other: impl ColourLike) -> f32 {
+        self.as_colour().delta_e(other.as_colour())
+    }
+
+    /// See [`Colour::mix`]
+    fn mix(self, other: impl ColourLike, weight: f32) -> Self {
+        Self::from_colour(self.as_colour().mix(other.as_colour(), weight))
+    }
+
+    /// See [`Colour::lighten`]
+    fn lighten(self, amount: f32) -> Self {
+        Self::from_colour(self.as_colour().lighten(amount))
+    }
+
+    /// See [`Colour::darken`]
+    fn darken(self, amount: f32) -> Self {
+        Self::from_colour(self.as_colour().darken(amount))
+    }
+
+    /// See [`Colour::on_colour`]
+    fn on_colour(self) -> Self {
+        Self::from_colour(self.as_colour().on_colour())
+    }
+}
+
+impl ColourLike for Colour {
+    fn as_colour(&self) -> Colour {
+        *self
+    }
+
+    fn from_colour(colour: Colour) -> Self {
+        colour
+    }
+}
@@ -0,0 +1,144 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{Colour, LinearRgb, Srgb};
+
+/// The maths shared by colour types, for writing algorithms which work on any
+/// of them
+///
+/// Types without an alpha channel have an alpha of `1.0`, and ignore the
+/// alpha they are given.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ColourOps, LinearRgb};
+///
+/// /// Blur each pixel with its neighbours, in linear light
+/// fn blur<C: ColourOps>(pixels: &[C]) -> Vec<C> {
+///     (0..pixels.len())
+///         .map(|i| {
+///             let neighbours = &pixels[i.saturating_sub(1)..(i + 2).min(pixels.len())];
+///             let mut sum = [0.0; 4];
+///             for pixel in neighbours {
+///                 for (total, component) in sum.iter_mut().zip(pixel.to_linear_rgba()) {
+///                     *total += component;
+///                 }
+///             }
+///             C::from_linear_rgba(sum.map(|total| total / neighbours.len() as f32))
+///         })
+///         .collect()
+/// }
+///
+/// let blurred = blur(&[Colour::BLACK, Colour::WHITE, Colour::BLACK]);
+/// assert_eq!(vec![Colour::new(0xbcbcbc), Colour::new(0x9c9c9c), Colour::new(0xbcbcbc)], blurred);
+///
+/// let blurred = blur(&[LinearRgb([0.0; 3]), LinearRgb([0.75; 3]), LinearRgb([0.0; 3])]);
+/// assert_eq!(vec![LinearRgb([0.375; 3]), LinearRgb([0.25; 3]), LinearRgb([0.375; 3])], blurred);
+/// ```
+pub trait ColourOps: Copy {
+    /// Apply `f` to each colour channel, leaving alpha unchanged
+    ///
+    /// Channels are in the range `0.0..=1.0`, in the type's own encoding, so
+    /// sRGB for [`Colour`] and [`Srgb`], and linear light for [`LinearRgb`].
+    fn map_channels(self, f: impl FnMut(f32) -> f32) -> Self;
+
+    /// Mix two colours channel by channel in the type's own encoding, where
+    /// `t` is the proportion of `other` in the result
+    fn lerp(self, other: Self, t: f32) -> Self;
+
+    /// Get the linear light red, green and blue, then alpha
+    fn to_linear_rgba(self) -> [f32; 4];
+
+    /// Make a colour from linear light red, green and blue, then alpha
+    fn from_linear_rgba(rgba: [f32; 4]) -> Self;
+
+    /// Get the relative luminance, see [`Colour::relative_luminance`]
+    fn luminance(self) -> f32;
+}
+
+impl ColourOps for Colour {
+    /// Apply `f` to each channel as a proportion of 255, rounding the results
+    /// and clamping them to the range `0.0..=1.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourOps};
+    ///
+    /// let halved = Colour::from_rgb(200, 100, 51).map_channels(|channel| channel / 2.0);
+    /// assert_eq!(Colour::from_rgb(100, 50, 26), halved);
+    /// assert_eq!(Colour::WHITE, Colour::BLUE.map_channels(|channel| channel * 10.0));
+    /// ```
+    fn map_channels(self, mut f: impl FnMut(f32) -> f32) -> Self {
+        let mut channel = |c: u8| (f(f32::from(c) / 255.0).clamp(0.0, 1.0) * 255.0).round() as u8;
+        Self::from_rgb(
+            channel(self.red()),
+            channel(self.green()),
+            channel(self.blue()),
+        )
+    }
+
+    /// See [`Colour::mix`]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self.mix(other, t)
+    }
+
+    fn to_linear_rgba(self) -> [f32; 4] {
+        let [red, green, blue] = self.to_linear();
+        [red, green, blue, 1.0]
+    }
+
+    fn from_linear_rgba([red, green, blue, _]: [f32; 4]) -> Self {
+        Self::from_linear([red, green, blue])
+    }
+
+    fn luminance(self) -> f32 {
+        self.relative_luminance()
+    }
+}
+
+impl ColourOps for LinearRgb {
+    fn map_channels(self, f: impl FnMut(f32) -> f32) -> Self {
+        Self(self.0.map(f))
+    }
+
+    /// See [`LinearRgb::mix`]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self.mix(other, t)
+    }
+
+    fn to_linear_rgba(self) -> [f32; 4] {
+        let [red, green, blue] = self.0;
+        [red, green, blue, 1.0]
+    }
+
+    fn from_linear_rgba([red, green, blue, _]: [f32; 4]) -> Self {
+        Self([red, green, blue])
+    }
+
+    fn luminance(self) -> f32 {
+        LinearRgb::luminance(self)
+    }
+}
+
+impl ColourOps for Srgb {
+    fn map_channels(self, f: impl FnMut(f32) -> f32) -> Self {
+        Self(self.0.map_channels(f))
+    }
+
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self(self.0.mix(other.0, t))
+    }
+
+    fn to_linear_rgba(self) -> [f32; 4] {
+        self.0.to_linear_rgba()
+    }
+
+    fn from_linear_rgba(rgba: [f32; 4]) -> Self {
+        Self(Colour::from_linear_rgba(rgba))
+    }
+
+    fn luminance(self) -> f32 {
+        Srgb::luminance(self)
+    }
+}
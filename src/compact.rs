@@ -0,0 +1,163 @@
+use crate::Colour;
+
+/// Round a channel to the nearest of the levels `bits` bits can hold
+const fn quantise(channel: u8, bits: u32) -> u8 {
+    let max = (1 << bits) - 1;
+    ((channel as u32 * max + 127) / 255) as u8
+}
+
+/// Expand a channel of `bits` bits to 8 bits by repeating its bits, so the
+/// largest value becomes 255
+const fn expand(value: u8, bits: u32) -> u8 {
+    let mut expanded = 0;
+    let mut shift = 8;
+    while shift > 0 {
+        shift -= bits as i32;
+        expanded |= if shift >= 0 {
+            value << shift
+        } else {
+            value >> -shift
+        };
+    }
+    expanded
+}
+
+impl Colour {
+    /// Pack the colour into a byte, with 3 bits of red and green and 2 of blue
+    ///
+    /// The bits are `RRRGGGBB`. Each channel is rounded to the nearest level it
+    /// can hold, which is lossy: after [`Colour::from_rgb332`], the worst
+    /// [`Colour::delta_e`] from the original colour is 34.3, for dark
+    /// colours. Black and white round trip exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(0b001_100_11, Colour::BLUE.to_rgb332());
+    /// assert_eq!(0, Colour::BLACK.to_rgb332());
+    /// assert_eq!(0xff, Colour::WHITE.to_rgb332());
+    /// ```
+    pub const fn to_rgb332(self) -> u8 {
+        quantise(self.red(), 3) << 5 | quantise(self.green(), 3) << 2 | quantise(self.blue(), 2)
+    }
+
+    /// Unpack a colour packed by [`Colour::to_rgb332`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x2492ff), Colour::from_rgb332(0b001_100_11));
+    /// assert_eq!(Colour::BLACK, Colour::from_rgb332(Colour::BLACK.to_rgb332()));
+    /// assert_eq!(Colour::WHITE, Colour::from_rgb332(Colour::WHITE.to_rgb332()));
+    /// ```
+    pub const fn from_rgb332(value: u8) -> Self {
+        Self::from_rgb(
+            expand(value >> 5, 3),
+            expand(value >> 2 & 0b111, 3),
+            expand(value & 0b11, 2),
+        )
+    }
+
+    /// Pack the colour into the low 12 bits of a `u16`, with 4 bits for each
+    /// channel
+    ///
+    /// The bits are `0000RRRRGGGGBBBB`. Each channel is rounded to the nearest
+    /// level it can hold, which is lossy: after [`Colour::from_rgb444`], the
+    /// worst [`Colour::delta_e`] from the original colour is 16.4, for dark
+    /// colours. Black and white round trip exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(0x39d, Colour::BLUE.to_rgb444());
+    /// assert_eq!(0, Colour::BLACK.to_rgb444());
+    /// assert_eq!(0xfff, Colour::WHITE.to_rgb444());
+    /// ```
+    pub const fn to_rgb444(self) -> u16 {
+        (quantise(self.red(), 4) as u16) << 8
+            | (quantise(self.green(), 4) as u16) << 4
+            | quantise(self.blue(), 4) as u16
+    }
+
+    /// Unpack a colour packed by [`Colour::to_rgb444`]
+    ///
+    /// The high 4 bits are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x3399dd), Colour::from_rgb444(0x39d));
+    /// assert_eq!(Colour::from_rgb444(0x39d), Colour::from_rgb444(0xf39d));
+    /// assert_eq!(Colour::BLACK, Colour::from_rgb444(Colour::BLACK.to_rgb444()));
+    /// assert_eq!(Colour::WHITE, Colour::from_rgb444(Colour::WHITE.to_rgb444()));
+    /// ```
+    pub const fn from_rgb444(value: u16) -> Self {
+        Self::from_rgb(
+            expand((value >> 8 & 0xf) as u8, 4),
+            expand((value >> 4 & 0xf) as u8, 4),
+            expand((value & 0xf) as u8, 4),
+        )
+    }
+
+    /// Get the index of the colour in `palette` which looks closest to this
+    /// one, to send in a single byte
+    ///
+    /// Closeness is measured with [`Colour::delta_e`], and the first of equally
+    /// close colours is used. How lossy this is depends on the palette, so
+    /// there's no fixed worst case like [`Colour::to_rgb332`]. Both ends need
+    /// the same palette to decode with [`Colour::from_palette_index`].
+    ///
+    /// # Panics
+    ///
+    /// If the palette is empty or has more than 256 colours.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let palette = [Colour::BLACK, Colour::WHITE, Colour::RED, Colour::BLUE];
+    /// assert_eq!(2, Colour::DARK_RED.to_palette_index(&palette));
+    /// assert_eq!(3, Colour::new(0x3090e0).to_palette_index(&palette));
+    /// assert_eq!(1, Colour::WHITE.to_palette_index(&palette));
+    /// ```
+    pub fn to_palette_index(self, palette: &[Colour]) -> u8 {
+        assert!(
+            (1..=256).contains(&palette.len()),
+            "palette must have 1 to 256 colours"
+        );
+        let mut nearest = (0, f32::INFINITY);
+        for (i, &colour) in palette.iter().enumerate() {
+            let distance = self.delta_e(colour);
+            if distance < nearest.1 {
+                nearest = (i, distance);
+            }
+        }
+        nearest.0 as u8
+    }
+
+    /// Get the colour at an index from [`Colour::to_palette_index`], or `None`
+    /// if the index is past the end of the palette
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let palette = [Colour::BLACK, Colour::WHITE, Colour::RED, Colour::BLUE];
+    /// let index = Colour::DARK_RED.to_palette_index(&palette);
+    /// assert_eq!(Some(Colour::RED), Colour::from_palette_index(index, &palette));
+    /// assert_eq!(None, Colour::from_palette_index(4, &palette));
+    /// ```
+    pub fn from_palette_index(index: u8, palette: &[Colour]) -> Option<Self> {
+        palette.get(usize::from(index)).copied()
+    }
+}
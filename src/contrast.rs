@@ -0,0 +1,250 @@
+use crate::{oklab, Colour};
+
+/// A level of contrast between text and its background from WCAG 2.1
+///
+/// Large text is at least 18 point, or 14 point and bold. The large text
+/// levels are also used for icons and other parts of an interface.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ContrastLevel};
+///
+/// assert!(Colour::WHITE.meets(ContrastLevel::AaLarge, Colour::BLUE));
+/// assert!(!Colour::WHITE.meets(ContrastLevel::Aa, Colour::BLUE));
+/// assert!(Colour::BLACK.meets(ContrastLevel::Aa, Colour::BLUE));
+/// assert!(!Colour::BLACK.meets(ContrastLevel::Aaa, Colour::BLUE));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ContrastLevel {
+    /// Level AA for large text, a ratio of at least 3
+    AaLarge,
+    /// Level AA, a ratio of at least 4.5
+    Aa,
+    /// Level AAA for large text, a ratio of at least 4.5
+    AaaLarge,
+    /// Level AAA, a ratio of at least 7
+    Aaa,
+}
+
+impl ContrastLevel {
+    /// Get the lowest contrast ratio which meets the level
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::ContrastLevel;
+    ///
+    /// assert_eq!(4.5, ContrastLevel::Aa.min_ratio());
+    /// assert_eq!(7.0, ContrastLevel::Aaa.min_ratio());
+    /// ```
+    pub const fn min_ratio(self) -> f32 {
+        match self {
+            Self::AaLarge => 3.0,
+            Self::Aa | Self::AaaLarge => 4.5,
+            Self::Aaa => 7.0,
+        }
+    }
+}
+
+impl Colour {
+    /// Get the WCAG contrast ratio between two colours
+    ///
+    /// The ratio is in the range `1.0..=21.0`, and is the same whichever way
+    /// round the colours are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert!((Colour::BLACK.contrast_ratio(Colour::WHITE) - 21.0).abs() < 1e-4);
+    /// assert_eq!(1.0, Colour::BLUE.contrast_ratio(Colour::BLUE));
+    /// assert_eq!(
+    ///     Colour::BLUE.contrast_ratio(Colour::WHITE),
+    ///     Colour::WHITE.contrast_ratio(Colour::BLUE),
+    /// );
+    /// ```
+    pub fn contrast_ratio(self, other: Self) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Check if the colour has enough contrast with `other` to meet a WCAG
+    /// level, see [`ContrastLevel`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ContrastLevel};
+    ///
+    /// let grey = Colour::new(0x767676);
+    /// assert!(grey.meets(ContrastLevel::Aa, Colour::WHITE));
+    /// assert!(!Colour::new(0x777777).meets(ContrastLevel::Aa, Colour::WHITE));
+    /// assert_eq!(grey.meets(ContrastLevel::Aa, Colour::WHITE), Colour::WHITE.meets(ContrastLevel::Aa, grey));
+    /// ```
+    pub fn meets(self, level: ContrastLevel, other: Self) -> bool {
+        self.contrast_ratio(other) >= level.min_ratio()
+    }
+
+    /// Check if the colour is light, with more contrast against black than
+    /// against white
+    ///
+    /// Every colour is either light or dark, see [`Colour::is_dark`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert!(Colour::YELLOW.is_light());
+    /// assert!(Colour::LIGHT_GREY.is_light());
+    /// assert!(!Colour::DARK_NAVY.is_light());
+    /// ```
+    pub fn is_light(self) -> bool {
+        self.contrast_ratio(Self::BLACK) >= self.contrast_ratio(Self::WHITE)
+    }
+
+    /// Check if the colour is dark, with more contrast against white than
+    /// against black
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert!(Colour::DARK_NAVY.is_dark());
+    /// assert!(Colour::BLACK.is_dark());
+    /// assert!(!Colour::WHITE.is_dark());
+    /// ```
+    pub fn is_dark(self) -> bool {
+        !self.is_light()
+    }
+
+    /// Get black or white, whichever has the most contrast with the colour,
+    /// for text on top of it
+    ///
+    /// One of them always has a contrast ratio of at least 4.5, meeting
+    /// [`ContrastLevel::Aa`]. [`Colour::on_colour`] gives a tinted colour
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::BLACK, Colour::YELLOW.best_text_colour());
+    /// assert_eq!(Colour::WHITE, Colour::DARK_RED.best_text_colour());
+    /// assert_eq!(Colour::BLACK, Colour::BLUE.best_text_colour());
+    /// ```
+    pub fn best_text_colour(self) -> Self {
+        if self.is_light() {
+            Self::BLACK
+        } else {
+            Self::WHITE
+        }
+    }
+
+    /// Get a colour for content on top of the colour, like text or icons
+    ///
+    /// The result has a contrast ratio of at least 4.5 with the colour, which
+    /// is enough for small text under WCAG AA. It is a very dark or very light
+    /// shade of the same hue, falling back to black or white when no shade has
+    /// enough contrast.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let on_yellow = Colour::YELLOW.on_colour();
+    /// assert!(on_yellow.contrast_ratio(Colour::YELLOW) >= 4.5);
+    /// assert_ne!(Colour::BLACK, on_yellow);
+    /// assert!((on_yellow.to_oklch()[2] - Colour::YELLOW.to_oklch()[2]).abs() < 5.0);
+    ///
+    /// let on_navy = Colour::DARK_NAVY.on_colour();
+    /// assert!(on_navy.contrast_ratio(Colour::DARK_NAVY) >= 4.5);
+    /// assert!(on_navy.relative_luminance() > Colour::DARK_NAVY.relative_luminance());
+    /// ```
+    pub fn on_colour(self) -> Self {
+        let [_, chroma, hue] = self.to_oklch();
+        let chroma = (chroma / 2.0).min(0.08);
+        let darker = self.is_light();
+        let lightness = |i: usize| {
+            let step = i as f32 * 0.02;
+            if darker {
+                0.35 - step
+            } else {
+                0.93 + step
+            }
+        };
+
+        (0..=10)
+            .map(|i| oklab::from_oklch_in_gamut([lightness(i), chroma, hue]))
+            .find(|&colour| colour.contrast_ratio(self) >= 4.5)
+            .unwrap_or(if darker { Self::BLACK } else { Self::WHITE })
+    }
+
+    /// Get the colour as a container, with the colour for content on top of it
+    ///
+    /// See [`Colour::on_colour`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let (container, on_container) = Colour::BLUE.colour_pair();
+    /// assert_eq!(Colour::BLUE, container);
+    /// assert!(on_container.contrast_ratio(container) >= 4.5);
+    /// ```
+    pub fn colour_pair(self) -> (Self, Self) {
+        (self, self.on_colour())
+    }
+
+    /// Adjust the lightness of the colour until it has a contrast ratio of at
+    /// least `ratio` with `background`
+    ///
+    /// The colour is returned unchanged if it already has enough contrast.
+    /// Otherwise it is made darker on light backgrounds and lighter on dark
+    /// ones, keeping its hue, until it has enough, falling back to black or
+    /// white. Every background has a contrast ratio of at least 4.5 with one
+    /// of black or white, but higher ratios can't always be reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::BLACK, Colour::BLACK.ensure_contrast(Colour::WHITE, 4.5));
+    ///
+    /// let text = Colour::BLUE.ensure_contrast(Colour::WHITE, 4.5);
+    /// assert!(text.contrast_ratio(Colour::WHITE) >= 4.5);
+    /// assert!(text.relative_luminance() < Colour::BLUE.relative_luminance());
+    /// assert!((text.to_oklch()[2] - Colour::BLUE.to_oklch()[2]).abs() < 5.0);
+    ///
+    /// let text = Colour::DARK_BLUE.ensure_contrast(Colour::DARK_NAVY, 4.5);
+    /// assert!(text.contrast_ratio(Colour::DARK_NAVY) >= 4.5);
+    /// assert!(text.relative_luminance() > Colour::DARK_BLUE.relative_luminance());
+    /// ```
+    pub fn ensure_contrast(self, background: Self, ratio: f32) -> Self {
+        if self.contrast_ratio(background) >= ratio {
+            return self;
+        }
+
+        let [lightness, chroma, hue] = self.to_oklch();
+        let darker = background.is_light();
+        (1..=50)
+            .map(|i| {
+                let step = i as f32 * 0.02;
+                let lightness = if darker {
+                    lightness - step
+                } else {
+                    lightness + step
+                };
+                oklab::from_oklch_in_gamut([lightness.clamp(0.0, 1.0), chroma, hue])
+            })
+            .find(|&colour| colour.contrast_ratio(background) >= ratio)
+            .unwrap_or(if darker { Self::BLACK } else { Self::WHITE })
+    }
+}
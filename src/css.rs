@@ -0,0 +1,446 @@
+use crate::Colour;
+#[cfg(feature = "alloc")]
+use crate::ParsedColour;
+#[cfg(feature = "alloc")]
+use crate::{ColourStop, Gradient};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, format, string::String};
+use core::{
+    fmt::{self, Write},
+    str,
+};
+
+/// A colour space for the CSS `color()` function
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, CssColourSpace};
+///
+/// assert_eq!("srgb-linear", CssColourSpace::SrgbLinear.keyword());
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(
+///     Ok(Colour::BLUE),
+///     Colour::BLUE.to_css_color(CssColourSpace::XyzD65).parse(),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CssColourSpace {
+    /// sRGB, the same as hex colours and `rgb()`
+    Srgb,
+    /// sRGB in linear light, see [`Colour::to_linear`]
+    SrgbLinear,
+    /// CIE XYZ with the D65 white point, see [`Colour::to_xyz`]
+    XyzD65,
+    /// Display P3, see [`Colour::to_display_p3`]
+    DisplayP3,
+}
+
+impl CssColourSpace {
+    /// Every supported colour space
+    pub const ALL: [Self; 4] = [Self::Srgb, Self::SrgbLinear, Self::XyzD65, Self::DisplayP3];
+
+    /// Get the keyword used for the colour space in CSS
+    pub fn keyword(self) -> &'static str {
+        match self {
+            Self::Srgb => "srgb",
+            Self::SrgbLinear => "srgb-linear",
+            Self::XyzD65 => "xyz-d65",
+            Self::DisplayP3 => "display-p3",
+        }
+    }
+
+    /// Find the colour space for a CSS keyword, ignoring case
+    #[cfg(feature = "alloc")]
+    pub(crate) fn from_keyword(keyword: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|space| space.keyword().eq_ignore_ascii_case(keyword))
+    }
+
+    /// Get the components of a colour in the colour space
+    #[cfg(feature = "alloc")]
+    pub(crate) fn components(self, colour: Colour) -> [f32; 3] {
+        match self {
+            Self::Srgb => colour.to_rgb_f64().map(|c| c as f32),
+            Self::SrgbLinear => colour.to_linear(),
+            Self::XyzD65 => colour.to_xyz(),
+            Self::DisplayP3 => colour.to_display_p3(),
+        }
+    }
+
+    /// Make a colour from components in the colour space, clamping colours
+    /// outside of the sRGB gamut
+    #[cfg(feature = "alloc")]
+    pub(crate) fn colour(self, [a, b, c]: [f32; 3]) -> Colour {
+        match self {
+            Self::Srgb => Colour::from_rgb_f64([a, b, c].map(f64::from)),
+            Self::SrgbLinear => Colour::from_linear([a, b, c]),
+            Self::XyzD65 => Colour::from_xyz([a, b, c]),
+            Self::DisplayP3 => Colour::from_display_p3(a, b, c),
+        }
+    }
+}
+
+impl fmt::Display for CssColourSpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.keyword())
+    }
+}
+
+impl Colour {
+    /// Write the colour as `#rrggbb` into a buffer, without allocating
+    pub(crate) fn format_hex(self, buffer: &mut [u8; 7]) -> &str {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        buffer[0] = b'#';
+        for (i, byte) in buffer[1..].iter_mut().enumerate() {
+            *byte = DIGITS[(self.0 >> (20 - 4 * i) & 0xf) as usize];
+        }
+        str::from_utf8(buffer).expect("hex digits are ASCII")
+    }
+
+    /// Get the colour as a CSS hex colour, like `#3498db`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!("#3498db", Colour::BLUE.to_css_hex());
+    /// assert_eq!(Ok(Colour::BLUE), Colour::BLUE.to_css_hex().parse());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_css_hex(self) -> String {
+        let mut css = String::with_capacity("#rrggbb".len());
+        self.write_hex(&mut css)
+            .expect("writing to a string can't fail");
+        css
+    }
+
+    /// Get the colour as a CSS `rgb()` function, like `rgb(52, 152, 219)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!("rgb(52, 152, 219)", Colour::BLUE.to_css_rgb());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_css_rgb(self) -> String {
+        let mut css = String::with_capacity("rgb(255, 255, 255)".len());
+        self.write_css_rgb(&mut css)
+            .expect("writing to a string can't fail");
+        css
+    }
+
+    /// Write the colour as a CSS hex colour, like `#3498db`, without allocating
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let mut log = String::from("accent=");
+    /// Colour::BLUE.write_hex(&mut log).unwrap();
+    /// assert_eq!("accent=#3498db", log);
+    /// ```
+    pub fn write_hex(self, w: &mut impl Write) -> fmt::Result {
+        w.write_str(self.format_hex(&mut [0; 7]))
+    }
+
+    /// Write the colour as a CSS `rgb()` function, like `rgb(52, 152, 219)`,
+    /// without allocating
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let mut log = String::from("accent=");
+    /// Colour::BLUE.write_css_rgb(&mut log).unwrap();
+    /// assert_eq!("accent=rgb(52, 152, 219)", log);
+    /// ```
+    pub fn write_css_rgb(self, w: &mut impl Write) -> fmt::Result {
+        write!(w, "rgb({}, {}, {})", self.red(), self.green(), self.blue())
+    }
+
+    /// Write the ANSI escape sequence which sets the terminal foreground to
+    /// the colour, without allocating
+    ///
+    /// This uses 24-bit colour, which most modern terminals support. Write
+    /// `\x1b[39m` afterwards to go back to the default foreground.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let mut line = String::new();
+    /// Colour::BLUE.write_ansi_fg(&mut line).unwrap();
+    /// assert_eq!("\x1b[38;2;52;152;219m", line);
+    /// ```
+    pub fn write_ansi_fg(self, w: &mut impl Write) -> fmt::Result {
+        write!(
+            w,
+            "\x1b[38;2;{};{};{}m",
+            self.red(),
+            self.green(),
+            self.blue()
+        )
+    }
+
+    /// Write the ANSI escape sequence which sets the terminal background to
+    /// the colour, without allocating
+    ///
+    /// This uses 24-bit colour like [`Colour::write_ansi_fg`]. Write
+    /// `\x1b[49m` afterwards to go back to the default background.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let mut line = String::new();
+    /// Colour::BLUE.write_ansi_bg(&mut line).unwrap();
+    /// assert_eq!("\x1b[48;2;52;152;219m", line);
+    /// ```
+    pub fn write_ansi_bg(self, w: &mut impl Write) -> fmt::Result {
+        write!(
+            w,
+            "\x1b[48;2;{};{};{}m",
+            self.red(),
+            self.green(),
+            self.blue()
+        )
+    }
+
+    /// Get a value which displays as a CSS hex colour, see
+    /// [`Colour::write_hex`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!("accent=#3498db", format!("accent={}", Colour::BLUE.hex_display()));
+    /// ```
+    pub fn hex_display(self) -> HexDisplay {
+        HexDisplay(self)
+    }
+
+    /// Get a value which displays as a CSS `rgb()` function, see
+    /// [`Colour::write_css_rgb`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!("color: rgb(52, 152, 219);", format!("color: {};", Colour::BLUE.css_rgb_display()));
+    /// ```
+    pub fn css_rgb_display(self) -> CssRgbDisplay {
+        CssRgbDisplay(self)
+    }
+
+    /// Get a value which displays as the ANSI escape sequence setting the
+    /// terminal foreground to the colour, see [`Colour::write_ansi_fg`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let line = format!("{}blue\x1b[39m", Colour::BLUE.ansi_fg_display());
+    /// assert_eq!("\x1b[38;2;52;152;219mblue\x1b[39m", line);
+    /// ```
+    pub fn ansi_fg_display(self) -> AnsiFgDisplay {
+        AnsiFgDisplay(self)
+    }
+
+    /// Get a value which displays as the ANSI escape sequence setting the
+    /// terminal background to the colour, see [`Colour::write_ansi_bg`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let line = format!("{}blue\x1b[49m", Colour::BLUE.ansi_bg_display());
+    /// assert_eq!("\x1b[48;2;52;152;219mblue\x1b[49m", line);
+    /// ```
+    pub fn ansi_bg_display(self) -> AnsiBgDisplay {
+        AnsiBgDisplay(self)
+    }
+
+    /// Get the colour as a CSS `color()` function in a colour space, like
+    /// `color(srgb 0.20392 0.59608 0.85882)`
+    ///
+    /// Components are always written with 5 decimal places, so the output is
+    /// stable, and parses back to the same colour.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, CssColourSpace};
+    ///
+    /// let blue = Colour::BLUE;
+    /// assert_eq!("color(srgb 0.20392 0.59608 0.85882)", blue.to_css_color(CssColourSpace::Srgb));
+    /// assert_eq!(
+    ///     "color(srgb-linear 0.03434 0.31399 0.70838)",
+    ///     blue.to_css_color(CssColourSpace::SrgbLinear),
+    /// );
+    /// assert_eq!(
+    ///     "color(xyz-d65 0.25429 0.28300 0.71142)",
+    ///     blue.to_css_color(CssColourSpace::XyzD65),
+    /// );
+    /// assert_eq!(
+    ///     "color(display-p3 0.32085 0.58799 0.83692)",
+    ///     blue.to_css_color(CssColourSpace::DisplayP3),
+    /// );
+    /// assert_eq!("color(srgb 1.00000 1.00000 1.00000)", Colour::WHITE.to_css_color(CssColourSpace::Srgb));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_css_color(self, space: CssColourSpace) -> String {
+        let [a, b, c] = space.components(self);
+        format!("color({space} {a:.5} {b:.5} {c:.5})")
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<[ColourStop]>> Gradient<S> {
+    /// Get the gradient as a CSS `linear-gradient()`, like
+    /// `linear-gradient(#000000 0%, #ffffff 100%)`
+    ///
+    /// Every stop is written as a hex colour and a percentage, and no direction
+    /// is given, so the gradient goes from top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourStop, Gradient};
+    ///
+    /// let gradient = Gradient::new(Colour::BLACK, Colour::WHITE);
+    /// assert_eq!("linear-gradient(#000000 0%, #ffffff 100%)", gradient.to_css());
+    ///
+    /// let stops = [ColourStop::new(0.25, Colour::BLUE), ColourStop::new(0.875, Colour::GOLD)];
+    /// let gradient = Gradient::from_stops(stops).unwrap();
+    /// assert_eq!("linear-gradient(#3498db 25%, #f1c40f 87.5%)", gradient.to_css());
+    /// assert_eq!(Ok(gradient.stops()), gradient.to_css().parse::<Gradient>().as_ref().map(Gradient::stops));
+    /// ```
+    pub fn to_css(&self) -> String {
+        let mut css = String::from("linear-gradient(");
+        for (i, stop) in self.stops().iter().enumerate() {
+            if i > 0 {
+                css.push_str(", ");
+            }
+            stop.colour
+                .write_hex(&mut css)
+                .expect("writing to a string can't fail");
+            write!(css, " {}%", stop.position * 100.0).expect("writing to a string can't fail");
+        }
+        css.push(')');
+        css
+    }
+}
+
+/// Displays a colour as a CSS hex colour, made by [`Colour::hex_display`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HexDisplay(Colour);
+
+impl fmt::Display for HexDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write_hex(f)
+    }
+}
+
+/// Displays a colour as a CSS `rgb()` function, made by
+/// [`Colour::css_rgb_display`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CssRgbDisplay(Colour);
+
+impl fmt::Display for CssRgbDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write_css_rgb(f)
+    }
+}
+
+/// Displays a colour as an ANSI foreground escape sequence, made by
+/// [`Colour::ansi_fg_display`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AnsiFgDisplay(Colour);
+
+impl fmt::Display for AnsiFgDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write_ansi_fg(f)
+    }
+}
+
+/// Displays a colour as an ANSI background escape sequence, made by
+/// [`Colour::ansi_bg_display`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AnsiBgDisplay(Colour);
+
+impl fmt::Display for AnsiBgDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write_ansi_bg(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ParsedColour {
+    /// Get the colour as a CSS hex colour, or `transparent`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ParsedColour};
+    ///
+    /// assert_eq!("#3498db", ParsedColour::Opaque(Colour::BLUE).to_css_hex());
+    /// assert_eq!("transparent", ParsedColour::from(None).to_css_hex());
+    /// ```
+    pub fn to_css_hex(self) -> String {
+        match self {
+            Self::Opaque(colour) => colour.to_css_hex(),
+            Self::Transparent => "transparent".to_owned(),
+        }
+    }
+
+    /// Get the colour as a CSS `rgb()` function, or `transparent`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ParsedColour};
+    ///
+    /// assert_eq!("rgb(52, 152, 219)", ParsedColour::Opaque(Colour::BLUE).to_css_rgb());
+    /// assert_eq!("transparent", ParsedColour::Transparent.to_css_rgb());
+    /// ```
+    pub fn to_css_rgb(self) -> String {
+        match self {
+            Self::Opaque(colour) => colour.to_css_rgb(),
+            Self::Transparent => "transparent".to_owned(),
+        }
+    }
+}
+
+/// Write the colour as a CSS hex colour, or `transparent`
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ParsedColour};
+///
+/// assert_eq!("#3498db", ParsedColour::from(Some(Colour::BLUE)).to_string());
+/// assert_eq!("transparent", ParsedColour::from(None).to_string());
+/// ```
+#[cfg(feature = "alloc")]
+impl fmt::Display for ParsedColour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Opaque(colour) => colour.fmt(f),
+            Self::Transparent => f.write_str("transparent"),
+        }
+    }
+}
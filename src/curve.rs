@@ -0,0 +1,180 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::Colour;
+
+/// A tone curve which remaps the values of a channel, like the curves in an
+/// image editor
+///
+/// The curve is stored as a lookup table, so applying it is cheap.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, Curve};
+///
+/// // Darken the shadows and brighten the highlights
+/// let contrast = Curve::from_points(&[(0, 0), (64, 48), (192, 208), (255, 255)]);
+/// assert_eq!(48, contrast.apply(64));
+/// assert_eq!(128, contrast.apply(128));
+/// assert!(contrast.apply(32) < 32);
+/// assert!(contrast.apply(224) > 224);
+/// assert_eq!(Colour::new(0x26a2e6), Colour::new(0x3498db).map_curve(&contrast));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Curve {
+    table: [u8; 256],
+}
+
+impl Curve {
+    /// Make a curve which leaves values unchanged
+    pub const fn identity() -> Self {
+        let mut table = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            table[i] = i as u8;
+            i += 1;
+        }
+        Self { table }
+    }
+
+    /// Make a curve through control points of `(input, output)`
+    ///
+    /// The points are joined by a monotone cubic, so the curve never
+    /// overshoots and stays increasing wherever the points do. Before the first
+    /// point and after the last the curve is flat. Points don't have to be
+    /// sorted, and a later point replaces an earlier one with the same input.
+    /// With no points the curve is [`Curve::identity`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Curve};
+    ///
+    /// assert_eq!(Curve::identity(), Curve::from_points(&[]));
+    /// assert_eq!(Curve::identity(), Curve::from_points(&[(255, 255), (0, 0)]));
+    ///
+    /// let invert = Curve::from_points(&[(0, 255), (255, 0)]);
+    /// assert_eq!(Colour::BLUE.invert(), Colour::BLUE.map_curve(&invert));
+    ///
+    /// // Flat outside of the points
+    /// let clip = Curve::from_points(&[(32, 0), (224, 255)]);
+    /// assert_eq!(0, clip.apply(16));
+    /// assert_eq!(255, clip.apply(240));
+    /// assert_eq!(128, clip.apply(128));
+    ///
+    /// // Monotone points give a monotone curve
+    /// let curve = Curve::from_points(&[(0, 0), (40, 200), (60, 210), (255, 255)]);
+    /// assert!((0..255).all(|i| curve.apply(i) <= curve.apply(i + 1)));
+    /// assert!((0..=255).all(|i| curve.apply(i) <= 255));
+    /// ```
+    pub fn from_points(points: &[(u8, u8)]) -> Self {
+        let mut outputs = [None; 256];
+        for &(input, output) in points {
+            outputs[usize::from(input)] = Some(f32::from(output));
+        }
+        let mut sorted = [(0.0, 0.0); 256];
+        let mut count = 0;
+        for (input, output) in outputs.into_iter().enumerate() {
+            if let Some(output) = output {
+                sorted[count] = (input as f32, output);
+                count += 1;
+            }
+        }
+        let points = &sorted[..count];
+        let (Some(&first), Some(&last)) = (points.first(), points.last()) else {
+            return Self::identity();
+        };
+
+        let mut slopes = [0.0; 255];
+        for (slope, pair) in slopes.iter_mut().zip(points.windows(2)) {
+            *slope = (pair[1].1 - pair[0].1) / (pair[1].0 - pair[0].0);
+        }
+        let slopes = &slopes[..count - 1];
+        // Tangents from the weighted harmonic mean of the neighbouring slopes,
+        // as in Fritsch and Butland's method, which keeps the curve monotone
+        let mut tangents = [0.0; 256];
+        for (i, tangent) in tangents[..count].iter_mut().enumerate() {
+            *tangent = match (i.checked_sub(1).map(|j| slopes[j]), slopes.get(i)) {
+                (Some(before), Some(&after)) => {
+                    if before * after <= 0.0 {
+                        0.0
+                    } else {
+                        let width_before = points[i].0 - points[i - 1].0;
+                        let width_after = points[i + 1].0 - points[i].0;
+                        let w1 = 2.0 * width_after + width_before;
+                        let w2 = width_after + 2.0 * width_before;
+                        (w1 + w2) / (w1 / before + w2 / after)
+                    }
+                }
+                (Some(slope), None) | (None, Some(&slope)) => slope,
+                (None, None) => 0.0,
+            };
+        }
+
+        let mut table = [0; 256];
+        let mut segment = 0;
+        for (input, value) in table.iter_mut().enumerate() {
+            let x = input as f32;
+            let y = if x <= first.0 {
+                first.1
+            } else if x >= last.0 {
+                last.1
+            } else {
+                while points[segment + 1].0 < x {
+                    segment += 1;
+                }
+                let ((x0, y0), (x1, y1)) = (points[segment], points[segment + 1]);
+                let width = x1 - x0;
+                let t = (x - x0) / width;
+                let (t2, t3) = (t * t, t * t * t);
+                (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+                    + (t3 - 2.0 * t2 + t) * width * tangents[segment]
+                    + (-2.0 * t3 + 3.0 * t2) * y1
+                    + (t3 - t2) * width * tangents[segment + 1]
+            };
+            *value = y.round().clamp(0.0, 255.0) as u8;
+        }
+        Self { table }
+    }
+
+    /// Apply the curve to a value
+    pub const fn apply(&self, value: u8) -> u8 {
+        self.table[value as usize]
+    }
+}
+
+impl Default for Curve {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Colour {
+    /// Remap each channel of the colour through its own curve
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Curve};
+    ///
+    /// // Warm the colour up by lifting red and lowering blue
+    /// let red = Curve::from_points(&[(0, 0), (128, 150), (255, 255)]);
+    /// let blue = Curve::from_points(&[(0, 0), (128, 110), (255, 255)]);
+    /// let warm = Colour::new(0x808080).map_curves(&red, &Curve::identity(), &blue);
+    /// assert_eq!(Colour::new(0x96806e), warm);
+    /// ```
+    pub fn map_curves(self, red: &Curve, green: &Curve, blue: &Curve) -> Self {
+        Self::from_rgb(
+            red.apply(self.red()),
+            green.apply(self.green()),
+            blue.apply(self.blue()),
+        )
+    }
+
+    /// Remap every channel of the colour through the same curve
+    ///
+    /// See [`Colour::map_curves`].
+    pub fn map_curve(self, curve: &Curve) -> Self {
+        self.map_curves(curve, curve, curve)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{Colour, ColourLike};
+use std::collections::HashSet;
+
+/// How the distance between two colours is measured
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Distance {
+    /// How different the colours look, see [`Colour::delta_e`]
+    #[default]
+    DeltaE,
+    /// The straight line distance between the red, green and blue channels,
+    /// each in the range `0..=255`
+    Rgb,
+}
+
+impl Distance {
+    /// Get the distance between two colours
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Distance};
+    ///
+    /// let (a, b) = (Colour::from_rgb(0, 0, 0), Colour::from_rgb(3, 4, 0));
+    /// assert_eq!(5.0, Distance::Rgb.between(a, b));
+    /// assert_eq!(a.delta_e(b), Distance::DeltaE.between(a, b));
+    /// ```
+    pub fn between(self, a: Colour, b: Colour) -> f32 {
+        match self {
+            Self::DeltaE => a.delta_e(b),
+            Self::Rgb => {
+                let channel = |a: u8, b: u8| (f32::from(a) - f32::from(b)).powi(2);
+                (channel(a.red(), b.red())
+                    + channel(a.green(), b.green())
+                    + channel(a.blue(), b.blue()))
+                .sqrt()
+            }
+        }
+    }
+}
+
+/// Remove colours which are within `tolerance` of an earlier colour
+///
+/// The first of each group of similar colours is kept, and the order of the
+/// colours doesn't change. Which colours are kept depends on the order: if
+/// `b` is close to both `a` and `c` but they aren't close to each other,
+/// `[a, b, c]` keeps `a` and `c`, while `[b, a, c]` keeps only `b`.
+///
+/// With a tolerance of `0.0` only exact duplicates are removed, the same as
+/// [`unique_exact`].
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, Distance};
+///
+/// let mut palette = vec![
+///     Colour::BLUE,
+///     Colour::GOLD,
+///     Colour::from_rgb(53, 152, 219),
+///     Colour::from_rgb(50, 150, 220),
+///     Colour::BLUE,
+///     Colour::RED,
+/// ];
+/// chrom::dedup(&mut palette, 2.0, Distance::DeltaE);
+/// assert_eq!(vec![Colour::BLUE, Colour::GOLD, Colour::RED], palette);
+///
+/// let mut palette = vec![Colour::BLUE, Colour::from_rgb(55, 156, 219)];
+/// chrom::dedup(&mut palette, 4.0, Distance::Rgb);
+/// assert_eq!(vec![Colour::BLUE, Colour::from_rgb(55, 156, 219)], palette);
+/// chrom::dedup(&mut palette, 5.0, Distance::Rgb);
+/// assert_eq!(vec![Colour::BLUE], palette);
+/// ```
+pub fn dedup<C: ColourLike>(colours: &mut Vec<C>, tolerance: f32, distance: Distance) {
+    let mut kept: Vec<Colour> = Vec::new();
+    colours.retain(|colour| {
+        let colour = colour.as_colour();
+        let duplicate = kept
+            .iter()
+            .any(|&other| distance.between(colour, other) <= tolerance);
+        if !duplicate {
+            kept.push(colour);
+        }
+        !duplicate
+    });
+}
+
+/// Remove colours which are exactly the same as an earlier colour
+///
+/// The order of the colours doesn't change.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+///
+/// let mut palette = vec![Colour::BLUE, Colour::GOLD, Colour::BLUE, Colour::new(0x3498dc)];
+/// chrom::unique_exact(&mut palette);
+/// assert_eq!(vec![Colour::BLUE, Colour::GOLD, Colour::new(0x3498dc)], palette);
+/// ```
+pub fn unique_exact<C: ColourLike>(colours: &mut Vec<C>) {
+    let mut seen = HashSet::new();
+    colours.retain(|colour| seen.insert(colour.as_colour()));
+}
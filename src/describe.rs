@@ -0,0 +1,209 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::Colour;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// How light a colour is, used by [`Description`]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Lightness {
+    Dark,
+    Medium,
+    Light,
+}
+
+/// How saturated a colour is, used by [`Description`]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Saturation {
+    Greyish,
+    Desaturated,
+    Vivid,
+}
+
+/// The name of a 30° sector of the colour wheel, used by [`Description`]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum HueName {
+    Red,
+    Orange,
+    Yellow,
+    Chartreuse,
+    Green,
+    SpringGreen,
+    Cyan,
+    Azure,
+    Blue,
+    Violet,
+    Magenta,
+    Rose,
+}
+
+impl HueName {
+    const ALL: [Self; 12] = [
+        Self::Red,
+        Self::Orange,
+        Self::Yellow,
+        Self::Chartreuse,
+        Self::Green,
+        Self::SpringGreen,
+        Self::Cyan,
+        Self::Azure,
+        Self::Blue,
+        Self::Violet,
+        Self::Magenta,
+        Self::Rose,
+    ];
+
+    /// Get the name of the sector a hue in degrees falls in
+    ///
+    /// Sectors are centred on multiples of 30°, starting with red at 0°.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::HueName;
+    ///
+    /// assert_eq!(HueName::Red, HueName::from_degrees(350.0));
+    /// assert_eq!(HueName::Orange, HueName::from_degrees(15.0));
+    /// assert_eq!(HueName::Blue, HueName::from_degrees(240.0));
+    /// ```
+    pub fn from_degrees(hue: f32) -> Self {
+        let sector = ((hue.rem_euclid(360.0) + 15.0) / 30.0) as usize;
+        Self::ALL[sector % Self::ALL.len()]
+    }
+}
+
+/// A structured description of a colour
+///
+/// The [`Display`](fmt::Display) implementation gives the English description
+/// returned by [`Colour::describe`].
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, Description, HueName, Lightness, Saturation};
+///
+/// assert_eq!(
+///     Description::Hued(Lightness::Dark, Saturation::Vivid, HueName::Orange),
+///     Colour::DARK_ORANGE.description(),
+/// );
+/// assert_eq!(Description::Grey(Lightness::Light), Colour::LIGHT_GREY.description());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Description {
+    Black,
+    White,
+    Grey(Lightness),
+    Hued(Lightness, Saturation, HueName),
+}
+
+impl fmt::Display for Lightness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Dark => "dark",
+            Self::Medium => "medium",
+            Self::Light => "light",
+        })
+    }
+}
+
+impl fmt::Display for Saturation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Greyish => "greyish",
+            Self::Desaturated => "desaturated",
+            Self::Vivid => "vivid",
+        })
+    }
+}
+
+impl fmt::Display for HueName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Red => "red",
+            Self::Orange => "orange",
+            Self::Yellow => "yellow",
+            Self::Chartreuse => "chartreuse",
+            Self::Green => "green",
+            Self::SpringGreen => "spring green",
+            Self::Cyan => "cyan",
+            Self::Azure => "azure",
+            Self::Blue => "blue",
+            Self::Violet => "violet",
+            Self::Magenta => "magenta",
+            Self::Rose => "rose",
+        })
+    }
+}
+
+impl fmt::Display for Description {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Black => f.write_str("black"),
+            Self::White => f.write_str("white"),
+            Self::Grey(Lightness::Medium) => f.write_str("grey"),
+            Self::Grey(lightness) => write!(f, "{lightness} grey"),
+            Self::Hued(Lightness::Medium, saturation, hue) => write!(f, "{saturation} {hue}"),
+            Self::Hued(lightness, saturation, hue) => write!(f, "{lightness} {saturation} {hue}"),
+        }
+    }
+}
+
+impl Colour {
+    /// Get a structured description of the colour
+    ///
+    /// The description is bucketed from the HSL values of the colour. Colours
+    /// with a lightness below `0.08` or above `0.95` are black or white, and
+    /// colours with a saturation below `0.1` or very little chroma are grey.
+    /// Lightness is dark below `0.4` and light above `0.7`, saturation is
+    /// greyish below `0.3` and vivid above `0.6`.
+    pub fn description(self) -> Description {
+        let hsl = self.to_hsl();
+        let chroma = self.max_channel().1 - self.min_channel().1;
+
+        if hsl.lightness < 0.08 {
+            return Description::Black;
+        }
+        if hsl.lightness > 0.95 {
+            return Description::White;
+        }
+
+        let lightness = if hsl.lightness < 0.4 {
+            Lightness::Dark
+        } else if hsl.lightness <= 0.7 {
+            Lightness::Medium
+        } else {
+            Lightness::Light
+        };
+        if hsl.saturation < 0.1 || chroma < 10 {
+            return Description::Grey(lightness);
+        }
+
+        let saturation = if hsl.saturation < 0.3 {
+            Saturation::Greyish
+        } else if hsl.saturation <= 0.6 {
+            Saturation::Desaturated
+        } else {
+            Saturation::Vivid
+        };
+        Description::Hued(lightness, saturation, HueName::from_degrees(hsl.hue))
+    }
+
+    /// Get a description of the colour in English, like `"dark desaturated blue"`
+    ///
+    /// See [`Colour::description`] for how colours are bucketed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!("vivid azure", Colour::BLUE.describe());
+    /// assert_eq!("dark desaturated violet", Colour::new(0x3c2a5c).describe());
+    /// assert_eq!("black", Colour::new(0x0a0a12).describe());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn describe(self) -> String {
+        self.description().to_string()
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{Colour, ColourLike};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{error, fmt};
+
+/// How much each channel of [`diff_image`] is amplified
+#[cfg(feature = "alloc")]
+const DIFF_IMAGE_SCALE: u8 = 8;
+
+/// An error returned when two images can't be compared
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ImageDiffError {
+    /// The images have a different number of pixels
+    LengthMismatch { left: usize, right: usize },
+}
+
+impl fmt::Display for ImageDiffError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch { left, right } => {
+                write!(f, "images have different lengths ({left} and {right})")
+            }
+        }
+    }
+}
+
+impl error::Error for ImageDiffError {}
+
+/// Check that two images have the same number of pixels
+fn check_lengths<C>(a: &[C], b: &[C]) -> Result<(), ImageDiffError> {
+    if a.len() == b.len() {
+        Ok(())
+    } else {
+        Err(ImageDiffError::LengthMismatch {
+            left: a.len(),
+            right: b.len(),
+        })
+    }
+}
+
+/// A comparison between two images, made by [`diff_summary`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DiffSummary {
+    /// The number of pixels which differ by more than the threshold
+    pub differing: usize,
+    /// The largest difference of any channel of any pixel
+    pub max_delta: u8,
+    /// The mean Delta E of every pixel, see [`Colour::delta_e`]
+    pub mean_delta_e: f32,
+    /// The index of the pixel with the largest Delta E, or `None` if no pixels
+    /// differ by more than the threshold
+    pub worst_index: Option<usize>,
+}
+
+impl Colour {
+    /// Get the absolute difference of each channel, like the difference blend
+    /// mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let a = Colour::from_rgb(10, 200, 30);
+    /// let b = Colour::from_rgb(20, 100, 30);
+    /// assert_eq!(Colour::from_rgb(10, 100, 0), a.abs_diff(b));
+    /// assert_eq!(a.abs_diff(b), b.abs_diff(a));
+    /// assert_eq!(Colour::WHITE, Colour::BLACK.abs_diff(Colour::WHITE));
+    /// ```
+    pub fn abs_diff(self, other: Self) -> Self {
+        Self::from_rgb(
+            self.red().abs_diff(other.red()),
+            self.green().abs_diff(other.green()),
+            self.blue().abs_diff(other.blue()),
+        )
+    }
+}
+
+/// Compare two images pixel by pixel
+///
+/// A pixel counts as differing when any of its channels differs by more than
+/// `threshold`, so a threshold of `0` counts every change.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{diff_summary, Colour, ImageDiffError};
+///
+/// let frame = [Colour::BLUE, Colour::GOLD, Colour::RED, Colour::WHITE];
+/// let same = diff_summary(&frame, &frame, 0).unwrap();
+/// assert_eq!(0, same.differing);
+/// assert_eq!(0, same.max_delta);
+/// assert_eq!(0.0, same.mean_delta_e);
+/// assert_eq!(None, same.worst_index);
+///
+/// // Slightly noisy, with one pixel which is further off
+/// let noisy = [
+///     Colour::BLUE + Colour::from_rgb(1, 0, 0),
+///     Colour::GOLD - Colour::from_rgb(0, 2, 0),
+///     Colour::RED + Colour::from_rgb(0, 6, 0),
+///     Colour::WHITE,
+/// ];
+/// let summary = diff_summary(&frame, &noisy, 0).unwrap();
+/// assert_eq!(3, summary.differing);
+/// assert_eq!(6, summary.max_delta);
+/// assert!(summary.mean_delta_e > 0.0 && summary.mean_delta_e < 2.0);
+/// assert_eq!(Some(2), summary.worst_index);
+/// let summary = diff_summary(&frame, &noisy, 2).unwrap();
+/// assert_eq!(1, summary.differing);
+/// assert_eq!(Some(2), summary.worst_index);
+/// assert_eq!(None, diff_summary(&frame, &noisy, 6).unwrap().worst_index);
+///
+/// let inverted = frame.map(|colour| colour.abs_diff(Colour::WHITE));
+/// let summary = diff_summary(&frame, &inverted, 10).unwrap();
+/// assert_eq!(4, summary.differing);
+/// assert_eq!(255, summary.max_delta);
+/// assert!(summary.mean_delta_e > 50.0);
+///
+/// assert_eq!(
+///     Err(ImageDiffError::LengthMismatch { left: 4, right: 3 }),
+///     diff_summary(&frame, &frame[1..], 0),
+/// );
+/// ```
+pub fn diff_summary<C: ColourLike>(
+    a: &[C],
+    b: &[C],
+    threshold: u8,
+) -> Result<DiffSummary, ImageDiffError> {
+    check_lengths(a, b)?;
+
+    let mut summary = DiffSummary {
+        differing: 0,
+        max_delta: 0,
+        mean_delta_e: 0.0,
+        worst_index: None,
+    };
+    let mut total_delta_e = 0.0;
+    let mut worst_delta_e = 0.0;
+    for (i, (a, b)) in a.iter().zip(b).enumerate() {
+        let (a, b) = (a.as_colour(), b.as_colour());
+        let diff = a.abs_diff(b);
+        let delta = diff.red().max(diff.green()).max(diff.blue());
+        summary.max_delta = summary.max_delta.max(delta);
+
+        let delta_e = if delta == 0 { 0.0 } else { a.delta_e(b) };
+        total_delta_e += delta_e;
+        if delta > threshold {
+            summary.differing += 1;
+            if summary.worst_index.is_none() || delta_e > worst_delta_e {
+                summary.worst_index = Some(i);
+                worst_delta_e = delta_e;
+            }
+        }
+    }
+    if !a.is_empty() {
+        summary.mean_delta_e = total_delta_e / a.len() as f32;
+    }
+
+    Ok(summary)
+}
+
+/// Make an image showing the difference between two images
+///
+/// Each pixel is the [`Colour::abs_diff`] of the two pixels with every channel
+/// multiplied by 8, so a difference of 32 or more is shown at full brightness
+/// and even a difference of 1 is visible. Identical pixels are black. Images
+/// with different lengths are an error, like [`diff_summary`].
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{diff_image, Colour, ImageDiffError};
+///
+/// let a = [Colour::BLUE, Colour::BLACK, Colour::from_rgb(100, 100, 100)];
+/// let b = [Colour::BLUE, Colour::WHITE, Colour::from_rgb(101, 104, 96)];
+/// assert_eq!(
+///     Ok(vec![Colour::BLACK, Colour::WHITE, Colour::from_rgb(8, 32, 32)]),
+///     diff_image(&a, &b),
+/// );
+/// assert_eq!(
+///     Err(ImageDiffError::LengthMismatch { left: 3, right: 2 }),
+///     diff_image(&a, &b[1..]),
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn diff_image<C: ColourLike>(a: &[C], b: &[C]) -> Result<Vec<Colour>, ImageDiffError> {
+    check_lengths(a, b)?;
+
+    Ok(a.iter()
+        .zip(b)
+        .map(|(a, b)| {
+            let diff = a.as_colour().abs_diff(b.as_colour());
+            let [red, green, blue] =
+                [diff.red(), diff.green(), diff.blue()].map(|c| c.saturating_mul(DIFF_IMAGE_SCALE));
+            Colour::from_rgb(red, green, blue)
+        })
+        .collect())
+}
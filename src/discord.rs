@@ -0,0 +1,221 @@
+use crate::{Colour, ColourRangeError};
+#[cfg(feature = "alloc")]
+use crate::{ParseColourError, MAX_COLOUR_LENGTH};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, string::String};
+#[cfg(feature = "alloc")]
+use core::{error, fmt};
+
+/// A colour from a Discord bot config or API string, parsed by
+/// [`Colour::from_discord_str`]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DiscordColour {
+    Colour(Colour),
+    /// `default`, which is no colour, sent to the API as `0`
+    Default,
+    /// `random`, for the caller to pick with their own random number generator
+    Random,
+}
+
+impl DiscordColour {
+    /// Get the colour, using `random` to pick one for [`DiscordColour::Random`]
+    ///
+    /// [`DiscordColour::Default`] has no colour, so it gives `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, DiscordColour};
+    ///
+    /// assert_eq!(Some(Colour::BLUE), DiscordColour::Colour(Colour::BLUE).resolve(|| Colour::RED));
+    /// assert_eq!(Some(Colour::RED), DiscordColour::Random.resolve(|| Colour::RED));
+    /// assert_eq!(None, DiscordColour::Default.resolve(|| Colour::RED));
+    /// ```
+    pub fn resolve(self, random: impl FnOnce() -> Colour) -> Option<Colour> {
+        match self {
+            Self::Colour(colour) => Some(colour),
+            Self::Default => None,
+            Self::Random => Some(random()),
+        }
+    }
+}
+
+impl From<Colour> for DiscordColour {
+    fn from(colour: Colour) -> Self {
+        Self::Colour(colour)
+    }
+}
+
+/// An error returned when a string can't be parsed as a [`DiscordColour`]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseDiscordColourError {
+    /// A decimal value is above `16777215`, which is `0xffffff`, with its
+    /// digits as they were written
+    OutOfRange(String),
+    /// The string isn't a decimal value, a keyword or a colour
+    InvalidColour(ParseColourError),
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ParseDiscordColourError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange(digits) => write!(
+                f,
+                "value {digits} is outside of the Discord colour range 0 to 16777215"
+            ),
+            Self::InvalidColour(error) => error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl error::Error for ParseDiscordColourError {}
+
+constants! {
+    /// The names and values of the Discord theme constants, in the same order
+    /// as [`Colour::ALL_CONSTANTS`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(("DISCORD_BLURPLE", Colour::DISCORD_BLURPLE), Colour::DISCORD_CONSTANTS[0]);
+    /// ```
+    DISCORD_CONSTANTS {
+        DISCORD_BLURPLE = 0x5865F2,
+        DISCORD_DARK_THEME = 0x313338,
+        DISCORD_DARK_EMBED = 0x2B2D31,
+        DISCORD_LIGHT_THEME = 0xFFFFFF,
+    }
+}
+
+impl Colour {
+    /// Get the colour as a value for the Discord API, like the `color` of an
+    /// embed or role
+    ///
+    /// The value is always in the range `0..=0xffffff` that the API accepts.
+    /// Some clients treat `0` as no colour rather than black, use
+    /// [`Colour::to_discord_visible`] to avoid that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(0x5865f2, Colour::DISCORD_BLURPLE.to_discord());
+    /// assert_eq!(0, Colour::BLACK.to_discord());
+    /// ```
+    pub const fn to_discord(self) -> u32 {
+        self.0
+    }
+
+    /// Get the colour as a value for the Discord API, with black replaced by
+    /// `0x010101`
+    ///
+    /// A colour of `0` is shown as no colour at all by some clients, usually
+    /// the default grey of an embed or role. `0x010101` looks the same as
+    /// black but is always shown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(0x010101, Colour::BLACK.to_discord_visible());
+    /// assert_eq!(0x010101, Colour::new(0x010101).to_discord_visible());
+    /// assert_eq!(0x000001, Colour::new(0x000001).to_discord_visible());
+    /// assert_eq!(0x5865f2, Colour::DISCORD_BLURPLE.to_discord_visible());
+    /// ```
+    pub const fn to_discord_visible(self) -> u32 {
+        if self.0 == 0 {
+            0x010101
+        } else {
+            self.0
+        }
+    }
+
+    /// Make a new colour using a value from the Discord API, failing if it is
+    /// outside of the range `0..=0xffffff`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourRangeError};
+    ///
+    /// assert_eq!(Ok(Colour::DISCORD_BLURPLE), Colour::from_discord(0x5865f2));
+    /// assert_eq!(Ok(Colour::BLACK), Colour::from_discord(0));
+    /// assert_eq!(Err(ColourRangeError(0x1000000)), Colour::from_discord(0x1000000));
+    /// ```
+    pub const fn from_discord(value: u32) -> Result<Self, ColourRangeError> {
+        Self::from_u32_checked(value)
+    }
+
+    /// Parse a colour the way Discord APIs and bot configs write them
+    ///
+    /// This accepts decimal values like `"3447003"`, the keywords `default`
+    /// and `random` in any case, and anything else [`Colour`] parses, like
+    /// `"#3498db"`. `"0"` is black, even though Discord shows it as no colour
+    /// in some places like [`DiscordColour::Default`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, DiscordColour, ParseColourError, ParseDiscordColourError};
+    ///
+    /// let inputs = [
+    ///     ("3447003", Ok(DiscordColour::Colour(Colour::BLUE))),
+    ///     ("#3498db", Ok(DiscordColour::Colour(Colour::BLUE))),
+    ///     ("16777215", Ok(DiscordColour::Colour(Colour::WHITE))),
+    ///     (" 0 ", Ok(DiscordColour::Colour(Colour::BLACK))),
+    ///     ("default", Ok(DiscordColour::Default)),
+    ///     ("Random", Ok(DiscordColour::Random)),
+    ///     ("16777216", Err(ParseDiscordColourError::OutOfRange("16777216".to_owned()))),
+    ///     (
+    ///         "99999999999999999999",
+    ///         Err(ParseDiscordColourError::OutOfRange("99999999999999999999".to_owned())),
+    ///     ),
+    ///     ("-1", Err(ParseDiscordColourError::InvalidColour(ParseColourError::InvalidSyntax))),
+    ///     ("#3498d", Err(ParseDiscordColourError::InvalidColour(ParseColourError::InvalidLength(5)))),
+    /// ];
+    /// for (input, expected) in inputs {
+    ///     assert_eq!(expected, Colour::from_discord_str(input), "{input:?}");
+    /// }
+    ///
+    /// let error = Colour::from_discord_str("16777216").unwrap_err();
+    /// assert_eq!(
+    ///     "value 16777216 is outside of the Discord colour range 0 to 16777215",
+    ///     error.to_string(),
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_discord_str(s: &str) -> Result<DiscordColour, ParseDiscordColourError> {
+        if s.len() > MAX_COLOUR_LENGTH {
+            return Err(ParseDiscordColourError::InvalidColour(
+                ParseColourError::TooLong(s.len()),
+            ));
+        }
+
+        let trimmed = s.trim();
+        if trimmed.eq_ignore_ascii_case("default") {
+            return Ok(DiscordColour::Default);
+        }
+        if trimmed.eq_ignore_ascii_case("random") {
+            return Ok(DiscordColour::Random);
+        }
+        if !trimmed.is_empty() && trimmed.bytes().all(|byte| byte.is_ascii_digit()) {
+            return trimmed
+                .parse()
+                .ok()
+                .and_then(|value| Self::from_discord(value).ok())
+                .map(DiscordColour::Colour)
+                .ok_or_else(|| ParseDiscordColourError::OutOfRange(trimmed.to_owned()));
+        }
+
+        s.parse()
+            .map(DiscordColour::Colour)
+            .map_err(ParseDiscordColourError::InvalidColour)
+    }
+}
@@ -0,0 +1,120 @@
+use crate::Colour;
+#[cfg(feature = "alloc")]
+use crate::ColourLike;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// The coloured square emoji and the colour each one is drawn in
+///
+/// Colours are the ones used by Twemoji, which Discord and several other chat
+/// apps render emoji with.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, COLOUR_EMOJI};
+///
+/// let (emoji, colour) = COLOUR_EMOJI[0];
+/// assert_eq!('🟥', emoji);
+/// assert_eq!('🟥', colour.to_colour_emoji());
+/// assert!(COLOUR_EMOJI.iter().all(|&(emoji, colour)| colour.to_colour_emoji() == emoji));
+/// ```
+pub const COLOUR_EMOJI: [(char, Colour); 9] = [
+    ('🟥', Colour(0xdd2e44)),
+    ('🟧', Colour(0xf4900c)),
+    ('🟨', Colour(0xfdcb58)),
+    ('🟩', Colour(0x78b159)),
+    ('🟦', Colour(0x55acee)),
+    ('🟪', Colour(0xaa8ed6)),
+    ('🟫', Colour(0xc1694f)),
+    ('⬛', Colour(0x292f33)),
+    ('⬜', Colour(0xe6e7e8)),
+];
+
+/// Colours which should map to each emoji, on top of the ones it is drawn in
+///
+/// The Twemoji colours alone are all fairly light, so without these dark
+/// colours end up as black and light reds as brown. They are all CSS named
+/// colours.
+const REFERENCES: [(char, Colour); 18] = [
+    ('🟥', Colour(0xff0000)), // red
+    ('🟥', Colour(0x800000)), // maroon
+    ('🟧', Colour(0xff7f50)), // coral
+    ('🟨', Colour(0xffff00)), // yellow
+    ('🟩', Colour(0x00ff00)), // lime
+    ('🟩', Colour(0x006400)), // darkgreen
+    ('🟦', Colour(0x0000ff)), // blue
+    ('🟦', Colour(0x000080)), // navy
+    ('🟦', Colour(0x00ffff)), // cyan
+    ('🟦', Colour(0x4682b4)), // steelblue
+    ('🟪', Colour(0x800080)), // purple
+    ('🟪', Colour(0xff00ff)), // magenta
+    ('🟪', Colour(0x4b0082)), // indigo
+    ('🟪', Colour(0xffc0cb)), // pink
+    ('🟫', Colour(0x8b4513)), // saddlebrown
+    ('🟫', Colour(0xd2b48c)), // tan
+    ('⬛', Colour(0x555555)),
+    ('⬜', Colour(0x999999)),
+];
+
+impl Colour {
+    /// Get the coloured square emoji which looks closest to the colour
+    ///
+    /// The colour is compared with [`Colour::delta_e`] to the colour each
+    /// emoji is drawn in (see [`COLOUR_EMOJI`]), and to a few extra reference
+    /// colours so that dark blues aren't black and light reds aren't brown.
+    /// There is no grey square, so greys go to whichever of black and white is
+    /// closer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!('🟦', Colour::BLUE.to_colour_emoji());
+    /// assert_eq!('🟫', Colour::from_rgb(139, 69, 19).to_colour_emoji());
+    /// assert_eq!('🟧', Colour::from_rgb(255, 127, 80).to_colour_emoji());
+    /// assert_eq!('🟥', Colour::from_rgb(128, 0, 0).to_colour_emoji());
+    /// ```
+    pub fn to_colour_emoji(self) -> char {
+        COLOUR_EMOJI
+            .iter()
+            .chain(&REFERENCES)
+            .min_by(|(_, a), (_, b)| self.delta_e(*a).total_cmp(&self.delta_e(*b)))
+            .map_or('⬛', |&(emoji, _)| emoji)
+    }
+}
+
+/// Draw pixels as a grid of coloured square emoji, see [`Colour::to_colour_emoji`]
+///
+/// Rows are `width` pixels wide and separated by newlines. The last row is
+/// shorter if the pixels don't fill it.
+///
+/// # Panics
+///
+/// If `width` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+///
+/// let pixels = [
+///     Colour::RED, Colour::WHITE, Colour::RED,
+///     Colour::WHITE, Colour::BLUE, Colour::WHITE,
+/// ];
+/// assert_eq!("🟥⬜🟥\n⬜🟦⬜", chrom::emoji_grid(&pixels, 3));
+/// assert_eq!("", chrom::emoji_grid::<Colour>(&[], 3));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn emoji_grid<C: ColourLike>(pixels: &[C], width: usize) -> String {
+    pixels
+        .chunks(width)
+        .map(|row| {
+            row.iter()
+                .map(|pixel| pixel.as_colour().to_colour_emoji())
+                .collect()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
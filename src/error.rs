@@ -0,0 +1,50 @@
+use core::{error, fmt};
+
+/// An error returned when a colour can't be represented in sRGB
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, OutOfGamutError};
+///
+/// assert_eq!(Err(OutOfGamutError), Colour::from_lab_checked([50.0, 120.0, 0.0]));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OutOfGamutError;
+
+impl fmt::Display for OutOfGamutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("colour is outside of the sRGB gamut")
+    }
+}
+
+impl error::Error for OutOfGamutError {}
+
+/// An error returned when a value doesn't fit in the 24 bits of a colour
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ColourRangeError};
+///
+/// let error = Colour::from_u32_checked(0x1000000).unwrap_err();
+/// assert_eq!(ColourRangeError(0x1000000), error);
+/// assert_eq!(
+///     "value 16777216 (0x1000000) is outside of the 24-bit colour range",
+///     error.to_string(),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ColourRangeError(pub u32);
+
+impl fmt::Display for ColourRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "value {} ({:#x}) is outside of the 24-bit colour range",
+            self.0, self.0
+        )
+    }
+}
+
+impl error::Error for ColourRangeError {}
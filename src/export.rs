@@ -0,0 +1,263 @@
+//! Reading and writing palette files for design tools
+//!
+//! GIMP palettes (`.gpl`) are text with a name for each colour, and Adobe
+//! Color swatches (`.aco`) are binary and used by Photoshop.
+//!
+//! # Examples
+//!
+//! ```
+//! use chrom::{export, Colour};
+//!
+//! let gpl = export::to_gpl(&[(Colour::RED, Some("Brand")), (Colour::BLUE, None)], "Site");
+//! let palette = export::from_gpl(&gpl).unwrap();
+//! assert_eq!(Some("Site"), palette.name.as_deref());
+//! assert_eq!(vec![(Colour::RED, Some("Brand".to_owned())), (Colour::BLUE, None)], palette.colours);
+//!
+//! let aco = export::to_aco(&[Colour::RED, Colour::BLUE]);
+//! assert_eq!(Ok(vec![Colour::RED, Colour::BLUE]), export::from_aco(&aco));
+//! ```
+
+use crate::Colour;
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+use core::{error, fmt};
+
+const GPL_HEADER: &str = "GIMP Palette";
+
+/// The colour space of an RGB record in an `.aco` file
+const ACO_RGB: u16 = 0;
+
+/// An error returned when a string can't be parsed as a GIMP palette
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseGplError {
+    /// The first line isn't `GIMP Palette`
+    MissingHeader,
+    /// A line isn't a comment, a header or three channels from 0 to 255,
+    /// with the line number starting from 1
+    InvalidLine(usize),
+}
+
+impl fmt::Display for ParseGplError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingHeader => write!(f, "palette doesn't start with {GPL_HEADER:?}"),
+            Self::InvalidLine(line) => write!(f, "invalid palette entry on line {line}"),
+        }
+    }
+}
+
+impl error::Error for ParseGplError {}
+
+/// An error returned when bytes can't be parsed as an Adobe Color swatch
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseAcoError {
+    /// The file ends before all of the colours it says it has
+    Truncated,
+    /// The file has a version other than 1 or 2
+    UnsupportedVersion(u16),
+    /// A colour is in a colour space other than RGB, which is `0`
+    UnsupportedColourSpace(u16),
+}
+
+impl fmt::Display for ParseAcoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated => f.write_str("swatch file is truncated"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported swatch file version {version}")
+            }
+            Self::UnsupportedColourSpace(space) => {
+                write!(
+                    f,
+                    "unsupported swatch colour space {space}, expected RGB (0)"
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for ParseAcoError {}
+
+/// A palette read from a GIMP palette file by [`from_gpl`]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GplPalette {
+    /// The name of the palette, or `None` if it doesn't have one
+    pub name: Option<String>,
+    /// Each colour, with its name if it has one
+    pub colours: Vec<(Colour, Option<String>)>,
+}
+
+/// Write colours as a GIMP palette (`.gpl`) called `name`
+///
+/// Colours without a name are written without one, which GIMP shows as
+/// "Untitled". Line breaks in names would end the line early, so they are
+/// replaced with spaces.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{export, Colour};
+///
+/// let gpl = export::to_gpl(&[(Colour::BLUE, Some("Sky")), (Colour::BLACK, None)], "Brand");
+/// assert_eq!("GIMP Palette\nName: Brand\n#\n 52 152 219\tSky\n  0   0   0\n", gpl);
+/// ```
+pub fn to_gpl(colours: &[(Colour, Option<&str>)], name: &str) -> String {
+    let one_line = |s: &str| s.replace(['\r', '\n'], " ");
+    let mut gpl = format!("{GPL_HEADER}\nName: {}\n#\n", one_line(name));
+    for (colour, name) in colours {
+        let (red, green, blue) = (colour.red(), colour.green(), colour.blue());
+        gpl += &format!("{red:3} {green:3} {blue:3}");
+        if let Some(name) = name {
+            gpl += &format!("\t{}", one_line(name));
+        }
+        gpl.push('\n');
+    }
+    gpl
+}
+
+/// Read a GIMP palette (`.gpl`)
+///
+/// Comments, blank lines and headers other than `Name` are skipped, and names
+/// of colours have whitespace trimmed from either end.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{export, Colour};
+/// use export::ParseGplError;
+///
+/// let palette = export::from_gpl("GIMP Palette\nName: Brand\nColumns: 4\n#\n 52 152 219\tSky\n").unwrap();
+/// assert_eq!(Some("Brand"), palette.name.as_deref());
+/// assert_eq!(vec![(Colour::BLUE, Some("Sky".to_owned()))], palette.colours);
+///
+/// assert_eq!(Err(ParseGplError::MissingHeader), export::from_gpl("52 152 219"));
+/// assert_eq!(Err(ParseGplError::InvalidLine(3)), export::from_gpl("GIMP Palette\n#\n52 152\n"));
+/// assert_eq!(Err(ParseGplError::InvalidLine(2)), export::from_gpl("GIMP Palette\n52 152 256\n"));
+/// ```
+pub fn from_gpl(s: &str) -> Result<GplPalette, ParseGplError> {
+    let mut lines = s.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header.trim() == GPL_HEADER => {}
+        _ => return Err(ParseGplError::MissingHeader),
+    }
+
+    let mut palette = GplPalette::default();
+    for (i, line) in lines {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix("Name:") {
+            palette.name = Some(name.trim().to_owned());
+            continue;
+        }
+        if line.starts_with("Columns:") {
+            continue;
+        }
+
+        let mut words = line.split_whitespace();
+        let mut channel = || words.next()?.parse::<u8>().ok();
+        let (Some(red), Some(green), Some(blue)) = (channel(), channel(), channel()) else {
+            return Err(ParseGplError::InvalidLine(i + 1));
+        };
+        let name = words.collect::<Vec<_>>().join(" ");
+        let name = (!name.is_empty()).then_some(name);
+        palette
+            .colours
+            .push((Colour::from_rgb(red, green, blue), name));
+    }
+    Ok(palette)
+}
+
+/// Write colours as an Adobe Color swatch (`.aco`)
+///
+/// This writes a version 1 file, which has RGB colours without names, with
+/// each channel scaled to 16 bits.
+///
+/// # Panics
+///
+/// Panics if there are more than 65535 colours, which don't fit in the file.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{export, Colour};
+///
+/// assert_eq!(
+///     vec![0, 1, 0, 1, 0, 0, 0x34, 0x34, 0x98, 0x98, 0xdb, 0xdb, 0, 0],
+///     export::to_aco(&[Colour::BLUE]),
+/// );
+/// ```
+pub fn to_aco(colours: &[Colour]) -> Vec<u8> {
+    let count = u16::try_from(colours.len()).expect("too many colours for a swatch file");
+    let mut aco = Vec::with_capacity(4 + colours.len() * 10);
+    for word in [1, count] {
+        aco.extend(word.to_be_bytes());
+    }
+    for colour in colours {
+        let [red, green, blue] = [colour.red(), colour.green(), colour.blue()].map(u16::from);
+        for word in [ACO_RGB, red * 257, green * 257, blue * 257, 0] {
+            aco.extend(word.to_be_bytes());
+        }
+    }
+    aco
+}
+
+/// Read an Adobe Color swatch (`.aco`)
+///
+/// Files from Photoshop have a version 1 section followed by a version 2
+/// section with the same colours and their names. Only the first section is
+/// read, so names are discarded. Each channel is the top 8 bits of its 16-bit
+/// value, which reads both the `0xffff` and `0xff00` some tools write for 255
+/// as 255.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{export, Colour};
+/// use export::ParseAcoError;
+///
+/// let aco = [0, 1, 0, 1, 0, 0, 0x34, 0x00, 0x98, 0x00, 0xdb, 0x00, 0, 0];
+/// assert_eq!(Ok(vec![Colour::BLUE]), export::from_aco(&aco));
+///
+/// assert_eq!(Err(ParseAcoError::Truncated), export::from_aco(&aco[..13]));
+/// assert_eq!(Err(ParseAcoError::UnsupportedVersion(3)), export::from_aco(&[0, 3, 0, 0]));
+/// assert_eq!(
+///     Err(ParseAcoError::UnsupportedColourSpace(2)),
+///     export::from_aco(&[0, 1, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0]),
+/// );
+/// ```
+pub fn from_aco(bytes: &[u8]) -> Result<Vec<Colour>, ParseAcoError> {
+    let mut words = bytes
+        .chunks(2)
+        .map(|chunk| <[u8; 2]>::try_from(chunk).map(u16::from_be_bytes));
+    let mut word = || match words.next() {
+        Some(Ok(word)) => Ok(word),
+        _ => Err(ParseAcoError::Truncated),
+    };
+
+    let version = word()?;
+    if version != 1 && version != 2 {
+        return Err(ParseAcoError::UnsupportedVersion(version));
+    }
+    let count = word()?;
+    let mut colours = Vec::with_capacity(count.into());
+    for _ in 0..count {
+        let space = word()?;
+        let [red, green, blue, _] = [word()?, word()?, word()?, word()?];
+        if space != ACO_RGB {
+            return Err(ParseAcoError::UnsupportedColourSpace(space));
+        }
+        let [red, green, blue] = [red, green, blue].map(|channel| channel.to_be_bytes()[0]);
+        colours.push(Colour::from_rgb(red, green, blue));
+
+        if version == 2 {
+            // The name, as a length in UTF-16 code units split into two
+            // words, then the code units including a null terminator
+            let length = (u32::from(word()?) << 16) | u32::from(word()?);
+            for _ in 0..length {
+                word()?;
+            }
+        }
+    }
+    Ok(colours)
+}
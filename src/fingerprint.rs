@@ -0,0 +1,153 @@
+use crate::{Colour, ColourLike};
+
+/// Mixed into every fingerprint, and changed whenever the algorithm changes
+const VERSION: u8 = 1;
+
+/// How coarsely colours are compared by [`palette_fingerprint`]
+///
+/// Each precision is the size of the cells of a grid in Oklab, which colours
+/// are rounded to before hashing.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum FingerprintPrecision {
+    /// Cells of `0.04`, for colours that look roughly the same
+    Coarse,
+    /// Cells of `0.02`, about the smallest difference that can be seen side by
+    /// side
+    #[default]
+    Medium,
+    /// Cells of `0.01`, for colours that are almost exactly the same
+    Fine,
+}
+
+impl FingerprintPrecision {
+    /// The size of a cell, in hundredths
+    const fn cell_size(self) -> i64 {
+        match self {
+            Self::Coarse => 4,
+            Self::Medium => 2,
+            Self::Fine => 1,
+        }
+    }
+}
+
+/// The fixed point scale used to find Oklab cells, so fingerprints only use
+/// integer arithmetic
+const ONE: i64 = 1 << 20;
+
+/// Get the integer part of the `n`th root of `value`
+fn root(value: u128, n: u32) -> i64 {
+    let (mut low, mut high) = (0_u128, 1 << (128 / n));
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+        if mid.pow(n) <= value {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    low as i64
+}
+
+/// Decode an sRGB channel to linear light, in fixed point
+fn linear(channel: u8) -> i64 {
+    let c = i64::from(channel) * ONE / 255;
+    if channel <= 10 {
+        return c * 100 / 1292;
+    }
+    // ((c + 0.055) / 1.055) ^ 2.4, as x² times the fifth root of x²
+    let x = (c * 1000 + 55 * ONE) / 1055;
+    let squared = x * x / ONE;
+    squared * root(squared as u128 * (ONE as u128).pow(4), 5) / ONE
+}
+
+/// Multiply a fixed point vector by a matrix in units of 10⁻¹⁰
+fn transform(matrix: [[i64; 3]; 3], v: [i64; 3]) -> [i64; 3] {
+    matrix.map(|row| (row[0] * v[0] + row[1] * v[1] + row[2] * v[2]) / 10_000_000_000)
+}
+
+/// Convert a colour to Oklab in fixed point, with the same matrices as
+/// [`Colour::to_oklab`]
+fn oklab(colour: Colour) -> [i64; 3] {
+    let lms = transform(
+        [
+            [4_122_214_708, 5_363_325_363, 514_459_929],
+            [2_119_034_982, 6_806_995_451, 1_073_969_566],
+            [883_024_619, 2_817_188_376, 6_299_787_005],
+        ],
+        [colour.red(), colour.green(), colour.blue()].map(linear),
+    );
+    transform(
+        [
+            [2_104_542_553, 7_936_177_850, -40_720_468],
+            [19_779_984_951, -24_285_922_050, 4_505_937_099],
+            [259_040_371, 7_827_717_662, -8_086_757_660],
+        ],
+        lms.map(|c| root(c.max(0) as u128 * (ONE as u128).pow(2), 3)),
+    )
+}
+
+/// Get a hash of a palette which is the same for palettes that look the same,
+/// for use as a cache key
+///
+/// Each colour is rounded to a grid in Oklab, with cells the size given by
+/// `precision`, and the rounded colours are hashed in order. Palettes with the
+/// same colours in a different order have different fingerprints. As with any
+/// rounding, two colours which are very close but on either side of the edge
+/// of a cell still have different fingerprints.
+///
+/// Fingerprints don't depend on the standard library's hashers or on floating
+/// point, since Oklab is found in fixed point, so they are the same on every
+/// platform and only change between versions of this crate when the
+/// algorithm does. That also
+/// changes a version number mixed into the hash, so old and new fingerprints
+/// don't match.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{palette_fingerprint, Colour, FingerprintPrecision};
+///
+/// let palette = [Colour::BLUE, Colour::GOLD, Colour::RED];
+/// let similar = [
+///     Colour::from_rgb(52, 153, 219),
+///     Colour::from_rgb(241, 196, 14),
+///     Colour::from_rgb(236, 66, 69),
+/// ];
+/// # for (a, b) in palette.iter().zip(similar) {
+/// #     assert!(a.delta_e(b) < 0.5);
+/// # }
+/// let precision = FingerprintPrecision::default();
+/// assert_eq!(palette_fingerprint(&palette, precision), palette_fingerprint(&similar, precision));
+///
+/// let different = [Colour::BLUE, Colour::GOLD, Colour::DARK_RED];
+/// assert_ne!(palette_fingerprint(&palette, precision), palette_fingerprint(&different, precision));
+///
+/// let reordered = [Colour::GOLD, Colour::BLUE, Colour::RED];
+/// assert_ne!(palette_fingerprint(&palette, precision), palette_fingerprint(&reordered, precision));
+///
+/// // Pinned, so that accidental changes to the algorithm are caught
+/// assert_eq!(0x1107_18fa_cf05_3fce, palette_fingerprint(&palette, precision));
+/// ```
+pub fn palette_fingerprint<C: ColourLike>(colours: &[C], precision: FingerprintPrecision) -> u64 {
+    // FNV-1a, which unlike the standard library's hashers is guaranteed to
+    // stay the same
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    let mut hash = OFFSET;
+    let mut write = |bytes: &[u8]| {
+        for &byte in bytes {
+            hash = (hash ^ u64::from(byte)).wrapping_mul(PRIME);
+        }
+    };
+
+    write(&[VERSION, precision as u8]);
+    // Round to the nearest cell, with halves rounded up
+    let cell_size = 2 * precision.cell_size() * ONE;
+    for colour in colours {
+        for component in oklab(colour.as_colour()) {
+            let cell = (200 * component + cell_size / 2).div_euclid(cell_size) as i32;
+            write(&cell.to_le_bytes());
+        }
+    }
+    hash
+}
@@ -0,0 +1,432 @@
+use core::f64::consts::{FRAC_PI_2, LN_2, PI, SQRT_2, TAU};
+
+/// ln 2 split into two parts, where the first has only 32 bits so that
+/// multiplying it by an exponent is exact
+const LN_2_HIGH: f64 = 6.931_471_803_691_238e-1;
+const LN_2_LOW: f64 = 1.908_214_929_270_587_7e-10;
+
+/// The float functions which are only built in with `std`, done in software
+/// for `no_std`
+///
+/// Import it with `#[allow(unused_imports)]`: whenever `std` is linked, even
+/// only by a dependency, the inherent methods are used instead, since they take
+/// precedence over a trait's. The software versions agree with `std` to within
+/// the last bit of an `f32`, and a few bits of an `f64`, but aren't always
+/// exactly the same.
+pub(crate) trait Float: Sized {
+    fn round(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn exp(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn atan2(self, other: Self) -> Self;
+}
+
+impl Float for f64 {
+    fn round(self) -> Self {
+        // Every float this large is already a whole number
+        if self.is_nan() || self.abs() >= 4_503_599_627_370_496.0 {
+            return self;
+        }
+        let whole = self.abs() as u64 as f64;
+        let rounded = if self.abs() - whole >= 0.5 {
+            whole + 1.0
+        } else {
+            whole
+        };
+        if self.is_sign_negative() {
+            -rounded
+        } else {
+            rounded
+        }
+    }
+
+    fn rem_euclid(self, rhs: Self) -> Self {
+        let remainder = self % rhs;
+        if remainder < 0.0 {
+            remainder + rhs.abs()
+        } else {
+            remainder
+        }
+    }
+
+    fn powi(self, n: i32) -> Self {
+        let mut result = 1.0;
+        for _ in 0..n.unsigned_abs() {
+            result *= self;
+        }
+        if n < 0 {
+            1.0 / result
+        } else {
+            result
+        }
+    }
+
+    fn powf(self, n: Self) -> Self {
+        if n == 0.0 {
+            return 1.0;
+        }
+        if self == 0.0 {
+            return if n > 0.0 { 0.0 } else { Self::INFINITY };
+        }
+        if self.is_nan() || n.is_nan() {
+            return Self::NAN;
+        }
+        if Float::round(n) == n && n.abs() <= 64.0 {
+            // Multiplying is more accurate than going through a logarithm
+            return Float::powi(self, n as i32);
+        }
+        let power = exp(n * ln(self.abs()));
+        if self > 0.0 {
+            power
+        } else if Float::round(n) != n {
+            // A negative number has no real root
+            Self::NAN
+        } else if Float::rem_euclid(n, 2.0) == 1.0 {
+            -power
+        } else {
+            power
+        }
+    }
+
+    fn exp(self) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+        exp(self)
+    }
+
+    fn sqrt(self) -> Self {
+        if self.is_nan() || self < 0.0 {
+            return Self::NAN;
+        }
+        sqrt(self)
+    }
+
+    fn cbrt(self) -> Self {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+        // Polish the root from the logarithm with Newton's method
+        let x = self.abs();
+        let mut root = exp(ln(x) / 3.0);
+        root -= (root - x / (root * root)) / 3.0;
+        if self < 0.0 {
+            -root
+        } else {
+            root
+        }
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        let (x, y) = (self.abs(), other.abs());
+        let (large, small) = if x > y { (x, y) } else { (y, x) };
+        if large == 0.0 || large.is_infinite() {
+            return large;
+        }
+        // Scale by the larger side so the squares can't overflow
+        let ratio = small / large;
+        large * sqrt(1.0 + ratio * ratio)
+    }
+
+    fn sin(self) -> Self {
+        if !self.is_finite() {
+            return Self::NAN;
+        }
+        sin(self)
+    }
+
+    fn cos(self) -> Self {
+        if !self.is_finite() {
+            return Self::NAN;
+        }
+        cos(self)
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (Float::sin(self), Float::cos(self))
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        if self.is_nan() || other.is_nan() {
+            return Self::NAN;
+        }
+        let (y, x) = (self, other);
+        // The signs of zeros pick the side, the same as `std`
+        let half_turn = if y.is_sign_negative() { -PI } else { PI };
+        if x > 0.0 {
+            atan(y / x)
+        } else if x < 0.0 {
+            atan(y / x) + half_turn
+        } else if y > 0.0 {
+            FRAC_PI_2
+        } else if y < 0.0 {
+            -FRAC_PI_2
+        } else if x.is_sign_negative() {
+            half_turn
+        } else {
+            y
+        }
+    }
+}
+
+/// Rounding the `f64` result gives the same `f32` as working in `f32`, or a
+/// more accurate one
+impl Float for f32 {
+    fn round(self) -> Self {
+        Float::round(f64::from(self)) as f32
+    }
+
+    fn rem_euclid(self, rhs: Self) -> Self {
+        Float::rem_euclid(f64::from(self), f64::from(rhs)) as f32
+    }
+
+    fn powi(self, n: i32) -> Self {
+        Float::powi(f64::from(self), n) as f32
+    }
+
+    fn powf(self, n: Self) -> Self {
+        Float::powf(f64::from(self), f64::from(n)) as f32
+    }
+
+    fn exp(self) -> Self {
+        Float::exp(f64::from(self)) as f32
+    }
+
+    fn sqrt(self) -> Self {
+        Float::sqrt(f64::from(self)) as f32
+    }
+
+    fn cbrt(self) -> Self {
+        Float::cbrt(f64::from(self)) as f32
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        Float::hypot(f64::from(self), f64::from(other)) as f32
+    }
+
+    fn sin(self) -> Self {
+        Float::sin(f64::from(self)) as f32
+    }
+
+    fn cos(self) -> Self {
+        Float::cos(f64::from(self)) as f32
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (Float::sin(self), Float::cos(self))
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        Float::atan2(f64::from(self), f64::from(other)) as f32
+    }
+}
+
+/// Get the natural logarithm of a positive, finite number
+fn ln(x: f64) -> f64 {
+    // Split x into m × 2^e, with m between √½ and √2
+    let bits = x.to_bits();
+    let mut exponent = ((bits >> 52) & 0x7ff) as i32 - 1023;
+    let mut m = f64::from_bits((bits & ((1 << 52) - 1)) | (1023 << 52));
+    if exponent == -1023 {
+        // Subnormal, so scale it up by 2^54 first
+        return ln(x * 18_014_398_509_481_984.0) - 54.0 * LN_2;
+    }
+    if m > SQRT_2 {
+        m /= 2.0;
+        exponent += 1;
+    }
+
+    // ln m = 2 atanh s, where s is at most about 0.17
+    let s = (m - 1.0) / (m + 1.0);
+    let (mut power, mut sum) = (s, 0.0);
+    for i in 0..12 {
+        sum += power / f64::from(2 * i + 1);
+        power *= s * s;
+    }
+    f64::from(exponent) * LN_2_HIGH + (2.0 * sum + f64::from(exponent) * LN_2_LOW)
+}
+
+/// Get e to the power of a number which isn't NaN
+fn exp(x: f64) -> f64 {
+    if x > 709.0 {
+        return f64::INFINITY;
+    }
+    if x < -708.0 {
+        return 0.0;
+    }
+
+    // e^x = 2^k × e^r, where r is at most ln 2 / 2
+    let k = (x / LN_2 + if x < 0.0 { -0.5 } else { 0.5 }) as i32;
+    let r = x - f64::from(k) * LN_2_HIGH - f64::from(k) * LN_2_LOW;
+    let (mut term, mut sum) = (1.0, 1.0);
+    for i in 1..=14 {
+        term *= r / f64::from(i);
+        sum += term;
+    }
+    sum * f64::from_bits(((k + 1023) as u64) << 52)
+}
+
+/// Get the square root of a number which isn't negative
+fn sqrt(x: f64) -> f64 {
+    if x == 0.0 || x == f64::INFINITY {
+        return x;
+    }
+    // Start from the logarithm and polish the root with Newton's method
+    let mut root = exp(ln(x) / 2.0);
+    for _ in 0..2 {
+        root = (root + x / root) / 2.0;
+    }
+    root
+}
+
+/// Bring a finite angle in radians into -π..=π, where the series for sine
+/// and cosine converge quickly
+fn reduce(x: f64) -> f64 {
+    // 2π split into three parts, where the first two have only 28 bits so
+    // multiplying them by a whole number of turns is exact, up to angles of
+    // about 30 million turns
+    const TAU_1: f64 = 6.283_185_303_211_212;
+    const TAU_2: f64 = 3.968_374_295_837_407e-9;
+    const TAU_3: f64 = 2.288_475_490_443_932_7e-17;
+    let turns = Float::round(x / TAU);
+    if turns.abs() > 33_554_432.0 {
+        return x % TAU;
+    }
+    x - turns * TAU_1 - turns * TAU_2 - turns * TAU_3
+}
+
+/// Get the sine of a finite angle in radians
+fn sin(x: f64) -> f64 {
+    let x = reduce(x);
+    let (mut term, mut sum) = (x, x);
+    for i in 1..=16 {
+        term *= -x * x / f64::from(2 * i * (2 * i + 1));
+        sum += term;
+    }
+    sum
+}
+
+/// Get the cosine of a finite angle in radians
+fn cos(x: f64) -> f64 {
+    let x = reduce(x);
+    let (mut term, mut sum) = (1.0, 1.0);
+    for i in 1..=16 {
+        term *= -x * x / f64::from((2 * i - 1) * (2 * i));
+        sum += term;
+    }
+    sum
+}
+
+/// Get the arctangent of a number, in radians
+fn atan(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x.abs() > 1.0 {
+        let angle = FRAC_PI_2 - atan(1.0 / x.abs());
+        return if x < 0.0 { -angle } else { angle };
+    }
+    // atan x = 2 atan (x / (1 + √(1 + x²))), done twice so the series
+    // converges quickly
+    let mut x = x;
+    for _ in 0..2 {
+        x /= 1.0 + sqrt(1.0 + x * x);
+    }
+    let (mut power, mut sum) = (x, 0.0);
+    for i in 0..14 {
+        let term = power / f64::from(2 * i + 1);
+        sum += if i % 2 == 0 { term } else { -term };
+        power *= x * x;
+    }
+    4.0 * sum
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::Float;
+    use std::format;
+
+    /// Values at the edges of each function's cases
+    const EDGES: [f32; 9] = [0.0, -0.0, 0.5, -0.5, 1.5, -2.5, 1e-6, 1e6, 8_388_607.5];
+
+    /// Inputs spread over the ranges the crate uses
+    fn inputs() -> impl Iterator<Item = f32> {
+        (-2000..=2000).map(|i| i as f32 / 100.0).chain(EDGES)
+    }
+
+    /// Check each software result against `std`, to within `ulps` units in
+    /// the last place
+    macro_rules! assert_close {
+        ($ty:ty, $ulps:expr, $expected:expr, $actual:expr, $($what:tt)*) => {{
+            let (expected, actual): ($ty, $ty) = ($expected, $actual);
+            let what = format!($($what)*);
+            if expected.is_nan() {
+                assert!(actual.is_nan(), "{what}: expected NaN, got {actual}");
+            } else if expected != actual {
+                let tolerance = (expected.abs() * $ulps * <$ty>::EPSILON).max(1e-12);
+                assert!(
+                    (expected - actual).abs() <= tolerance,
+                    "{what}: expected {expected}, got {actual}"
+                );
+            }
+        }};
+    }
+
+    /// Check every function for one type
+    macro_rules! check {
+        ($ty:ty, $ulps:expr) => {{
+            for x in inputs().map(<$ty>::from) {
+                assert_eq!(x.round(), Float::round(x), "round({x})");
+                for rhs in [1.0, 360.0, -7.5] {
+                    assert_eq!(x.rem_euclid(rhs), Float::rem_euclid(x, rhs), "{x} % {rhs}");
+                }
+
+                for n in [-3, 0, 1, 2, 3] {
+                    assert_close!($ty, $ulps, x.powi(n), Float::powi(x, n), "{x}^{n}");
+                }
+                for n in [0.0, 1.0 / 2.4, 2.4, 3.0] {
+                    // The error grows with the size of the exponent of e
+                    let ulps = $ulps * (1.0 + (n * x.abs().ln()).abs());
+                    assert_close!($ty, ulps, x.powf(n), Float::powf(x, n), "{x}^{n}");
+                }
+                assert_close!($ty, $ulps, x.sqrt(), Float::sqrt(x), "sqrt({x})");
+                assert_close!($ty, $ulps, x.cbrt(), Float::cbrt(x), "cbrt({x})");
+                let hypot = Float::hypot(x, 3.0);
+                assert_close!($ty, $ulps, x.hypot(3.0), hypot, "hypot({x})");
+
+                assert_close!($ty, $ulps, x.sin(), Float::sin(x), "sin({x})");
+                assert_close!($ty, $ulps, x.cos(), Float::cos(x), "cos({x})");
+                let (sin, cos) = Float::sin_cos(x);
+                assert_eq!((Float::sin(x), Float::cos(x)), (sin, cos), "sin_cos({x})");
+                for other in [-1.0, -0.0, 0.0, 0.3, 2.0] {
+                    let atan2 = Float::atan2(x, other);
+                    assert_close!($ty, $ulps, x.atan2(other), atan2, "atan2({x}, {other})");
+                }
+            }
+            for x in (-800..=800).map(|i| <$ty>::from(i as f32 / 10.0)) {
+                assert_close!($ty, $ulps, x.exp(), Float::exp(x), "exp({x})");
+            }
+            assert_eq!(0.0, Float::sin(0.0 as $ty));
+            assert!(Float::sin(<$ty>::INFINITY).is_nan());
+        }};
+    }
+
+    #[test]
+    fn f32_matches_std() {
+        check!(f32, 2.0);
+    }
+
+    #[test]
+    fn f64_matches_std() {
+        check!(f64, 8.0);
+    }
+}
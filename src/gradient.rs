@@ -0,0 +1,564 @@
+use crate::Colour;
+#[cfg(feature = "alloc")]
+use crate::ColourLike;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::ops::Range;
+use core::{array, error, fmt};
+
+/// A colour at a position along a [`Gradient`]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ColourStop {
+    /// Position in the range `0.0..=1.0`
+    pub position: f32,
+    pub colour: Colour,
+}
+
+impl ColourStop {
+    /// Make a new colour stop
+    pub const fn new(position: f32, colour: Colour) -> Self {
+        Self { position, colour }
+    }
+}
+
+/// An error returned when colour stops don't make a valid gradient
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientError {
+    /// There are no stops
+    Empty,
+    /// A stop has a position outside of the range `0.0..=1.0`
+    OutOfRange(f32),
+    /// The stops aren't sorted by position
+    Unsorted,
+}
+
+impl fmt::Display for GradientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("gradient has no stops"),
+            Self::OutOfRange(position) => {
+                write!(f, "stop position {position} is outside of the range 0 to 1")
+            }
+            Self::Unsorted => f.write_str("gradient stops aren't sorted by position"),
+        }
+    }
+}
+
+impl error::Error for GradientError {}
+
+/// A gradient between colour stops
+///
+/// The stops are stored in `S`, which is a [`Vec`] by default. An array or a
+/// slice can be used instead where allocation isn't possible.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ColourStop, Gradient};
+///
+/// let gradient = Gradient::new(Colour::BLACK, Colour::WHITE);
+/// assert_eq!(Colour::new(0x808080), gradient.at(0.5));
+///
+/// let stops = [
+///     ColourStop::new(0.0, Colour::RED),
+///     ColourStop::new(0.8, Colour::GOLD),
+///     ColourStop::new(1.0, Colour::GREEN),
+/// ];
+/// let gradient = Gradient::from_stops(stops).unwrap();
+/// assert_eq!(Colour::GOLD, gradient.at(0.8));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient<S = Vec<ColourStop>> {
+    stops: S,
+}
+
+/// A gradient between colour stops
+///
+/// The stops are stored in `S`, which is an array or a slice. With the
+/// `alloc` feature, `S` is a `Vec` by default.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ColourStop, Gradient};
+///
+/// const TRAFFIC_LIGHT: Gradient<[ColourStop; 3]> = Gradient::traffic_light();
+/// assert_eq!(Colour::YELLOW, TRAFFIC_LIGHT.at(0.5));
+/// assert_eq!([Colour::GREEN, Colour::YELLOW, Colour::RED], TRAFFIC_LIGHT.steps_array());
+/// ```
+#[cfg(not(feature = "alloc"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient<S> {
+    stops: S,
+}
+
+#[cfg(feature = "alloc")]
+impl Gradient {
+    /// Make a new gradient between two colours
+    pub fn new(start: impl ColourLike, end: impl ColourLike) -> Self {
+        Self {
+            stops: vec![
+                ColourStop::new(0.0, start.as_colour()),
+                ColourStop::new(1.0, end.as_colour()),
+            ],
+        }
+    }
+
+    /// Make a new gradient with evenly spaced colours
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Gradient, GradientError};
+    ///
+    /// let gradient = Gradient::from_colours(&[Colour::RED, Colour::GOLD, Colour::GREEN]).unwrap();
+    /// assert_eq!(Colour::GOLD, gradient.at(0.5));
+    ///
+    /// assert_eq!(Err(GradientError::Empty), Gradient::from_colours::<Colour>(&[]));
+    /// ```
+    pub fn from_colours<C: ColourLike>(colours: &[C]) -> Result<Self, GradientError> {
+        let last = colours.len().saturating_sub(1).max(1) as f32;
+        Self::from_stops(
+            colours
+                .iter()
+                .enumerate()
+                .map(|(i, colour)| ColourStop::new(i as f32 / last, colour.as_colour()))
+                .collect(),
+        )
+    }
+}
+
+impl Gradient<[ColourStop; 2]> {
+    /// Make a gradient from black at `0.0` to white at `1.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourStop, Gradient};
+    ///
+    /// const GREYSCALE: Gradient<[ColourStop; 2]> = Gradient::greyscale();
+    /// let gradient = GREYSCALE;
+    /// assert_eq!(Colour::BLACK, gradient.at(0.0));
+    /// assert_eq!(Colour::new(0x808080), gradient.at(0.5));
+    /// assert_eq!(Colour::WHITE, gradient.at(1.0));
+    /// ```
+    pub const fn greyscale() -> Self {
+        Self {
+            stops: [
+                ColourStop::new(0.0, Colour::BLACK),
+                ColourStop::new(1.0, Colour::WHITE),
+            ],
+        }
+    }
+}
+
+impl Gradient<[ColourStop; 3]> {
+    const fn three_stops(start: Colour, middle: Colour, end: Colour) -> Self {
+        Self {
+            stops: [
+                ColourStop::new(0.0, start),
+                ColourStop::new(0.5, middle),
+                ColourStop::new(1.0, end),
+            ],
+        }
+    }
+
+    /// Make a gradient from green through yellow to red, where `0.0` is good
+    /// and `1.0` is bad
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Gradient};
+    ///
+    /// let gradient = Gradient::traffic_light();
+    /// assert_eq!(Colour::GREEN, gradient.at(0.0));
+    /// assert_eq!(Colour::YELLOW, gradient.at(0.5));
+    /// assert_eq!(Colour::RED, gradient.at(1.0));
+    /// assert_eq!(Colour::new(0xabed72), gradient.at(0.25));
+    /// ```
+    pub const fn traffic_light() -> Self {
+        Self::three_stops(Colour::GREEN, Colour::YELLOW, Colour::RED)
+    }
+
+    /// Make a gradient from red through yellow to green, where `0.0` is empty
+    /// and `1.0` is full
+    ///
+    /// This is [`Gradient::traffic_light`] the other way round.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Gradient};
+    ///
+    /// let gradient = Gradient::battery();
+    /// assert_eq!(Colour::RED, gradient.at(0.0));
+    /// assert_eq!(Colour::YELLOW, gradient.at(0.5));
+    /// assert_eq!(Colour::GREEN, gradient.at(1.0));
+    /// # #[cfg(feature = "alloc")]
+    /// assert_eq!(Gradient::traffic_light().reversed().stops(), gradient.stops());
+    /// ```
+    pub const fn battery() -> Self {
+        Self::three_stops(Colour::RED, Colour::YELLOW, Colour::GREEN)
+    }
+
+    /// Make a diverging gradient from blue through white to red, where `0.0`
+    /// is cold, `0.5` is neutral and `1.0` is hot
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Gradient};
+    ///
+    /// let gradient = Gradient::temperature();
+    /// assert_eq!(Colour::BLUE, gradient.at(0.0));
+    /// assert_eq!(Colour::WHITE, gradient.at(0.5));
+    /// assert_eq!(Colour::RED, gradient.at(1.0));
+    /// assert_eq!(Colour::new(0x9acced), gradient.at(0.25));
+    /// ```
+    pub const fn temperature() -> Self {
+        Self::three_stops(Colour::BLUE, Colour::WHITE, Colour::RED)
+    }
+}
+
+impl<S: AsRef<[ColourStop]>> Gradient<S> {
+    /// Make a new gradient using colour stops
+    ///
+    /// There must be at least one stop, and the stops must be sorted by
+    /// position. Two stops can share a position to make a hard edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourStop, Gradient, GradientError};
+    ///
+    /// let stops = [ColourStop::new(0.5, Colour::RED), ColourStop::new(0.2, Colour::BLUE)];
+    /// assert_eq!(Err(GradientError::Unsorted), Gradient::from_stops(stops));
+    ///
+    /// let stops = [ColourStop::new(1.5, Colour::RED)];
+    /// assert_eq!(Err(GradientError::OutOfRange(1.5)), Gradient::from_stops(stops));
+    /// ```
+    pub fn from_stops(stops: S) -> Result<Self, GradientError> {
+        let slice = stops.as_ref();
+        if slice.is_empty() {
+            return Err(GradientError::Empty);
+        }
+        if let Some(stop) = slice
+            .iter()
+            .find(|stop| !(0.0..=1.0).contains(&stop.position))
+        {
+            return Err(GradientError::OutOfRange(stop.position));
+        }
+        if slice.windows(2).any(|w| w[0].position > w[1].position) {
+            return Err(GradientError::Unsorted);
+        }
+
+        Ok(Self { stops })
+    }
+
+    /// Get the stops of the gradient
+    pub fn stops(&self) -> &[ColourStop] {
+        self.stops.as_ref()
+    }
+
+    /// Get the colour at a position along the gradient
+    ///
+    /// Positions are clamped to the range `0.0..=1.0`. Before the first stop and
+    /// after the last, the colour of that stop is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourStop, Gradient};
+    ///
+    /// let stops = [ColourStop::new(0.25, Colour::BLACK), ColourStop::new(0.75, Colour::WHITE)];
+    /// let gradient = Gradient::from_stops(stops).unwrap();
+    /// assert_eq!(Colour::BLACK, gradient.at(0.1));
+    /// assert_eq!(Colour::new(0x808080), gradient.at(0.5));
+    /// assert_eq!(Colour::WHITE, gradient.at(2.0));
+    /// ```
+    pub fn at(&self, t: f32) -> Colour {
+        let stops = self.stops();
+        let t = t.clamp(0.0, 1.0);
+        // The first stop at or after `t`, found with a binary search since the
+        // stops are sorted
+        match stops.partition_point(|stop| stop.position < t || t.is_nan()) {
+            0 => stops[0].colour,
+            i if i == stops.len() => stops[i - 1].colour,
+            i => {
+                let (start, end) = (stops[i - 1], stops[i]);
+                let t = (t - start.position) / (end.position - start.position);
+                start.colour.mix(end.colour, t)
+            }
+        }
+    }
+
+    /// Get `n` evenly spaced colours along the gradient, including both ends
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Gradient};
+    ///
+    /// let gradient = Gradient::new(Colour::BLACK, Colour::WHITE);
+    /// assert_eq!(
+    ///     vec![Colour::BLACK, Colour::new(0x808080), Colour::WHITE],
+    ///     gradient.steps(3),
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn steps(&self, n: usize) -> Vec<Colour> {
+        let last = n.saturating_sub(1).max(1) as f32;
+        (0..n).map(|i| self.at(i as f32 / last)).collect()
+    }
+
+    /// Get `N` evenly spaced colours along the gradient, including both ends
+    ///
+    /// This is the same as [`Gradient::steps`] without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourStop, Gradient};
+    ///
+    /// let stops = [ColourStop::new(0.0, Colour::BLACK), ColourStop::new(1.0, Colour::WHITE)];
+    /// let gradient = Gradient::from_stops(stops).unwrap();
+    /// assert_eq!([Colour::BLACK, Colour::new(0x808080), Colour::WHITE], gradient.steps_array());
+    /// # #[cfg(feature = "alloc")]
+    /// assert_eq!(gradient.steps(5)[..], gradient.steps_array::<5>());
+    /// ```
+    pub fn steps_array<const N: usize>(&self) -> [Colour; N] {
+        let last = N.saturating_sub(1).max(1) as f32;
+        array::from_fn(|i| self.at(i as f32 / last))
+    }
+
+    /// Split the gradient into `n` equal buckets, like the entries of a map
+    /// legend
+    ///
+    /// Each bucket has its range of positions and the colour at the middle of
+    /// that range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Gradient};
+    ///
+    /// let gradient = Gradient::new(Colour::BLACK, Colour::WHITE);
+    /// assert_eq!(
+    ///     vec![
+    ///         (0.0..0.25, Colour::new(0x202020)),
+    ///         (0.25..0.5, Colour::new(0x606060)),
+    ///         (0.5..0.75, Colour::new(0x9f9f9f)),
+    ///         (0.75..1.0, Colour::new(0xdfdfdf)),
+    ///     ],
+    ///     gradient.buckets(4),
+    /// );
+    /// assert_eq!(vec![(0.0..1.0, Colour::new(0x808080))], gradient.buckets(1));
+    /// assert!(gradient.buckets(0).is_empty());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn buckets(&self, n: usize) -> Vec<(Range<f32>, Colour)> {
+        (0..n)
+            .map(|i| {
+                let range = i as f32 / n as f32..(i + 1) as f32 / n as f32;
+                let colour = self.at((range.start + range.end) / 2.0);
+                (range, colour)
+            })
+            .collect()
+    }
+
+    /// Split the gradient into buckets at the given positions
+    ///
+    /// There is one more bucket than there are breaks, and each bucket has its
+    /// range of positions and the colour at the middle of that range. The
+    /// breaks must be sorted and in the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Gradient, GradientError};
+    ///
+    /// let gradient = Gradient::new(Colour::BLACK, Colour::WHITE);
+    /// assert_eq!(
+    ///     Ok(vec![(0.0..0.5, Colour::new(0x404040)), (0.5..1.0, Colour::new(0xbfbfbf))]),
+    ///     gradient.buckets_at(&[0.5]),
+    /// );
+    /// assert_eq!(
+    ///     Ok(vec![(0.0..0.2, Colour::new(0x1a1a1a)), (0.2..1.0, Colour::new(0x999999))]),
+    ///     gradient.buckets_at(&[0.2]),
+    /// );
+    /// assert_eq!(Ok(gradient.buckets(1)), gradient.buckets_at(&[]));
+    /// # assert_eq!(Ok(gradient.buckets(4)), gradient.buckets_at(&[0.25, 0.5, 0.75]));
+    ///
+    /// assert_eq!(Err(GradientError::Unsorted), gradient.buckets_at(&[0.6, 0.4]));
+    /// assert_eq!(Err(GradientError::OutOfRange(1.5)), gradient.buckets_at(&[1.5]));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn buckets_at(&self, breaks: &[f32]) -> Result<Vec<(Range<f32>, Colour)>, GradientError> {
+        if let Some(&position) = breaks
+            .iter()
+            .find(|position| !(0.0..=1.0).contains(*position))
+        {
+            return Err(GradientError::OutOfRange(position));
+        }
+        if breaks.windows(2).any(|w| w[0] > w[1]) {
+            return Err(GradientError::Unsorted);
+        }
+
+        let starts = [0.0].into_iter().chain(breaks.iter().copied());
+        let ends = breaks.iter().copied().chain([1.0]);
+        Ok(starts
+            .zip(ends)
+            .map(|(start, end)| (start..end, self.at((start + end) / 2.0)))
+            .collect())
+    }
+
+    /// Get the gradient running in the opposite direction
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourStop, Gradient};
+    ///
+    /// let stops = [ColourStop::new(0.0, Colour::RED), ColourStop::new(0.25, Colour::BLUE)];
+    /// let gradient = Gradient::from_stops(stops).unwrap();
+    /// let reversed = gradient.reversed();
+    /// assert_eq!(
+    ///     [ColourStop::new(0.75, Colour::BLUE), ColourStop::new(1.0, Colour::RED)],
+    ///     reversed.stops(),
+    /// );
+    /// assert_eq!(gradient.stops(), reversed.reversed().stops());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn reversed(&self) -> Gradient {
+        Gradient {
+            stops: self
+                .stops()
+                .iter()
+                .rev()
+                .map(|stop| ColourStop::new(1.0 - stop.position, stop.colour))
+                .collect(),
+        }
+    }
+
+    /// Get the part of the gradient between two positions, stretched to fill
+    /// the whole range
+    ///
+    /// Positions are clamped to the range `0.0..=1.0`, and stops are added at
+    /// both ends with the colours the gradient has there. If `from` is after
+    /// `to` the part between them is reversed. If they are equal the result is
+    /// a single colour.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourStop, Gradient};
+    ///
+    /// let gradient = Gradient::from_colours(&[Colour::BLACK, Colour::RED, Colour::WHITE]).unwrap();
+    /// let slice = gradient.slice(0.25, 0.75);
+    /// assert_eq!(
+    ///     [
+    ///         ColourStop::new(0.0, Colour::new(0x772123)),
+    ///         ColourStop::new(0.5, Colour::RED),
+    ///         ColourStop::new(1.0, Colour::new(0xf6a1a2)),
+    ///     ],
+    ///     slice.stops(),
+    /// );
+    /// assert_eq!(gradient.slice(0.25, 0.75).reversed(), gradient.slice(0.75, 0.25));
+    ///
+    /// let single = gradient.slice(0.5, 0.5);
+    /// assert_eq!([ColourStop::new(0.0, Colour::RED)], single.stops());
+    /// assert_eq!(Colour::RED, single.at(0.7));
+    /// # assert_eq!(gradient.stops(), gradient.slice(-1.0, 2.0).stops());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn slice(&self, from: f32, to: f32) -> Gradient {
+        let (from, to) = (from.clamp(0.0, 1.0), to.clamp(0.0, 1.0));
+        if from > to {
+            return self.slice(to, from).reversed();
+        }
+        if from == to {
+            return Gradient {
+                stops: vec![ColourStop::new(0.0, self.at(from))],
+            };
+        }
+
+        let width = to - from;
+        let inner = self
+            .stops()
+            .iter()
+            .filter(|stop| from < stop.position && stop.position < to)
+            .map(|stop| {
+                let position = ((stop.position - from) / width).clamp(0.0, 1.0);
+                ColourStop::new(position, stop.colour)
+            });
+        let mut stops = vec![ColourStop::new(0.0, self.at(from))];
+        stops.extend(inner);
+        stops.push(ColourStop::new(1.0, self.at(to)));
+        Gradient { stops }
+    }
+
+    /// Join another gradient onto the end of this one, each taking half of the
+    /// range
+    ///
+    /// See [`Gradient::concat_at`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Gradient};
+    ///
+    /// let gradient = Gradient::new(Colour::BLACK, Colour::RED)
+    ///     .concat(&Gradient::new(Colour::RED, Colour::WHITE));
+    /// assert_eq!(Colour::RED, gradient.at(0.5));
+    /// assert_eq!(Colour::new(0x772123), gradient.at(0.25));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn concat<T: AsRef<[ColourStop]>>(&self, other: &Gradient<T>) -> Gradient {
+        self.concat_at(other, 0.5)
+    }
+
+    /// Join another gradient onto the end of this one, with this gradient
+    /// squeezed into `0.0..split` and the other into `split..1.0`
+    ///
+    /// `split` is clamped to the range `0.0..=1.0`. If the gradients don't
+    /// meet at the same colour, there is a hard edge at `split`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourStop, Gradient};
+    ///
+    /// let first = Gradient::new(Colour::BLACK, Colour::WHITE);
+    /// let second = Gradient::new(Colour::RED, Colour::BLUE);
+    /// assert_eq!(
+    ///     [
+    ///         ColourStop::new(0.0, Colour::BLACK),
+    ///         ColourStop::new(0.25, Colour::WHITE),
+    ///         ColourStop::new(0.25, Colour::RED),
+    ///         ColourStop::new(1.0, Colour::BLUE),
+    ///     ],
+    ///     first.concat_at(&second, 0.25).stops(),
+    /// );
+    /// # let joined = first.concat_at(&second, 0.3);
+    /// # assert_eq!(Some(1.0), joined.stops().last().map(|stop| stop.position));
+    /// # assert_eq!(first.stops(), first.concat_at(&second, 1.0).slice(0.0, 1.0).stops());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn concat_at<T: AsRef<[ColourStop]>>(&self, other: &Gradient<T>, split: f32) -> Gradient {
+        let split = split.clamp(0.0, 1.0);
+        let first = self
+            .stops()
+            .iter()
+            .map(|stop| ColourStop::new(stop.position * split, stop.colour));
+        let second = other.stops().iter().map(|stop| {
+            let position = (split + stop.position * (1.0 - split)).min(1.0);
+            ColourStop::new(position, stop.colour)
+        });
+        Gradient {
+            stops: first.chain(second).collect(),
+        }
+    }
+}
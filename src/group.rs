@@ -0,0 +1,62 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::ColourLike;
+use alloc::{collections::BTreeMap, vec::Vec};
+
+/// Group colours into families of similar hue
+///
+/// Colours are put into buckets of `bucket_degrees` by their Oklch hue, so
+/// shades of the same colour end up together whatever their lightness. The
+/// first bucket is centred on 0°, so reds either side of it stay together.
+/// Greys, with an Oklch chroma below `0.03`, go into their own group at the
+/// end. The other groups are sorted by hue, and colours keep their order within
+/// a group.
+///
+/// # Panics
+///
+/// If `bucket_degrees` isn't positive.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+///
+/// let light_blue = Colour::from_rgb(160, 192, 255);
+/// let navy = Colour::from_rgb(0, 0, 128);
+/// let orange = Colour::from_rgb(255, 165, 0);
+/// assert_eq!(
+///     vec![vec![orange], vec![light_blue, navy], vec![Colour::GREY]],
+///     chrom::group_by_hue(&[light_blue, Colour::GREY, orange, navy], 30.0),
+/// );
+///
+/// // Hues of 357° and 2° are the same family
+/// let crimson = Colour::new(0xff0090);
+/// let rose = Colour::new(0xff0080);
+/// assert_eq!(
+///     vec![vec![crimson, rose]],
+///     chrom::group_by_hue(&[crimson, rose], 30.0),
+/// );
+/// assert!(chrom::group_by_hue::<Colour>(&[], 30.0).is_empty());
+/// ```
+pub fn group_by_hue<C: ColourLike>(colours: &[C], bucket_degrees: f32) -> Vec<Vec<C>> {
+    assert!(bucket_degrees > 0.0, "bucket size must be positive");
+
+    let mut buckets = BTreeMap::<usize, Vec<C>>::new();
+    let mut greys = Vec::new();
+    for &colour in colours {
+        let [_, chroma, hue] = colour.as_colour().to_oklch();
+        if chroma < 0.03 {
+            greys.push(colour);
+        } else {
+            let centred = (hue + bucket_degrees / 2.0).rem_euclid(360.0);
+            let bucket = (centred / bucket_degrees) as usize;
+            buckets.entry(bucket).or_default().push(colour);
+        }
+    }
+
+    let mut groups: Vec<_> = buckets.into_values().collect();
+    if !greys.is_empty() {
+        groups.push(greys);
+    }
+    groups
+}
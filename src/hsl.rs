@@ -0,0 +1,500 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::Colour;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::array;
+
+impl Colour {
+    fn max_min(self) -> (f32, f32) {
+        let (max, min) = self.const_max_min();
+        (max as f32 / 255.0, min as f32 / 255.0)
+    }
+
+    const fn const_max_min(self) -> (u8, u8) {
+        let (red, green, blue) = (self.red(), self.green(), self.blue());
+        let max = if red > green { red } else { green };
+        let max = if max > blue { max } else { blue };
+        let min = if red < green { red } else { green };
+        let min = if min < blue { min } else { blue };
+        (max, min)
+    }
+
+    /// Get the hue of the colour in degrees
+    ///
+    /// The hue is in the range `0.0..360.0`. Greys have no hue, so `0.0` is
+    /// returned for them and carries no meaning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(120.0, Colour::from_rgb(0, 255, 0).hue());
+    /// assert_eq!(0.0, Colour::new(0x808080).hue());
+    /// ```
+    pub fn hue(self) -> f32 {
+        let (max, min) = self.max_min();
+        let delta = max - min;
+        if delta == 0.0 {
+            return 0.0;
+        }
+
+        let [red, green, blue] = [self.red(), self.green(), self.blue()].map(|c| c as f32 / 255.0);
+        let hue = if max == red {
+            (green - blue) / delta
+        } else if max == green {
+            (blue - red) / delta + 2.0
+        } else {
+            (red - green) / delta + 4.0
+        };
+        (hue * 60.0).rem_euclid(360.0)
+    }
+
+    /// Get the HSL saturation of the colour
+    ///
+    /// The saturation is in the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(1.0, Colour::from_rgb(255, 0, 0).saturation());
+    /// assert_eq!(0.0, Colour::WHITE.saturation());
+    /// ```
+    pub fn saturation(self) -> f32 {
+        let (max, min) = self.max_min();
+        if max == min {
+            return 0.0;
+        }
+
+        (max - min) / (1.0 - (max + min - 1.0).abs())
+    }
+
+    /// Get the HSL lightness of the colour
+    ///
+    /// The lightness is in the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(0.5, Colour::from_rgb(255, 0, 0).lightness());
+    /// assert_eq!(1.0, Colour::WHITE.lightness());
+    /// ```
+    pub fn lightness(self) -> f32 {
+        let (max, min) = self.max_min();
+        (max + min) / 2.0
+    }
+
+    /// Get the HSV value of the colour
+    ///
+    /// The value is in the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(1.0, Colour::from_rgb(255, 0, 0).value());
+    /// assert_eq!(0.0, Colour::BLACK.value());
+    /// ```
+    pub fn value(self) -> f32 {
+        self.max_min().0
+    }
+
+    /// Get the distance between the hues of two colours in degrees, going the
+    /// shortest way round the colour wheel
+    ///
+    /// The distance is in the range `0.0..=180.0`. Greys have no hue, so the
+    /// distance from a grey to any colour is `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let red = Colour::from_rgb(255, 0, 0);
+    /// assert_eq!(120.0, red.hue_distance(Colour::from_rgb(0, 255, 0)));
+    /// assert_eq!(180.0, red.hue_distance(Colour::from_rgb(0, 255, 255)));
+    ///
+    /// // 350° and 10° are 20° apart, not 340°
+    /// let crimson = Colour::from_hsl(350.0, 1.0, 0.5);
+    /// let scarlet = Colour::from_hsl(10.0, 1.0, 0.5);
+    /// assert!((crimson.hue_distance(scarlet) - 20.0).abs() < 0.5);
+    /// assert_eq!(crimson.hue_distance(scarlet), scarlet.hue_distance(crimson));
+    ///
+    /// assert_eq!(0.0, Colour::from_rgb(128, 128, 128).hue_distance(red));
+    /// assert_eq!(0.0, red.hue_distance(Colour::WHITE));
+    /// ```
+    pub fn hue_distance(self, other: Self) -> f32 {
+        if self.saturation() == 0.0 || other.saturation() == 0.0 {
+            return 0.0;
+        }
+
+        let distance = (self.hue() - other.hue()).abs();
+        distance.min(360.0 - distance)
+    }
+
+    /// Check if the colour is warm, with a hue from 330° to 90°
+    ///
+    /// Warm colours are magenta-reds, reds, oranges and yellows. Every colour
+    /// other than a grey is either warm or cool, see [`Colour::is_cool`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert!(Colour::RED.is_warm());
+    /// assert!(Colour::GOLD.is_warm());
+    /// assert!(Colour::from_hsl(350.0, 1.0, 0.5).is_warm());
+    /// assert!(Colour::from_hsl(10.0, 1.0, 0.5).is_warm());
+    /// assert!(!Colour::BLUE.is_warm());
+    /// assert!(!Colour::WHITE.is_warm());
+    /// ```
+    pub fn is_warm(self) -> bool {
+        let hue = self.hue();
+        self.saturation() != 0.0 && !(90.0..330.0).contains(&hue)
+    }
+
+    /// Check if the colour is cool, with a hue from 90° to 330°
+    ///
+    /// Cool colours are greens, cyans, blues and purples. Every colour other than
+    /// a grey is either warm or cool, see [`Colour::is_warm`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert!(Colour::BLUE.is_cool());
+    /// assert!(Colour::GREEN.is_cool());
+    /// assert!(Colour::PURPLE.is_cool());
+    /// assert!(!Colour::ORANGE.is_cool());
+    /// assert!(!Colour::BLACK.is_cool());
+    /// ```
+    pub fn is_cool(self) -> bool {
+        let hue = self.hue();
+        self.saturation() != 0.0 && (90.0..330.0).contains(&hue)
+    }
+}
+
+/// A colour in the HSL colour space
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, Hsl};
+///
+/// let red = Hsl { hue: 0.0, saturation: 1.0, lightness: 0.5 };
+/// assert_eq!(Colour::from_rgb(255, 0, 0), red.into());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Hsl {
+    /// Hue in degrees, in the range `0.0..360.0`
+    pub hue: f32,
+    /// Saturation in the range `0.0..=1.0`
+    pub saturation: f32,
+    /// Lightness in the range `0.0..=1.0`
+    pub lightness: f32,
+}
+
+impl From<Colour> for Hsl {
+    fn from(colour: Colour) -> Self {
+        colour.to_hsl()
+    }
+}
+
+impl From<Hsl> for Colour {
+    fn from(hsl: Hsl) -> Self {
+        Self::from_hsl(hsl.hue, hsl.saturation, hsl.lightness)
+    }
+}
+
+impl Colour {
+    /// Make a new colour using HSL values
+    ///
+    /// The hue is in degrees and wraps around, saturation and lightness are
+    /// clamped to the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(0, 0, 255), Colour::from_hsl(240.0, 1.0, 0.5));
+    /// assert_eq!(Colour::from_rgb(0, 0, 255), Colour::from_hsl(-120.0, 1.0, 0.5));
+    /// assert_eq!(Colour::WHITE, Colour::from_hsl(0.0, 0.0, 1.0));
+    /// ```
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        Self::from_hue_chroma(hue, chroma, lightness - chroma / 2.0)
+    }
+
+    /// Make a new colour from a hue in degrees, its chroma, and the amount
+    /// `m` added to every channel, shared by HSL and HSV
+    pub(crate) fn from_hue_chroma(hue: f32, chroma: f32, m: f32) -> Self {
+        let hue = hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        let (red, green, blue) = match hue as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let channel = |c: f32| ((c + m) * 255.0).round() as u8;
+        Self::from_rgb(channel(red), channel(green), channel(blue))
+    }
+
+    /// Get the HSL values of the colour
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Hsl};
+    ///
+    /// let hsl = Colour::from_rgb(255, 0, 0).to_hsl();
+    /// assert_eq!(Hsl { hue: 0.0, saturation: 1.0, lightness: 0.5 }, hsl);
+    ///
+    /// for colour in [Colour::AQUA, Colour::DARK_GOLD, Colour::LIGHT_GREY] {
+    ///     assert_eq!(colour, colour.to_hsl().into());
+    /// }
+    /// ```
+    pub fn to_hsl(self) -> Hsl {
+        Hsl {
+            hue: self.hue(),
+            saturation: self.saturation(),
+            lightness: self.lightness(),
+        }
+    }
+
+    /// Increase the HSL lightness of the colour by `amount`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(255, 102, 102), Colour::from_rgb(255, 0, 0).lighten(0.2));
+    /// assert_eq!(Colour::WHITE, Colour::BLUE.lighten(1.0));
+    /// ```
+    pub fn lighten(self, amount: f32) -> Self {
+        let hsl = self.to_hsl();
+        Self::from_hsl(hsl.hue, hsl.saturation, hsl.lightness + amount)
+    }
+
+    /// Decrease the HSL lightness of the colour by `amount`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(153, 0, 0), Colour::from_rgb(255, 0, 0).darken(0.2));
+    /// assert_eq!(Colour::BLACK, Colour::BLUE.darken(1.0));
+    /// ```
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Set the HSL lightness of the colour using integer maths, where `target`
+    /// is the sum of the largest and smallest channels multiplied by 1000
+    const fn const_with_lightness(self, target: u32) -> Self {
+        const fn div_round(n: u32, d: u32) -> u32 {
+            (n + d / 2) / d
+        }
+        const fn channel(c: u8, sum: u32, target: u32) -> u8 {
+            let c = c as u32;
+            let value = match (sum <= 255, target <= 255_000) {
+                (true, true) => div_round(c * target, sum * 1000),
+                (true, false) => 255 - div_round((sum - c) * (510_000 - target), sum * 1000),
+                (false, true) => div_round(target * (255 + c - sum), (510 - sum) * 1000),
+                (false, false) => {
+                    255 - div_round((255 - c) * (510_000 - target), (510 - sum) * 1000)
+                }
+            };
+            value as u8
+        }
+
+        let (max, min) = self.const_max_min();
+        let sum = max as u32 + min as u32;
+        if max == min {
+            let grey = div_round(target, 2000) as u8;
+            return Self::from_rgb(grey, grey, grey);
+        }
+
+        Self::from_rgb(
+            channel(self.red(), sum, target),
+            channel(self.green(), sum, target),
+            channel(self.blue(), sum, target),
+        )
+    }
+
+    /// Increase the HSL lightness of the colour by `amount` thousandths, in a
+    /// `const` context
+    ///
+    /// This is an approximation of [`Colour::lighten`] using integer maths,
+    /// each channel is within 2 of `colour.lighten(amount as f32 / 1000.0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// const BRAND: Colour = Colour::BLUE;
+    /// const HOVER: Colour = BRAND.const_lighten_permille(100);
+    /// assert_eq!(Colour::BLUE.lighten(0.1), HOVER);
+    /// assert_eq!(Colour::from_rgb(255, 102, 102), Colour::from_rgb(255, 0, 0).const_lighten_permille(200));
+    /// assert_eq!(Colour::WHITE, Colour::BLUE.const_lighten_permille(1000));
+    /// ```
+    pub const fn const_lighten_permille(self, amount: u16) -> Self {
+        let (max, min) = self.const_max_min();
+        let target = (max as u32 + min as u32) * 1000 + amount as u32 * 510;
+        let target = if target > 510_000 { 510_000 } else { target };
+        self.const_with_lightness(target)
+    }
+
+    /// Decrease the HSL lightness of the colour by `amount` thousandths, in a
+    /// `const` context
+    ///
+    /// This is an approximation of [`Colour::darken`] using integer maths,
+    /// each channel is within 2 of `colour.darken(amount as f32 / 1000.0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// const PRESSED: Colour = Colour::BLUE.const_darken_permille(100);
+    /// assert_eq!(Colour::BLUE.darken(0.1), PRESSED);
+    /// assert_eq!(Colour::from_rgb(153, 0, 0), Colour::from_rgb(255, 0, 0).const_darken_permille(200));
+    /// assert_eq!(Colour::BLACK, Colour::BLUE.const_darken_permille(1000));
+    /// ```
+    pub const fn const_darken_permille(self, amount: u16) -> Self {
+        let (max, min) = self.const_max_min();
+        let target = ((max as u32 + min as u32) * 1000).saturating_sub(amount as u32 * 510);
+        self.const_with_lightness(target)
+    }
+
+    /// Increase the HSL saturation of the colour by `amount`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(255, 0, 0), Colour::from_rgb(204, 51, 51).saturate(0.4));
+    /// ```
+    pub fn saturate(self, amount: f32) -> Self {
+        let hsl = self.to_hsl();
+        Self::from_hsl(hsl.hue, hsl.saturation + amount, hsl.lightness)
+    }
+
+    /// Decrease the HSL saturation of the colour by `amount`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(153, 102, 102), Colour::from_rgb(204, 51, 51).desaturate(0.4));
+    /// assert_eq!(Colour::new(0x808080), Colour::from_rgb(255, 0, 0).desaturate(1.0));
+    /// ```
+    pub fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// Rotate the hue of the colour by `degrees`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let red = Colour::from_rgb(255, 0, 0);
+    /// assert_eq!(Colour::from_rgb(0, 255, 0), red.rotate_hue(120.0));
+    /// assert_eq!(Colour::from_rgb(255, 0, 255), red.rotate_hue(-60.0));
+    /// ```
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        let hsl = self.to_hsl();
+        Self::from_hsl(hsl.hue + degrees, hsl.saturation, hsl.lightness)
+    }
+
+    /// Set the hue of the colour to `degrees`, keeping its HSL saturation and
+    /// lightness
+    ///
+    /// The hue wraps around like [`Colour::from_hsl`]. Greys have no hue, so
+    /// they are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let red = Colour::from_rgb(255, 0, 0);
+    /// assert_eq!(Colour::from_rgb(0, 0, 255), red.with_hue(240.0));
+    /// assert_eq!(Colour::from_rgb(0, 0, 255), red.with_hue(-120.0));
+    /// assert_eq!(Colour::DARK_GREEN.hue(), Colour::DARK_GREEN.with_hue(Colour::DARK_GREEN.hue()).hue());
+    /// assert_eq!(Colour::GREY.saturation(), Colour::GREY.with_hue(0.0).saturation());
+    /// assert_eq!(Colour::new(0x808080), Colour::new(0x808080).with_hue(120.0));
+    /// ```
+    pub fn with_hue(self, degrees: f32) -> Self {
+        let hsl = self.to_hsl();
+        Self::from_hsl(degrees, hsl.saturation, hsl.lightness)
+    }
+
+    /// Get `n` shades of the colour, from dark to light
+    ///
+    /// The shades keep the hue and saturation of the colour, with lightness evenly
+    /// spaced between black and white, excluding both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let red = Colour::from_rgb(255, 0, 0);
+    /// assert_eq!(
+    ///     vec![Colour::from_rgb(128, 0, 0), red, Colour::from_rgb(255, 128, 128)],
+    ///     red.shades(3),
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn shades(self, n: usize) -> Vec<Self> {
+        let hsl = self.to_hsl();
+        (0..n)
+            .map(|i| {
+                let lightness = (i + 1) as f32 / (n + 1) as f32;
+                Self::from_hsl(hsl.hue, hsl.saturation, lightness)
+            })
+            .collect()
+    }
+
+    /// Get `N` shades of the colour, from dark to light
+    ///
+    /// This is the same as [`Colour::shades`] without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let shades: [Colour; 5] = Colour::BLUE.shades_array();
+    /// # #[cfg(feature = "alloc")]
+    /// assert_eq!(Colour::BLUE.shades(5)[..], shades);
+    /// ```
+    pub fn shades_array<const N: usize>(self) -> [Self; N] {
+        let hsl = self.to_hsl();
+        array::from_fn(|i| {
+            let lightness = (i + 1) as f32 / (N + 1) as f32;
+            Self::from_hsl(hsl.hue, hsl.saturation, lightness)
+        })
+    }
+}
@@ -0,0 +1,152 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{
+    precise::{linear_to_xyz, xyz_to_linear, XYZ_TO_LINEAR},
+    Colour,
+};
+use core::f64::consts::PI;
+
+// The D65 white point in CIE Luv, and the constants for CIE lightness
+const REF_U: f64 = 0.197_830_006_642_836_81;
+const REF_V: f64 = 0.468_319_994_938_791;
+const KAPPA: f64 = 24_389.0 / 27.0;
+const EPSILON: f64 = 216.0 / 24_389.0;
+
+fn xyz_to_luv([x, y, z]: [f64; 3]) -> [f64; 3] {
+    let l = if y <= EPSILON {
+        y * KAPPA
+    } else {
+        116.0 * y.cbrt() - 16.0
+    };
+    if l == 0.0 {
+        return [0.0; 3];
+    }
+
+    let divider = x + 15.0 * y + 3.0 * z;
+    let u = 13.0 * l * (4.0 * x / divider - REF_U);
+    let v = 13.0 * l * (9.0 * y / divider - REF_V);
+    [l, u, v]
+}
+
+fn luv_to_xyz([l, u, v]: [f64; 3]) -> [f64; 3] {
+    if l == 0.0 {
+        return [0.0; 3];
+    }
+
+    let var_u = u / (13.0 * l) + REF_U;
+    let var_v = v / (13.0 * l) + REF_V;
+    let y = if l <= 8.0 {
+        l / KAPPA
+    } else {
+        ((l + 16.0) / 116.0).powi(3)
+    };
+    let x = -(9.0 * y * var_u) / ((var_u - 4.0) * var_v - var_u * var_v);
+    let z = (9.0 * y - 15.0 * var_v * y - var_v * x) / (3.0 * var_v);
+    [x, y, z]
+}
+
+/// Get the edges of the sRGB gamut at a lightness, as lines of `(slope,
+/// intercept)` in the chroma plane
+fn bounds(l: f64) -> [(f64, f64); 6] {
+    let sub1 = (l + 16.0).powi(3) / 1_560_896.0;
+    let sub2 = if sub1 > EPSILON { sub1 } else { l / KAPPA };
+
+    let mut bounds = [(0.0, 0.0); 6];
+    for (i, [m1, m2, m3]) in XYZ_TO_LINEAR.into_iter().enumerate() {
+        for t in 0..2 {
+            let t = f64::from(t);
+            let top1 = (284_517.0 * m1 - 94_839.0 * m3) * sub2;
+            let top2 =
+                (838_422.0 * m3 + 769_860.0 * m2 + 731_718.0 * m1) * l * sub2 - 769_860.0 * t * l;
+            let bottom = (632_260.0 * m3 - 126_452.0 * m2) * sub2 + 126_452.0 * t;
+            bounds[i * 2 + t as usize] = (top1 / bottom, top2 / bottom);
+        }
+    }
+    bounds
+}
+
+/// Get the largest chroma inside of the sRGB gamut at a lightness and hue
+fn max_chroma(l: f64, h: f64) -> f64 {
+    let h = h / 180.0 * PI;
+    bounds(l)
+        .into_iter()
+        .map(|(slope, intercept)| intercept / (h.sin() - slope * h.cos()))
+        .filter(|&length| length >= 0.0)
+        .fold(f64::INFINITY, f64::min)
+}
+
+impl Colour {
+    /// Get the HSLuv components of the colour
+    ///
+    /// HSLuv is a version of HSL where lightness matches how light colours
+    /// look, and saturation is the proportion of the most saturated colour
+    /// with the same hue and lightness. The hue is in degrees in the range
+    /// `0.0..360.0`, and saturation and lightness are in the range
+    /// `0.0..=100.0`. Greys have no hue, so `0.0` is returned for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// # fn assert_close(a: [f32; 3], b: [f32; 3]) {
+    /// #     assert!(a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-3), "{a:?} != {b:?}");
+    /// # }
+    /// // From the HSLuv snapshot test vectors
+    /// assert_close([12.177_051, 100.0, 53.237_116], Colour::new(0xff0000).to_hsluv());
+    /// assert_close([265.874_32, 100.0, 3.769_552_9], Colour::new(0x000044).to_hsluv());
+    /// assert_close([0.0, 0.0, 56.703_41], Colour::new(0x888888).to_hsluv());
+    /// assert_close([0.0, 0.0, 100.0], Colour::WHITE.to_hsluv());
+    /// ```
+    pub fn to_hsluv(self) -> [f32; 3] {
+        let [l, u, v] = xyz_to_luv(linear_to_xyz(self.to_linear_f64()));
+        let c = u.hypot(v);
+        let h = if c < 1e-8 {
+            0.0
+        } else {
+            v.atan2(u).to_degrees().rem_euclid(360.0)
+        };
+
+        let s = if !(1e-8..=99.999_999_9).contains(&l) {
+            0.0
+        } else {
+            (c / max_chroma(l, h) * 100.0).min(100.0)
+        };
+        [h as f32, s as f32, l as f32]
+    }
+
+    /// Make a new colour using HSLuv components, see [`Colour::to_hsluv`]
+    ///
+    /// The hue is in degrees and wraps around, saturation and lightness are
+    /// clamped to the range `0.0..=100.0`. Every combination is inside of the
+    /// sRGB gamut.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0xff0000), Colour::from_hsluv(12.177_051, 100.0, 53.237_116));
+    /// assert_eq!(Colour::WHITE, Colour::from_hsluv(0.0, 0.0, 100.0));
+    /// assert_eq!(Colour::BLACK, Colour::from_hsluv(120.0, 150.0, -10.0));
+    ///
+    /// let [h, s, l] = Colour::BLUE.to_hsluv();
+    /// assert_eq!(Colour::BLUE, Colour::from_hsluv(h, s, l));
+    /// assert_eq!(Colour::BLUE, Colour::from_hsluv(h - 360.0, s, l));
+    /// ```
+    pub fn from_hsluv(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let h = f64::from(hue).rem_euclid(360.0);
+        let s = f64::from(saturation.clamp(0.0, 100.0));
+        let l = f64::from(lightness.clamp(0.0, 100.0));
+        if l > 99.999_999_9 {
+            return Self::WHITE;
+        }
+        if l < 1e-8 {
+            return Self::BLACK;
+        }
+
+        let c = max_chroma(l, h) / 100.0 * s;
+        let (sin, cos) = (h / 180.0 * PI).sin_cos();
+        Self::from_linear_f64(xyz_to_linear(luv_to_xyz([l, cos * c, sin * c])))
+    }
+}
@@ -0,0 +1,93 @@
+use crate::Colour;
+
+/// A colour in the HSV colour space, also called HSB
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, Hsv};
+///
+/// let red = Hsv { hue: 0.0, saturation: 1.0, value: 1.0 };
+/// assert_eq!(Colour::from_rgb(255, 0, 0), red.into());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Hsv {
+    /// Hue in degrees, in the range `0.0..360.0`
+    pub hue: f32,
+    /// Saturation in the range `0.0..=1.0`
+    pub saturation: f32,
+    /// Value in the range `0.0..=1.0`
+    pub value: f32,
+}
+
+impl From<Colour> for Hsv {
+    fn from(colour: Colour) -> Self {
+        colour.to_hsv()
+    }
+}
+
+impl From<Hsv> for Colour {
+    fn from(hsv: Hsv) -> Self {
+        Self::from_hsv(hsv.hue, hsv.saturation, hsv.value)
+    }
+}
+
+impl Colour {
+    /// Make a new colour using HSV values
+    ///
+    /// The hue is in degrees and wraps around, saturation and value are
+    /// clamped to the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(0, 0, 255), Colour::from_hsv(240.0, 1.0, 1.0));
+    /// assert_eq!(Colour::from_rgb(0, 0, 255), Colour::from_hsv(-120.0, 1.0, 1.0));
+    /// assert_eq!(Colour::from_rgb(0, 128, 0), Colour::from_hsv(120.0, 1.0, 0.5));
+    /// assert_eq!(Colour::WHITE, Colour::from_hsv(0.0, 0.0, 1.0));
+    /// assert_eq!(Colour::BLACK, Colour::from_hsv(60.0, 1.0, 0.0));
+    /// ```
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let value = value.clamp(0.0, 1.0);
+        let chroma = value * saturation;
+        Self::from_hue_chroma(hue, chroma, value - chroma)
+    }
+
+    /// Get the HSV values of the colour
+    ///
+    /// The hue is the same as [`Colour::hue`] and the value the same as
+    /// [`Colour::value`], but the saturation is different to the HSL
+    /// [`Colour::saturation`]: it's how far the colour is from a grey of the
+    /// same value, so bright colours have full saturation in HSV but not HSL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Hsv};
+    ///
+    /// let hsv = Colour::from_rgb(255, 0, 0).to_hsv();
+    /// assert_eq!(Hsv { hue: 0.0, saturation: 1.0, value: 1.0 }, hsv);
+    /// assert_eq!(0.0, Colour::BLACK.to_hsv().saturation);
+    ///
+    /// for colour in [Colour::AQUA, Colour::DARK_GOLD, Colour::LIGHT_GREY] {
+    ///     assert_eq!(colour, colour.to_hsv().into());
+    /// }
+    /// ```
+    pub fn to_hsv(self) -> Hsv {
+        let value = self.value();
+        let saturation = if value == 0.0 {
+            0.0
+        } else {
+            let min = self.red().min(self.green()).min(self.blue()) as f32 / 255.0;
+            (value - min) / value
+        };
+        Hsv {
+            hue: self.hue(),
+            saturation,
+            value,
+        }
+    }
+}
@@ -0,0 +1,115 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{Colour, ColourLike};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+impl Colour {
+    /// Check if the colour is close enough to a key colour to be keyed out
+    ///
+    /// Colours match if their [`Colour::delta_e`] from the key is at most
+    /// `tolerance`, so lighter and darker shades of the key match as long as
+    /// they look similar to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let key = Colour::from_rgb(0, 177, 64);
+    /// assert!(key.matches_key(key, 0.0));
+    /// assert!(Colour::from_rgb(40, 200, 90).matches_key(key, 15.0));
+    /// assert!(Colour::from_rgb(0, 160, 58).matches_key(key, 15.0));
+    /// assert!(Colour::from_rgb(0, 140, 50).matches_key(key, 15.0));
+    /// assert!(!Colour::from_rgb(0, 110, 40).matches_key(key, 15.0));
+    /// assert!(!Colour::from_rgb(0, 60, 20).matches_key(key, 15.0));
+    /// assert!(!Colour::from_rgb(224, 172, 105).matches_key(key, 15.0));
+    /// ```
+    pub fn matches_key(self, key: Colour, tolerance: f32) -> bool {
+        self.delta_e(key) <= tolerance
+    }
+
+    /// Get the matte value of the colour when keying out a colour
+    ///
+    /// Colours within `inner` of the key are fully transparent (`0`), colours
+    /// further than `outer` are fully opaque (`255`), and the matte fades
+    /// linearly in between to give a soft edge. Distances are measured with
+    /// [`Colour::delta_e`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let key = Colour::from_rgb(0, 177, 64);
+    /// assert_eq!(0, Colour::from_rgb(40, 200, 90).key_alpha(key, 10.0, 20.0));
+    /// assert_eq!(48, Colour::from_rgb(0, 140, 50).key_alpha(key, 10.0, 20.0));
+    /// assert_eq!(255, Colour::from_rgb(0, 110, 40).key_alpha(key, 10.0, 20.0));
+    /// assert_eq!(255, Colour::from_rgb(224, 172, 105).key_alpha(key, 10.0, 20.0));
+    ///
+    /// // Without a soft edge the matte is the same as `matches_key`
+    /// assert_eq!(255, Colour::from_rgb(0, 140, 50).key_alpha(key, 10.0, 10.0));
+    /// ```
+    pub fn key_alpha(self, key: Colour, inner: f32, outer: f32) -> u8 {
+        let distance = self.delta_e(key);
+        if distance <= inner {
+            0
+        } else if distance >= outer {
+            255
+        } else {
+            ((distance - inner) / (outer - inner) * 255.0).round() as u8
+        }
+    }
+}
+
+/// Get which pixels match a key colour, see [`Colour::matches_key`]
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+///
+/// let key = Colour::from_rgb(0, 177, 64);
+/// let pixels = [key, Colour::from_rgb(0, 160, 58), Colour::from_rgb(224, 172, 105)];
+/// assert_eq!(vec![true, true, false], chrom::chroma_mask(&pixels, key, 15.0));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn chroma_mask(pixels: &[impl ColourLike], key: Colour, tolerance: f32) -> Vec<bool> {
+    pixels
+        .iter()
+        .map(|pixel| pixel.as_colour().matches_key(key, tolerance))
+        .collect()
+}
+
+/// Get which pixels match a key colour without allocating, see [`chroma_mask`]
+///
+/// # Panics
+///
+/// If `mask` and `pixels` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+///
+/// let key = Colour::from_rgb(0, 177, 64);
+/// let pixels = [key, Colour::from_rgb(0, 160, 58), Colour::from_rgb(224, 172, 105)];
+/// let mut mask = [false; 3];
+/// chrom::chroma_mask_into(&pixels, key, 15.0, &mut mask);
+/// assert_eq!([true, true, false], mask);
+/// ```
+pub fn chroma_mask_into(
+    pixels: &[impl ColourLike],
+    key: Colour,
+    tolerance: f32,
+    mask: &mut [bool],
+) {
+    assert_eq!(
+        pixels.len(),
+        mask.len(),
+        "mask length doesn't match the number of pixels"
+    );
+    for (matches, pixel) in mask.iter_mut().zip(pixels) {
+        *matches = pixel.as_colour().matches_key(key, tolerance);
+    }
+}
@@ -0,0 +1,247 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{space, Colour, OutOfGamutError};
+
+// The matrices and white point are from CSS Color 4's sample code,
+// https://www.w3.org/TR/css-color-4/#color-conversion-code
+
+/// The D65 reference white in XYZ
+const WHITE: [f32; 3] = [0.950_455_9, 1.0, 1.089_057_8];
+
+const EPSILON: f32 = 216.0 / 24389.0;
+const KAPPA: f32 = 24389.0 / 27.0;
+
+/// Multiply a matrix by a column vector
+pub(crate) fn transform(matrix: [[f32; 3]; 3], vector: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+/// Convert linear light sRGB to XYZ, using the matrices from CSS Color 4
+pub(crate) fn linear_to_xyz(rgb: [f32; 3]) -> [f32; 3] {
+    transform(
+        [
+            [0.412_390_8, 0.357_584_33, 0.180_480_8],
+            [0.212_639, 0.715_168_65, 0.072_192_32],
+            [0.019_330_818, 0.119_194_78, 0.950_532_14],
+        ],
+        rgb,
+    )
+}
+
+/// Convert XYZ to linear light sRGB, using the matrices from CSS Color 4
+pub(crate) fn xyz_to_linear(xyz: [f32; 3]) -> [f32; 3] {
+    transform(
+        [
+            [3.240_97, -1.537_383_2, -0.498_610_76],
+            [-0.969_243_6, 1.875_967_5, 0.041_555_06],
+            [0.055_630_08, -0.203_976_96, 1.056_971_5],
+        ],
+        xyz,
+    )
+}
+
+fn xyz_to_lab(xyz: [f32; 3]) -> [f32; 3] {
+    let [x, y, z] = [0, 1, 2].map(|i| {
+        let t = xyz[i] / WHITE[i];
+        if t > EPSILON {
+            t.cbrt()
+        } else {
+            (KAPPA * t + 16.0) / 116.0
+        }
+    });
+    [116.0 * y - 16.0, 500.0 * (x - y), 200.0 * (y - z)]
+}
+
+fn lab_to_xyz([l, a, b]: [f32; 3]) -> [f32; 3] {
+    let y = (l + 16.0) / 116.0;
+    let x = y + a / 500.0;
+    let z = y - b / 200.0;
+    let [x, z] = [x, z].map(|t| {
+        if t.powi(3) > EPSILON {
+            t.powi(3)
+        } else {
+            (116.0 * t - 16.0) / KAPPA
+        }
+    });
+    let y = if l > KAPPA * EPSILON {
+        y.powi(3)
+    } else {
+        l / KAPPA
+    };
+    [x * WHITE[0], y * WHITE[1], z * WHITE[2]]
+}
+
+impl Colour {
+    /// Get the CIE XYZ components of the colour
+    ///
+    /// Uses the D65 white point, with `Y` in the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let [x, y, z] = Colour::WHITE.to_xyz();
+    /// assert!((x - 0.95046).abs() < 1e-4);
+    /// assert!((y - 1.0).abs() < 1e-4);
+    /// assert!((z - 1.08906).abs() < 1e-4);
+    /// ```
+    pub fn to_xyz(self) -> [f32; 3] {
+        if cfg!(feature = "precise") {
+            return self.to_xyz_f64().map(|c| c as f32);
+        }
+
+        linear_to_xyz(self.to_linear())
+    }
+
+    /// Make a new colour using CIE XYZ components
+    ///
+    /// Colours outside of the sRGB gamut are clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let blue = Colour::BLUE;
+    /// assert_eq!(blue, Colour::from_xyz(blue.to_xyz()));
+    /// ```
+    pub fn from_xyz(xyz: [f32; 3]) -> Self {
+        if cfg!(feature = "precise") {
+            return Self::from_xyz_f64(xyz.map(f64::from));
+        }
+
+        Self::from_linear(xyz_to_linear(xyz))
+    }
+
+    /// Get the CIE L\*a\*b\* components of the colour
+    ///
+    /// Uses the D65 white point, with `L` in the range `0.0..=100.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let [l, a, b] = Colour::from_rgb(255, 0, 0).to_lab();
+    /// assert!((l - 53.2371).abs() < 1e-2);
+    /// assert!((a - 80.0901).abs() < 1e-2);
+    /// assert!((b - 67.2033).abs() < 1e-2);
+    /// ```
+    pub fn to_lab(self) -> [f32; 3] {
+        xyz_to_lab(self.to_xyz())
+    }
+
+    /// Make a new colour using CIE L\*a\*b\* components
+    ///
+    /// Colours outside of the sRGB gamut are clamped, use
+    /// [`Colour::from_lab_checked`] to detect them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let purple = Colour::PURPLE;
+    /// assert_eq!(purple, Colour::from_lab(purple.to_lab()));
+    /// ```
+    pub fn from_lab(lab: [f32; 3]) -> Self {
+        Self::from_xyz(lab_to_xyz(lab))
+    }
+
+    /// Make a new colour using CIE L\*a\*b\* components, failing if the colour is
+    /// outside of the sRGB gamut
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let gold = Colour::GOLD;
+    /// assert_eq!(Ok(gold), Colour::from_lab_checked(gold.to_lab()));
+    /// assert!(Colour::from_lab_checked([100.0, 0.0, -100.0]).is_err());
+    /// ```
+    pub fn from_lab_checked(lab: [f32; 3]) -> Result<Self, OutOfGamutError> {
+        let linear = xyz_to_linear(lab_to_xyz(lab));
+        if space::in_gamut(linear) {
+            Ok(Self::from_linear(linear))
+        } else {
+            Err(OutOfGamutError)
+        }
+    }
+
+    /// Get the perceptual difference between two colours
+    ///
+    /// This is the CIEDE2000 Delta E, where a difference of about `1.0` is the
+    /// smallest a person can notice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(0.0, Colour::BLUE.delta_e(Colour::BLUE));
+    /// assert!((Colour::BLACK.delta_e(Colour::WHITE) - 100.0).abs() < 1e-3);
+    /// assert!(Colour::BLUE.delta_e(Colour::from_rgb(52, 152, 220)) < 1.0);
+    /// ```
+    pub fn delta_e(self, other: Self) -> f32 {
+        ciede2000(self.to_lab(), other.to_lab())
+    }
+}
+
+/// The CIEDE2000 colour difference between two L\*a\*b\* colours
+pub(crate) fn ciede2000([l1, a1, b1]: [f32; 3], [l2, a2, b2]: [f32; 3]) -> f32 {
+    const POW_25_7: f32 = 6_103_515_625.0;
+
+    let c_bar = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+    let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + POW_25_7)).sqrt());
+    let (a1, a2) = (a1 * (1.0 + g), a2 * (1.0 + g));
+    let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+    let hue = |a: f32, b: f32| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let (h1, h2) = (hue(a1, b1), hue(a2, b2));
+
+    let delta_l = l2 - l1;
+    let delta_c = c2 - c1;
+    let delta_h = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 > h1 {
+        h2 - h1 - 360.0
+    } else {
+        h2 - h1 + 360.0
+    };
+    let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h.to_radians() / 2.0).sin();
+
+    let l_bar = (l1 + l2) / 2.0;
+    let c_bar = (c1 + c2) / 2.0;
+    let h_bar = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * (h_bar - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar).to_radians().cos()
+        + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+    let delta_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_bar.powi(7) / (c_bar.powi(7) + POW_25_7)).sqrt();
+    let s_l = 1.0 + 0.015 * (l_bar - 50.0).powi(2) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar;
+    let s_h = 1.0 + 0.015 * c_bar * t;
+    let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+    let (l, c, h) = (delta_l / s_l, delta_c / s_c, delta_h / s_h);
+    (l * l + c * c + h * h + r_t * c * h).max(0.0).sqrt()
+}
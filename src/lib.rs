@@ -6,20 +6,226 @@
 //! use chrom::Colour;
 //!
 //! // Make a new colour using a hex value
-//! let white = Colour(0xffffff);
+//! let white = Colour::new(0xffffff);
 //! // Make a new colour using built-in constants
 //! let blue = Colour::BLUE;
 //! // Make a new colour using RGB values
 //! let yellow = Colour::from_rgb(254, 231, 92);
+//! // Make a new colour by parsing a CSS string
+//! # #[cfg(feature = "alloc")]
+//! let green: Colour = "#57f287".parse().unwrap();
 //! ```
 //!
 //! # Feature flags
 //!
+//! `ansi` - Enable painting text with colours for terminals
+//!
 //! `serde` - Enable serde features
+//!
+//! `serde_json` - Enable canonicalising colours in JSON with `serde_json`
+//!
+//! `names-i18n` - Enable colour names in languages other than English
+//!
+//! `palettes` - Enable categorical palettes for data visualisation
+//!
+//! `precise` - Do colour space conversions in `f64`, even for `f32` results
+//!
+//! `rgb` - Enable conversions to and from the `rgb` crate's pixel types
+//!
+//! `std` - Enabled by default. Enable the parts which need the standard
+//! library, terminal output and [`dedup`]. Without it the crate is `no_std`,
+//! and keeps everything else, with its floating point maths done in software,
+//! so results can differ from `std` in the last bit.
+//!
+//! `alloc` - Enable parsing, gradients in a `Vec`, palette files, pixel codecs
+//! and the methods which return a `String` or `Vec` without the rest of `std`
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Define colour constants and a table of their names and values, so a
+/// constant can't be left out of the table
+macro_rules! constants {
+    ($(#[$meta:meta])* $table:ident { $($name:ident = $value:expr,)* }) => {
+        impl Colour {
+            $(pub const $name: Self = Self($value);)*
+
+            $(#[$meta])*
+            pub const $table: &'static [(&'static str, Colour)] =
+                &[$((stringify!($name), Self::$name)),*];
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+mod accumulator;
+mod adjust;
+mod animation;
+#[cfg(feature = "ansi")]
+mod ansi;
+mod basic;
+#[cfg(feature = "alloc")]
+mod cache;
+mod channel;
+#[cfg(feature = "alloc")]
+pub mod codec;
+mod colour_like;
+mod colour_ops;
+mod compact;
+mod contrast;
+mod css;
+mod curve;
+#[cfg(feature = "std")]
+mod dedup;
+mod describe;
+mod diff;
+mod discord;
+mod emoji;
+mod error;
+#[cfg(feature = "alloc")]
+pub mod export;
+mod fingerprint;
+// Only used without `std`, but always built so that it is tested
+#[allow(dead_code)]
+mod float;
+mod gradient;
+#[cfg(feature = "alloc")]
+mod group;
+mod hsl;
+mod hsluv;
+mod hsv;
+mod key;
+mod lab;
+mod lightness;
+mod literal;
+mod luma;
+mod mix;
+mod names;
+#[cfg(feature = "names-i18n")]
+mod names_i18n;
+mod oklab;
+mod p3;
+mod packed;
+mod palette;
+#[cfg(feature = "alloc")]
+mod palette_diff;
+#[cfg(feature = "palettes")]
+pub mod palettes;
+#[cfg(feature = "alloc")]
+mod parse;
+mod picker;
+mod pigment;
+mod precise;
+pub mod prelude;
+#[cfg(feature = "alloc")]
+mod raster;
+#[cfg(feature = "rgb")]
+mod rgb;
+mod rgba;
+mod scheme;
+#[cfg(feature = "alloc")]
+mod sequential;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod space;
+mod step;
+mod strict;
+#[cfg(feature = "std")]
+mod term;
+mod tone;
+mod white_point;
+
+#[cfg(feature = "alloc")]
+pub use accumulator::ColourAccumulator;
+pub use adjust::Adjustment;
+#[cfg(feature = "alloc")]
+pub use adjust::ColourAdjustment;
+pub use animation::{breathe, pulse_between, rainbow_cycle};
+#[cfg(feature = "ansi")]
+pub use ansi::Painted;
+pub use basic::BasicColour;
+#[cfg(feature = "alloc")]
+pub use cache::ConversionCache;
+pub use channel::Channel;
+pub use colour_like::ColourLike;
+pub use colour_ops::ColourOps;
+pub use contrast::ContrastLevel;
+pub use css::{AnsiBgDisplay, AnsiFgDisplay, CssColourSpace, CssRgbDisplay, HexDisplay};
+pub use curve::Curve;
+#[cfg(feature = "std")]
+pub use dedup::{dedup, unique_exact, Distance};
+pub use describe::{Description, HueName, Lightness, Saturation};
+#[cfg(feature = "alloc")]
+pub use diff::diff_image;
+pub use diff::{diff_summary, DiffSummary, ImageDiffError};
+pub use discord::DiscordColour;
+#[cfg(feature = "alloc")]
+pub use discord::ParseDiscordColourError;
+#[cfg(feature = "alloc")]
+pub use emoji::emoji_grid;
+pub use emoji::COLOUR_EMOJI;
+pub use error::{ColourRangeError, OutOfGamutError};
+pub use fingerprint::{palette_fingerprint, FingerprintPrecision};
+pub use gradient::{ColourStop, Gradient, GradientError};
+#[cfg(feature = "alloc")]
+pub use group::group_by_hue;
+pub use hsl::Hsl;
+pub use hsv::Hsv;
+#[cfg(feature = "alloc")]
+pub use key::chroma_mask;
+pub use key::chroma_mask_into;
+pub use lightness::{equalise_lightness, lightness_spread};
+pub use names::CSS_NAMES;
+#[cfg(feature = "names-i18n")]
+pub use names_i18n::Locale;
+pub use packed::{Endian, PackedFormat};
+pub use palette::Palette;
+#[cfg(feature = "alloc")]
+pub use palette_diff::{
+    palette_diff, palette_diff_with, Pairing, PaletteDiff, PaletteDiffError, PalettePair,
+};
+#[cfg(feature = "alloc")]
+pub use parse::{
+    parse_many, ParseColourError, ParseGradientError, ParseReport, ParsedColour, MAX_COLOUR_LENGTH,
+};
+#[cfg(feature = "alloc")]
+pub use raster::BufferSizeError;
+pub use rgba::Rgba;
+pub use scheme::{ColourScheme, SchemeColour};
+#[cfg(feature = "alloc")]
+pub use sequential::{diverging_palette, sequential_palette};
+pub use space::{LinearRgb, Srgb};
+pub use strict::Strict;
+#[cfg(feature = "std")]
+pub use term::TermColourGuard;
+pub use white_point::WhitePoint;
 
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-use std::{fmt, ops};
+use ::serde::Serialize;
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, string::String};
+use core::{fmt, ops};
+
+/// The version of the exact outputs of conversions and adjustments
+///
+/// Every function which makes a colour is pinned by a table of a few hundred
+/// inputs and their exact outputs in the crate's tests. Whenever any of those
+/// outputs change on purpose, even by one bit, this is increased, so that
+/// golden images and other snapshots can be tied to it. The outputs are the
+/// same with and without the `precise` feature.
+///
+/// Colours are pinned, but floats like the result of [`Colour::to_oklab`]
+/// aren't, as they depend on the platform's maths library.
+///
+/// # Examples
+///
+/// ```
+/// // Regenerate golden images when this changes
+/// assert_eq!(1, chrom::CONFORMANCE_VERSION);
+/// ```
+pub const CONFORMANCE_VERSION: u32 = 1;
 
 /// A representation of a colour
 ///
@@ -29,42 +235,188 @@ use std::{fmt, ops};
 /// use chrom::Colour;
 ///
 /// // Make a new colour using a hex value
-/// let white = Colour(0xffffff);
+/// let white = Colour::new(0xffffff);
 /// assert_eq!(*white, 0xffffff);
 ///
 /// // Make a new colour using built-in constants
 /// let blue = Colour::BLUE;
 /// assert_eq!((52, 152, 219), blue.into());
 /// ```
-#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-pub struct Colour(pub u32);
+///
+/// A colour always fits in 24 bits, there is no way to make one with any of the
+/// top 8 bits set:
+///
+/// ```
+/// use chrom::Colour;
+///
+/// assert_eq!(0x3498db, *Colour::new(0xff3498db));
+/// assert_eq!(0x3498db, *Colour::from(0xff3498db));
+/// assert!(Colour::from_u32_checked(0x1000000).is_err());
+/// assert_eq!(0xffffff, *(Colour::WHITE + Colour::WHITE));
+/// assert_eq!(0xffffff, *(Colour::WHITE * Colour::WHITE));
+/// assert_eq!(0x000000, *(Colour::BLACK - Colour::WHITE));
+/// assert_eq!(0xffffff, *(Colour::WHITE / Colour::BLACK));
+/// # #[cfg(feature = "alloc")]
+/// assert!("#ff3498db".parse::<Colour>().is_err());
+/// ```
+///
+/// ```compile_fail
+/// let colour = chrom::Colour(0xff3498db);
+/// ```
+///
+/// ```compile_fail
+/// let mut colour = chrom::Colour::BLUE;
+/// *colour = 0xff3498db;
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Colour(u32);
 
 pub type Color = Colour;
 
+constants! {
+    /// The names and values of the built-in constants, like
+    /// `("BLUE", Colour::BLUE)`
+    ///
+    /// The order is the order they're declared in, and new constants are
+    /// added to the end. The Discord theme colours are in
+    /// [`Colour::DISCORD_CONSTANTS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(("WHITE", Colour::WHITE), Colour::ALL_CONSTANTS[0]);
+    /// assert!(Colour::ALL_CONSTANTS.contains(&("DARK_NAVY", Colour::DARK_NAVY)));
+    /// ```
+    ALL_CONSTANTS {
+        WHITE = 0xffffff,
+        BLACK = 0x000000,
+        AQUA = 0x1ABC9C,
+        GREEN = 0x57F287,
+        BLUE = 0x3498DB,
+        YELLOW = 0xFEE75C,
+        PURPLE = 0x9B59B6,
+        GOLD = 0xF1C40F,
+        ORANGE = 0xE67E22,
+        RED = 0xED4245,
+        GREY = 0x95A5A6,
+        NAVY = 0x34495E,
+        DARK_AQUA = 0x11806A,
+        DARK_GREEN = 0x1F8B4C,
+        DARK_BLUE = 0x206694,
+        DARK_PURPLE = 0x71368A,
+        DARK_GOLD = 0xC27C0E,
+        DARK_ORANGE = 0xA84300,
+        DARK_RED = 0x992D22,
+        DARK_GREY = 0x979C9F,
+        DARK_NAVY = 0x2C3E50,
+        LIGHT_GREY = 0xBCC0C0,
+    }
+}
+
 impl Colour {
-    pub const WHITE: Self = Self(0xffffff);
-    pub const BLACK: Self = Self(0x000000);
-    pub const AQUA: Self = Self(0x1ABC9C);
-    pub const GREEN: Self = Self(0x57F287);
-    pub const BLUE: Self = Self(0x3498DB);
-    pub const YELLOW: Self = Self(0xFEE75C);
-    pub const PURPLE: Self = Self(0x9B59B6);
-    pub const GOLD: Self = Self(0xF1C40F);
-    pub const ORANGE: Self = Self(0xE67E22);
-    pub const RED: Self = Self(0xED4245);
-    pub const GREY: Self = Self(0x95A5A6);
-    pub const NAVY: Self = Self(0x34495E);
-    pub const DARK_AQUA: Self = Self(0x11806A);
-    pub const DARK_GREEN: Self = Self(0x1F8B4C);
-    pub const DARK_BLUE: Self = Self(0x206694);
-    pub const DARK_PURPLE: Self = Self(0x71368A);
-    pub const DARK_GOLD: Self = Self(0xC27C0E);
-    pub const DARK_ORANGE: Self = Self(0xA84300);
-    pub const DARK_RED: Self = Self(0x992D22);
-    pub const DARK_GREY: Self = Self(0x979C9F);
-    pub const DARK_NAVY: Self = Self(0x2C3E50);
-    pub const LIGHT_GREY: Self = Self(0xBCC0C0);
+    /// Make a new colour using a hex value
+    ///
+    /// Only the low 24 bits are used, any higher bits are discarded. Use
+    /// [`Colour::from_u32_checked`] to reject values with them set instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::BLUE, Colour::new(0x3498db));
+    /// assert_eq!(Colour::BLUE, Colour::new(0xff3498db));
+    /// ```
+    pub const fn new(value: u32) -> Self {
+        Self(value & 0xffffff)
+    }
+
+    /// Iterate over the names and values of the built-in constants, in the
+    /// order of [`Colour::ALL_CONSTANTS`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let blue = Colour::constants().find(|&(name, _)| name == "BLUE");
+    /// assert_eq!(Some(("BLUE", Colour::BLUE)), blue);
+    /// ```
+    pub fn constants() -> impl Iterator<Item = (&'static str, Colour)> {
+        Self::ALL_CONSTANTS.iter().copied()
+    }
+
+    /// Get the built-in constant which looks closest to the colour, with its
+    /// name
+    ///
+    /// Closeness is measured with [`Colour::delta_e`], like
+    /// [`Palette::nearest`]. The first of [`Colour::ALL_CONSTANTS`] wins a
+    /// tie.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(("BLUE", Colour::BLUE), Colour::new(0x3399dd).nearest_constant());
+    /// assert_eq!(("RED", Colour::RED), Colour::new(0xff0000).nearest_constant());
+    /// assert_eq!(("BLACK", Colour::BLACK), Colour::new(0x050505).nearest_constant());
+    /// ```
+    pub fn nearest_constant(self) -> (&'static str, Self) {
+        Self::constants()
+            .min_by(|(_, a), (_, b)| self.delta_e(*a).total_cmp(&self.delta_e(*b)))
+            .expect("there are built-in constants")
+    }
+
+    /// Make a new colour using a hex value, failing if it doesn't fit in 24 bits
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourRangeError};
+    ///
+    /// assert_eq!(Ok(Colour::BLUE), Colour::from_u32_checked(0x3498db));
+    /// assert_eq!(Err(ColourRangeError(0xff3498db)), Colour::from_u32_checked(0xff3498db));
+    /// ```
+    pub const fn from_u32_checked(value: u32) -> Result<Self, ColourRangeError> {
+        if value > 0xffffff {
+            Err(ColourRangeError(value))
+        } else {
+            Ok(Self(value))
+        }
+    }
+
+    /// Get the hex value of the colour
+    ///
+    /// This is always `0x00RRGGBB`, see [`Colour::to_packed`] for other layouts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(0x3498db, Colour::BLUE.as_u32());
+    /// ```
+    pub const fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Get the hex value of the colour, the same as [`Colour::as_u32`]
+    pub const fn into_inner(self) -> u32 {
+        self.0
+    }
+
+    /// Combine two colours channel by channel
+    fn zip_channels(self, other: Self, f: impl Fn(u8, u8) -> u8) -> Self {
+        Self::from_rgb(
+            f(self.red(), other.red()),
+            f(self.green(), other.green()),
+            f(self.blue(), other.blue()),
+        )
+    }
 
     /// Make a new colour using RGB values
     ///
@@ -87,7 +439,7 @@ impl Colour {
     /// ```
     /// use chrom::Colour;
     ///
-    /// let red = Colour(0xff0000);
+    /// let red = Colour::new(0xff0000);
     /// assert_eq!(255, red.red());
     /// ```
     pub const fn red(self) -> u8 {
@@ -101,7 +453,7 @@ impl Colour {
     /// ```
     /// use chrom::Colour;
     ///
-    /// let green = Colour(0x00ff00);
+    /// let green = Colour::new(0x00ff00);
     /// assert_eq!(255, green.green());
     /// ```
     pub const fn green(self) -> u8 {
@@ -115,7 +467,7 @@ impl Colour {
     /// ```
     /// use chrom::Colour;
     ///
-    /// let blue = Colour(0x0000ff);
+    /// let blue = Colour::new(0x0000ff);
     /// assert_eq!(255, blue.blue());
     /// ```
     pub const fn blue(self) -> u8 {
@@ -129,19 +481,41 @@ impl Colour {
     /// ```
     /// use chrom::Colour;
     ///
-    /// let red = Colour(0xff0000);
+    /// let red = Colour::new(0xff0000);
     /// assert_eq!("ff0000", red.hex());
     /// ```
+    #[cfg(feature = "alloc")]
     pub fn hex(self) -> String {
-        format!("{:06x}", self.0)
+        self.format_hex(&mut [0; 7])[1..].to_owned()
+    }
+
+    /// Get the canonical string of the colour, `"#rrggbb"` in lowercase
+    ///
+    /// Every way of writing the same colour has the same canonical string, so
+    /// files written with it only change when the colours do. This form won't
+    /// change between versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let white: Colour = "#FFF".parse().unwrap();
+    /// assert_eq!("#ffffff", white.to_canonical_string());
+    /// assert_eq!("#ffffff", Colour::new(16777215).to_canonical_string());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_canonical_string(self) -> String {
+        self.format_hex(&mut [0; 7]).to_owned()
     }
 }
 
+/// Add channel by channel, saturating at 255
 impl ops::Add for Colour {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self(*self + *rhs)
+        self.zip_channels(rhs, u8::saturating_add)
     }
 }
 
@@ -159,23 +533,18 @@ impl ops::Deref for Colour {
     }
 }
 
-impl ops::DerefMut for Colour {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
-}
-
 impl fmt::Display for Colour {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "#{}", self.hex())
+        self.write_hex(f)
     }
 }
 
+/// Divide channel by channel, dividing by a zero channel saturates at 255
 impl ops::Div for Colour {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        Self(*self / *rhs)
+        self.zip_channels(rhs, |a, b| a.checked_div(b).unwrap_or(u8::MAX))
     }
 }
 
@@ -185,12 +554,20 @@ impl ops::DivAssign for Colour {
     }
 }
 
+/// Make a new colour using a hex value, see [`Colour::new`]
+///
+/// This is always `0x00RRGGBB`, use [`Colour::from_packed`] for values from C
+/// libraries and other places where the channel order matters.
 impl From<u32> for Colour {
     fn from(colour: u32) -> Self {
-        Colour(colour)
+        Self::new(colour)
     }
 }
 
+/// Get the hex value of the colour, see [`Colour::as_u32`]
+///
+/// This is always `0x00RRGGBB`, use [`Colour::to_packed`] for values passed to
+/// C libraries and other places where the channel order matters.
 impl From<Colour> for u32 {
     fn from(colour: Colour) -> Self {
         *colour
@@ -209,11 +586,12 @@ impl From<Colour> for (u8, u8, u8) {
     }
 }
 
+/// Multiply channel by channel, saturating at 255
 impl ops::Mul for Colour {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        Self(*self * *rhs)
+        self.zip_channels(rhs, u8::saturating_mul)
     }
 }
 
@@ -223,11 +601,12 @@ impl ops::MulAssign for Colour {
     }
 }
 
+/// Subtract channel by channel, saturating at 0
 impl ops::Sub for Colour {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Self(*self - *rhs)
+        self.zip_channels(rhs, u8::saturating_sub)
     }
 }
 
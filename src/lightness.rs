@@ -0,0 +1,71 @@
+use crate::ColourLike;
+
+/// Get the difference between the lightest and darkest colours in a palette
+///
+/// Lightness is measured with Oklab `L`, so the spread is in the range
+/// `0.0..=1.0`. An empty palette has a spread of `0.0`.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+///
+/// assert!((chrom::lightness_spread(&[Colour::BLACK, Colour::WHITE]) - 1.0).abs() < 1e-4);
+/// assert_eq!(0.0, chrom::lightness_spread(&[Colour::BLUE]));
+/// assert_eq!(0.0, chrom::lightness_spread::<Colour>(&[]));
+/// ```
+pub fn lightness_spread<C: ColourLike>(palette: &[C]) -> f32 {
+    let (min, max) = palette
+        .iter()
+        .map(|colour| colour.as_colour().to_oklab()[0])
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), l| {
+            (min.min(l), max.max(l))
+        });
+    (max - min).max(0.0)
+}
+
+/// Give every colour in a palette the same Oklab lightness
+///
+/// `target` is in the range `0.0..=1.0`, and defaults to the mean lightness of
+/// the palette. Each colour keeps its hue, and its chroma is reduced when
+/// needed to stay inside of the sRGB gamut, see
+/// [`Colour::normalise_lightness`](crate::Colour::normalise_lightness).
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+///
+/// let original = [
+///     Colour::from_rgb(255, 255, 0),
+///     Colour::from_rgb(0, 0, 128),
+///     Colour::from_rgb(230, 126, 34),
+/// ];
+/// assert!(chrom::lightness_spread(&original) > 0.6);
+///
+/// let mut palette = original;
+/// chrom::equalise_lightness(&mut palette, None);
+/// assert!(chrom::lightness_spread(&palette) < 0.01);
+/// for (before, after) in original.iter().zip(&palette) {
+///     assert!((before.to_oklch()[2] - after.to_oklch()[2]).abs() < 3.0);
+/// }
+///
+/// chrom::equalise_lightness(&mut palette, Some(0.8));
+/// assert!(palette.iter().all(|colour| (colour.to_oklab()[0] - 0.8).abs() < 5e-3));
+/// ```
+pub fn equalise_lightness<C: ColourLike>(palette: &mut [C], target: Option<f32>) {
+    if palette.is_empty() {
+        return;
+    }
+
+    let target = target.unwrap_or_else(|| {
+        let total: f32 = palette
+            .iter()
+            .map(|colour| colour.as_colour().to_oklab()[0])
+            .sum();
+        total / palette.len() as f32
+    });
+    for colour in palette {
+        *colour = C::from_colour(colour.as_colour().normalise_lightness(target));
+    }
+}
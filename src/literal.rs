@@ -0,0 +1,129 @@
+use crate::Colour;
+
+/// The value of each ASCII hex digit, and `0xff` for every other byte
+pub(crate) const HEX_DIGITS: [u8; 256] = {
+    let mut digits = [0xff; 256];
+    let mut i = 0;
+    while i < 10 {
+        digits[b'0' as usize + i] = i as u8;
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 6 {
+        digits[b'a' as usize + i] = 10 + i as u8;
+        digits[b'A' as usize + i] = 10 + i as u8;
+        i += 1;
+    }
+    digits
+};
+
+/// Make a colour from a hex literal like `"#3498db"` or `"#fc0"`, checked at
+/// compile time
+///
+/// The `#` is optional, and the string can be any constant expression. A
+/// string with an invalid digit, or with more than 6 digits so it wouldn't
+/// fit in 24 bits, fails to compile instead of silently losing channels. See
+/// [`Colour::from_hex_str`].
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{colour, Colour};
+///
+/// const ACCENT: Colour = colour!("#3498db");
+/// static PALETTE: [Colour; 2] = [colour!("#fc0"), colour!("2c3e50")];
+///
+/// assert_eq!(Colour::BLUE, ACCENT);
+/// assert_eq!([Colour::new(0xffcc00), Colour::DARK_NAVY], PALETTE);
+/// ```
+///
+/// ```compile_fail
+/// let colour = chrom::colour!("#ff88000");
+/// ```
+///
+/// ```compile_fail
+/// let colour = chrom::colour!("#ff880g");
+/// ```
+#[macro_export]
+macro_rules! colour {
+    ($hex:expr) => {{
+        // A `const` item, rather than an inline `const` block, so that this
+        // builds before Rust 1.79
+        const COLOUR: $crate::Colour = match $crate::Colour::from_hex_str($hex) {
+            ::core::option::Option::Some(colour) => colour,
+            ::core::option::Option::None => {
+                ::core::panic!("invalid hex colour, expected #rgb or #rrggbb")
+            }
+        };
+        COLOUR
+    }};
+}
+
+/// Make a colour from a hex literal, the same as [`colour!`]
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{color, Color};
+///
+/// const ACCENT: Color = color!("#3498db");
+/// assert_eq!(Color::BLUE, ACCENT);
+/// ```
+#[macro_export]
+macro_rules! color {
+    ($hex:expr) => {
+        $crate::colour!($hex)
+    };
+}
+
+impl Colour {
+    /// Make a new colour from a hex string like `"#3498db"` or `"#fc0"`, in a
+    /// const context
+    ///
+    /// The `#` is optional. Strings which aren't 3 or 6 hex digits give
+    /// `None`, so a value too wide for 24 bits is rejected rather than having
+    /// its top bits dropped like [`Colour::new`]. Use [`colour!`] to check a
+    /// literal at compile time, or [`str::parse`] for any CSS colour.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// const ACCENT: Option<Colour> = Colour::from_hex_str("#3498db");
+    /// assert_eq!(Some(Colour::BLUE), ACCENT);
+    /// assert_eq!(Some(Colour::new(0xffcc00)), Colour::from_hex_str("FC0"));
+    /// assert_eq!(None, Colour::from_hex_str("#ff88000"));
+    /// assert_eq!(None, Colour::from_hex_str("#ff880g"));
+    /// assert_eq!(None, Colour::from_hex_str(""));
+    /// ```
+    pub const fn from_hex_str(s: &str) -> Option<Self> {
+        let digits = match s.as_bytes() {
+            [b'#', digits @ ..] => digits,
+            digits => digits,
+        };
+        if digits.len() != 3 && digits.len() != 6 {
+            return None;
+        }
+
+        let mut value = 0;
+        let mut i = 0;
+        while i < digits.len() {
+            let digit = HEX_DIGITS[digits[i] as usize];
+            if digit == 0xff {
+                return None;
+            }
+            value = value << 4 | digit as u32;
+            i += 1;
+        }
+
+        if digits.len() == 3 {
+            return Some(Self::from_rgb(
+                ((value >> 8) & 0xf) as u8 * 17,
+                ((value >> 4) & 0xf) as u8 * 17,
+                (value & 0xf) as u8 * 17,
+            ));
+        }
+        Some(Self(value))
+    }
+}
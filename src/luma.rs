@@ -0,0 +1,124 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::Colour;
+
+// BT.601 luma weights, as used by analogue TV and JPEG
+const KR: f32 = 0.299;
+const KB: f32 = 0.114;
+const KG: f32 = 1.0 - KR - KB;
+
+/// Convert luma and chroma to sRGB encoded components, which may be out of
+/// gamut
+fn ycbcr_to_rgb(luma: f32, [cb, cr]: [f32; 2]) -> [f32; 3] {
+    let red = luma + 2.0 * (1.0 - KR) * cr;
+    let blue = luma + 2.0 * (1.0 - KB) * cb;
+    let green = (luma - KR * red - KB * blue) / KG;
+    [red, green, blue]
+}
+
+impl Colour {
+    /// Split the colour into luma and chroma
+    ///
+    /// This is full range BT.601 Y′CbCr, the same as JPEG uses, calculated from
+    /// the sRGB encoded components. Luma is in the range `0.0..=1.0`, and the
+    /// two chroma components, Cb and Cr, are in the range `-0.5..=0.5`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let (luma, [cb, cr]) = Colour::from_rgb(255, 0, 0).split_luma_chroma();
+    /// assert!((luma - 0.299).abs() < 1e-6);
+    /// assert!((cb + 0.168_736).abs() < 1e-6);
+    /// assert!((cr - 0.5).abs() < 1e-6);
+    ///
+    /// let (luma, [cb, cr]) = Colour::WHITE.split_luma_chroma();
+    /// assert!((luma - 1.0).abs() < 1e-6 && cb.abs() < 1e-6 && cr.abs() < 1e-6);
+    /// ```
+    pub fn split_luma_chroma(self) -> (f32, [f32; 2]) {
+        let [red, green, blue] = [self.red(), self.green(), self.blue()].map(|c| c as f32 / 255.0);
+        let luma = KR * red + KG * green + KB * blue;
+        let cb = (blue - luma) / (2.0 * (1.0 - KB));
+        let cr = (red - luma) / (2.0 * (1.0 - KR));
+        (luma, [cb, cr])
+    }
+
+    /// Make a new colour using luma and chroma, see
+    /// [`Colour::split_luma_chroma`]
+    ///
+    /// Luma is clamped to the range `0.0..=1.0`. If the colour would be outside
+    /// of the sRGB gamut, the chroma is scaled down until it isn't, which keeps
+    /// the hue the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let (luma, chroma) = Colour::BLUE.split_luma_chroma();
+    /// assert_eq!(Colour::BLUE, Colour::from_luma_chroma(luma, chroma));
+    ///
+    /// // Full red chroma can't be as light as this, so it is scaled down
+    /// let pink = Colour::from_luma_chroma(0.8, [-0.168_736, 0.5]);
+    /// assert_eq!(Colour::from_rgb(255, 182, 182), pink);
+    /// ```
+    pub fn from_luma_chroma(luma: f32, chroma: [f32; 2]) -> Self {
+        let luma = luma.clamp(0.0, 1.0);
+
+        // Each component is luma plus a multiple of the chroma, so find
+        // the largest fraction of the chroma which keeps them all in range
+        let offsets = ycbcr_to_rgb(0.0, chroma);
+        let scale = offsets.iter().fold(1.0_f32, |scale, &offset| {
+            if offset > 0.0 {
+                scale.min((1.0 - luma) / offset)
+            } else if offset < 0.0 {
+                scale.min(-luma / offset)
+            } else {
+                scale
+            }
+        });
+
+        let [red, green, blue] = ycbcr_to_rgb(luma, chroma.map(|c| c * scale))
+            .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+        Self::from_rgb(red, green, blue)
+    }
+
+    /// Get the colour with a different luma, keeping its chroma
+    ///
+    /// See [`Colour::split_luma_chroma`] and [`Colour::from_luma_chroma`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let dark = Colour::BLUE.with_luma(0.25);
+    /// assert!((dark.split_luma_chroma().0 - 0.25).abs() < 5e-3);
+    /// assert!((dark.hue() - Colour::BLUE.hue()).abs() < 2.0);
+    /// ```
+    pub fn with_luma(self, luma: f32) -> Self {
+        let (_, chroma) = self.split_luma_chroma();
+        Self::from_luma_chroma(luma, chroma)
+    }
+
+    /// Get the colour with the chroma of another colour, keeping its luma
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let grey = Colour::from_rgb(128, 128, 128);
+    /// let tinted = grey.with_chroma_of(Colour::ORANGE);
+    /// assert!((tinted.hue() - Colour::ORANGE.hue()).abs() < 2.0);
+    /// assert!((tinted.split_luma_chroma().0 - grey.split_luma_chroma().0).abs() < 5e-3);
+    ///
+    /// assert_eq!(grey, Colour::ORANGE.with_chroma_of(Colour::WHITE).with_luma(128.0 / 255.0));
+    /// ```
+    pub fn with_chroma_of(self, other: Self) -> Self {
+        let (luma, _) = self.split_luma_chroma();
+        let (_, chroma) = other.split_luma_chroma();
+        Self::from_luma_chroma(luma, chroma)
+    }
+}
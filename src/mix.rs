@@ -0,0 +1,231 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{Colour, Srgb};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+impl Colour {
+    /// Mix two colours channel by channel
+    ///
+    /// `weight` is the proportion of `other` in the result, `0.0` giving `self`
+    /// and `1.0` giving `other`. It is clamped to the range `0.0..=1.0`.
+    ///
+    /// This interpolates the sRGB values, like CSS transitions, and is what
+    /// [`ColourOps::lerp`](crate::ColourOps::lerp) does for colours. See
+    /// [`Colour::mix_linear`] and [`Colour::mix_oklab`] for mixes which look
+    /// more even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let grey = Colour::BLACK.mix(Colour::WHITE, 0.5);
+    /// assert_eq!(Colour::new(0x808080), grey);
+    /// assert_eq!(Colour::RED, Colour::RED.mix(Colour::BLUE, 0.0));
+    /// assert_eq!(Colour::BLUE, Colour::RED.mix(Colour::BLUE, 1.0));
+    /// ```
+    pub fn mix(self, other: Self, weight: f32) -> Self {
+        let weight = weight.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * weight).round() as u8;
+        Self::from_rgb(
+            channel(self.red(), other.red()),
+            channel(self.green(), other.green()),
+            channel(self.blue(), other.blue()),
+        )
+    }
+
+    /// Mix two colours in linear light
+    ///
+    /// `weight` is the proportion of `other` in the result, and is clamped to
+    /// the range `0.0..=1.0`. This is how light physically mixes, so mixes are
+    /// brighter than with [`Colour::mix`], without its dark middle between
+    /// saturated colours.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")]
+    /// use chrom::{Colour, ConversionCache};
+    /// # #[cfg(not(feature = "alloc"))]
+    /// # use chrom::Colour;
+    ///
+    /// let red = Colour::from_rgb(255, 0, 0);
+    /// let green = Colour::from_rgb(0, 255, 0);
+    /// assert_eq!(Colour::new(0x808000), red.mix(green, 0.5));
+    /// assert_eq!(Colour::new(0xbcbc00), red.mix_linear(green, 0.5));
+    ///
+    /// assert_eq!(Colour::RED, Colour::RED.mix_linear(Colour::BLUE, -1.0));
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// let cache = ConversionCache::new(&[Colour::RED, Colour::BLUE]);
+    /// assert_eq!(cache.mix_linear(0, 1, 0.3), Colour::RED.mix_linear(Colour::BLUE, 0.3));
+    /// # }
+    /// ```
+    pub fn mix_linear(self, other: Self, weight: f32) -> Self {
+        let weight = weight.clamp(0.0, 1.0);
+        Srgb(self)
+            .decode()
+            .mix(Srgb(other).decode(), weight)
+            .encode()
+            .0
+    }
+
+    /// Mix two colours in Oklab, a perceptual colour space
+    ///
+    /// `weight` is the proportion of `other` in the result, and is clamped to
+    /// the range `0.0..=1.0`. The lightness changes evenly through the mix,
+    /// which suits gradients for charts and heatmaps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")]
+    /// use chrom::{Colour, ConversionCache};
+    /// # #[cfg(not(feature = "alloc"))]
+    /// # use chrom::Colour;
+    ///
+    /// let grey = Colour::BLACK.mix_oklab(Colour::WHITE, 0.5);
+    /// assert!((grey.to_oklab()[0] - 0.5).abs() < 5e-3);
+    /// assert_eq!(Colour::RED, Colour::RED.mix_oklab(Colour::BLUE, 0.0));
+    /// assert_eq!(Colour::BLUE, Colour::RED.mix_oklab(Colour::BLUE, 1.0));
+    ///
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// let cache = ConversionCache::new(&[Colour::RED, Colour::BLUE]);
+    /// assert_eq!(cache.mix_oklab(0, 1, 0.3), Colour::RED.mix_oklab(Colour::BLUE, 0.3));
+    /// # }
+    /// ```
+    pub fn mix_oklab(self, other: Self, weight: f32) -> Self {
+        let weight = weight.clamp(0.0, 1.0);
+        let (a, b) = (self.to_oklab(), other.to_oklab());
+        Self::from_oklab([0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * weight))
+    }
+
+    /// Mix two colours channel by channel, in a `const` context
+    ///
+    /// `weight` is the proportion of `other` in thousandths, and is clamped to
+    /// `1000`. This is an approximation of [`Colour::mix`] using integer maths,
+    /// each channel is within 2 of `colour.mix(other, weight as f32 / 1000.0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// const MUTED: Colour = Colour::BLUE.const_mix(Colour::GREY, 250);
+    /// assert_eq!(Colour::BLUE.mix(Colour::GREY, 0.25), MUTED);
+    /// assert_eq!(Colour::new(0x808080), Colour::BLACK.const_mix(Colour::WHITE, 500));
+    /// assert_eq!(Colour::BLUE, Colour::RED.const_mix(Colour::BLUE, 2000));
+    /// ```
+    pub const fn const_mix(self, other: Self, weight: u16) -> Self {
+        const fn channel(a: u8, b: u8, weight: u32) -> u8 {
+            ((a as u32 * (1000 - weight) + b as u32 * weight + 500) / 1000) as u8
+        }
+
+        let weight = if weight > 1000 { 1000 } else { weight as u32 };
+        Self::from_rgb(
+            channel(self.red(), other.red(), weight),
+            channel(self.green(), other.green(), weight),
+            channel(self.blue(), other.blue(), weight),
+        )
+    }
+
+    /// Get the average of colours channel by channel
+    ///
+    /// Returns `None` if there are no colours.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Some(Colour::new(0x808080)), Colour::average(&[Colour::BLACK, Colour::WHITE]));
+    /// assert_eq!(Some(Colour::BLUE), Colour::average(&[Colour::BLUE]));
+    /// assert_eq!(None, Colour::average(&[]));
+    /// ```
+    pub fn average(colours: &[Self]) -> Option<Self> {
+        let count = colours.len() as u64;
+        if count == 0 {
+            return None;
+        }
+
+        let sums = colours.iter().fold([0; 3], |[red, green, blue], colour| {
+            [
+                red + u64::from(colour.red()),
+                green + u64::from(colour.green()),
+                blue + u64::from(colour.blue()),
+            ]
+        });
+        let [red, green, blue] = sums.map(|sum| ((sum + count / 2) / count) as u8);
+        Some(Self::from_rgb(red, green, blue))
+    }
+
+    /// Composite `colour` over this colour at `opacity`, flattening it into an
+    /// opaque colour
+    ///
+    /// The colours are blended in linear light, which is physically correct
+    /// but gives lighter results than [`Colour::mix`], which blends the sRGB
+    /// values the way browsers composite. `opacity` is clamped to the range
+    /// `0.0..=1.0`, `0.0` giving this colour and `1.0` giving `colour`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let surface = Colour::new(0x121212);
+    /// assert_eq!(Colour::new(0x434343), surface.overlay(Colour::WHITE, 0.05));
+    /// assert_eq!(surface, surface.overlay(Colour::WHITE, 0.0));
+    /// assert_eq!(Colour::WHITE, surface.overlay(Colour::WHITE, 1.0));
+    /// ```
+    pub fn overlay(self, colour: Self, opacity: f32) -> Self {
+        let opacity = opacity.clamp(0.0, 1.0);
+        if opacity == 0.0 {
+            return self;
+        }
+        if opacity == 1.0 {
+            return colour;
+        }
+
+        let (under, over) = (self.to_linear(), colour.to_linear());
+        Self::from_linear([0, 1, 2].map(|i| under[i] + (over[i] - under[i]) * opacity))
+    }
+
+    /// Composite `overlay` over this colour at each of `opacities`, like the
+    /// elevation overlays of Material Design's dark theme
+    ///
+    /// See [`Colour::overlay`]. Compositing in linear light makes the surfaces
+    /// lighter than those in Material Design's own table, which composites in
+    /// sRGB like [`Colour::mix`]: with white over `#121212` at its opacities of
+    /// 5% to 16%, each channel is 37 to 57 higher. Use `mix` to match the
+    /// table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// // The opacities of Material Design's 1dp, 2dp, 4dp, 8dp and 24dp
+    /// // elevations
+    /// let opacities = [0.05, 0.07, 0.09, 0.12, 0.16];
+    /// let surface = Colour::new(0x121212);
+    /// assert_eq!(
+    ///     vec![
+    ///         Colour::new(0x434343),
+    ///         Colour::new(0x4e4e4e),
+    ///         Colour::new(0x575757),
+    ///         Colour::new(0x636363),
+    ///         Colour::new(0x717171),
+    ///     ],
+    ///     surface.elevate(Colour::WHITE, &opacities),
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn elevate(self, overlay: Self, opacities: &[f32]) -> Vec<Self> {
+        opacities
+            .iter()
+            .map(|&opacity| self.overlay(overlay, opacity))
+            .collect()
+    }
+}
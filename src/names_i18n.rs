@@ -0,0 +1,471 @@
+use crate::{Colour, CSS_NAMES};
+use alloc::string::String;
+
+/// A language for colour names, used by [`Colour::from_name_localised`]
+///
+/// English names are the CSS named colours. The other languages have their
+/// everyday colour words, each the CSS colour of its English translation, so
+/// `"rot"` is CSS `red`. Adjectives are included in both their masculine and
+/// feminine forms for the basic colour words.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Locale {
+    English,
+    German,
+    French,
+    Spanish,
+    Italian,
+    Portuguese,
+    Russian,
+}
+
+impl Locale {
+    /// Every locale, in the order [`Colour::from_name_any_locale`] searches
+    /// them
+    pub const ALL: [Self; 7] = [
+        Self::English,
+        Self::German,
+        Self::French,
+        Self::Spanish,
+        Self::Italian,
+        Self::Portuguese,
+        Self::Russian,
+    ];
+
+    /// Get the ISO 639-1 code of the language
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Locale;
+    ///
+    /// assert_eq!("de", Locale::German.code());
+    /// ```
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::German => "de",
+            Self::French => "fr",
+            Self::Spanish => "es",
+            Self::Italian => "it",
+            Self::Portuguese => "pt",
+            Self::Russian => "ru",
+        }
+    }
+
+    /// Get the colour names of the locale, sorted by name
+    ///
+    /// Names are normalised: lowercase, without diacritics or spaces. Russian
+    /// names are written with `е` for `ё`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Locale};
+    ///
+    /// assert!(Locale::French.names().contains(&("bleumarine", Colour::new(0x000080))));
+    /// ```
+    pub const fn names(self) -> &'static [(&'static str, Colour)] {
+        match self {
+            Self::English => &CSS_NAMES,
+            Self::German => &GERMAN,
+            Self::French => &FRENCH,
+            Self::Spanish => &SPANISH,
+            Self::Italian => &ITALIAN,
+            Self::Portuguese => &PORTUGUESE,
+            Self::Russian => &RUSSIAN,
+        }
+    }
+
+    fn find(self, normalised: &str) -> Option<Colour> {
+        let names = self.names();
+        names
+            .binary_search_by(|(candidate, _)| candidate.cmp(&normalised))
+            .ok()
+            .map(|i| names[i].1)
+    }
+}
+
+/// Fold the case of a name and remove its diacritics, spaces, hyphens and
+/// underscores
+fn normalise(name: &str) -> String {
+    let mut normalised = String::with_capacity(name.len());
+    for c in name.trim().chars().flat_map(char::to_lowercase) {
+        match c {
+            '-' | '_' => {}
+            c if c.is_whitespace() => {}
+            'à'..='å' | 'ā' => normalised.push('a'),
+            'æ' => normalised.push_str("ae"),
+            'ç' => normalised.push('c'),
+            'è'..='ë' | 'ē' => normalised.push('e'),
+            'ì'..='ï' | 'ī' => normalised.push('i'),
+            'ñ' => normalised.push('n'),
+            'ò'..='ö' | 'ø' | 'ō' => normalised.push('o'),
+            'œ' => normalised.push_str("oe"),
+            'ù'..='ü' | 'ū' => normalised.push('u'),
+            'ý' | 'ÿ' => normalised.push('y'),
+            'ß' => normalised.push_str("ss"),
+            'ё' => normalised.push('е'),
+            // Combining diacritics, from names which aren't precomposed
+            '\u{300}'..='\u{36f}' if normalised.ends_with(|c: char| c.is_ascii()) => {}
+            c => normalised.push(c),
+        }
+    }
+    normalised
+}
+
+impl Colour {
+    /// Get the colour with a name in a language, see [`Locale`]
+    ///
+    /// The name is matched ignoring case, diacritics, spaces, hyphens and
+    /// underscores, so `"Grün"`, `"grun"` and `"GRÜN"` are all found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Locale};
+    ///
+    /// let red = Some(Colour::new(0xff0000));
+    /// assert_eq!(red, Colour::from_name_localised("rot", Locale::German));
+    /// assert_eq!(red, Colour::from_name_localised("Rouge", Locale::French));
+    /// assert_eq!(red, Colour::from_name_localised("roja", Locale::Spanish));
+    /// assert_eq!(red, Colour::from_name_localised("Красный", Locale::Russian));
+    /// assert_eq!(red, Colour::from_name_localised("red", Locale::English));
+    /// assert_eq!(
+    ///     Colour::from_name_localised("Bleu Marine", Locale::French),
+    ///     Colour::from_name("navy"),
+    /// );
+    /// assert_eq!(None, Colour::from_name_localised("rot", Locale::French));
+    /// ```
+    pub fn from_name_localised(name: &str, locale: Locale) -> Option<Self> {
+        locale.find(&normalise(name))
+    }
+
+    /// Get the colour with a name in any language
+    ///
+    /// Some names mean different colours in different languages, like
+    /// `"violet"`, which is CSS `violet` in English but `purple` in French.
+    /// The locales are searched in the order of [`Locale::ALL`], so English
+    /// comes first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Locale};
+    ///
+    /// assert_eq!(Colour::from_name("green"), Colour::from_name_any_locale("grün"));
+    /// assert_eq!(Colour::from_name("green"), Colour::from_name_any_locale("verde"));
+    /// assert_eq!(Colour::from_name("violet"), Colour::from_name_any_locale("violet"));
+    /// assert_eq!(Colour::from_name("purple"), Colour::from_name_localised("violet", Locale::French));
+    /// assert_eq!(None, Colour::from_name_any_locale("blurple"));
+    /// ```
+    pub fn from_name_any_locale(name: &str) -> Option<Self> {
+        let name = normalise(name);
+        Locale::ALL
+            .into_iter()
+            .find_map(|locale| locale.find(&name))
+    }
+}
+
+const GERMAN: [(&str, Colour); 41] = [
+    ("beige", Colour(0xf5f5dc)),
+    ("blau", Colour(0x0000ff)),
+    ("braun", Colour(0xa52a2a)),
+    ("cyan", Colour(0x00ffff)),
+    ("dunkelblau", Colour(0x00008b)),
+    ("dunkelgrun", Colour(0x006400)),
+    ("dunkelrot", Colour(0x8b0000)),
+    ("elfenbein", Colour(0xfffff0)),
+    ("fuchsia", Colour(0xff00ff)),
+    ("gelb", Colour(0xffff00)),
+    ("gold", Colour(0xffd700)),
+    ("grau", Colour(0x808080)),
+    ("grun", Colour(0x008000)),
+    ("hellblau", Colour(0xadd8e6)),
+    ("hellgrun", Colour(0x90ee90)),
+    ("himmelblau", Colour(0x87ceeb)),
+    ("indigo", Colour(0x4b0082)),
+    ("karmesinrot", Colour(0xdc143c)),
+    ("kastanienbraun", Colour(0x800000)),
+    ("khaki", Colour(0xf0e68c)),
+    ("koralle", Colour(0xff7f50)),
+    ("lachs", Colour(0xfa8072)),
+    ("lavendel", Colour(0xe6e6fa)),
+    ("lila", Colour(0xee82ee)),
+    ("magenta", Colour(0xff00ff)),
+    ("marineblau", Colour(0x000080)),
+    ("oliv", Colour(0x808000)),
+    ("orange", Colour(0xffa500)),
+    ("orchidee", Colour(0xda70d6)),
+    ("petrol", Colour(0x008080)),
+    ("pink", Colour(0xff69b4)),
+    ("purpur", Colour(0x800080)),
+    ("rosa", Colour(0xffc0cb)),
+    ("rot", Colour(0xff0000)),
+    ("schokoladenbraun", Colour(0xd2691e)),
+    ("schwarz", Colour(0x000000)),
+    ("silber", Colour(0xc0c0c0)),
+    ("tomatenrot", Colour(0xff6347)),
+    ("turkis", Colour(0x40e0d0)),
+    ("violett", Colour(0x9400d3)),
+    ("weiss", Colour(0xffffff)),
+];
+
+const FRENCH: [(&str, Colour); 41] = [
+    ("argent", Colour(0xc0c0c0)),
+    ("beige", Colour(0xf5f5dc)),
+    ("blanc", Colour(0xffffff)),
+    ("bleu", Colour(0x0000ff)),
+    ("bleuciel", Colour(0x87ceeb)),
+    ("bleuclair", Colour(0xadd8e6)),
+    ("bleufonce", Colour(0x00008b)),
+    ("bleumarine", Colour(0x000080)),
+    ("bordeaux", Colour(0x800000)),
+    ("brun", Colour(0xa52a2a)),
+    ("chocolat", Colour(0xd2691e)),
+    ("corail", Colour(0xff7f50)),
+    ("cramoisi", Colour(0xdc143c)),
+    ("cyan", Colour(0x00ffff)),
+    ("fuchsia", Colour(0xff00ff)),
+    ("gris", Colour(0x808080)),
+    ("indigo", Colour(0x4b0082)),
+    ("ivoire", Colour(0xfffff0)),
+    ("jaune", Colour(0xffff00)),
+    ("kaki", Colour(0xf0e68c)),
+    ("lavande", Colour(0xe6e6fa)),
+    ("magenta", Colour(0xff00ff)),
+    ("marron", Colour(0xa52a2a)),
+    ("noir", Colour(0x000000)),
+    ("olive", Colour(0x808000)),
+    ("or", Colour(0xffd700)),
+    ("orange", Colour(0xffa500)),
+    ("orchidee", Colour(0xda70d6)),
+    ("pourpre", Colour(0x800080)),
+    ("rose", Colour(0xffc0cb)),
+    ("rouge", Colour(0xff0000)),
+    ("rougefonce", Colour(0x8b0000)),
+    ("sarcelle", Colour(0x008080)),
+    ("saumon", Colour(0xfa8072)),
+    ("tomate", Colour(0xff6347)),
+    ("turquoise", Colour(0x40e0d0)),
+    ("vert", Colour(0x008000)),
+    ("vertcitron", Colour(0x00ff00)),
+    ("vertclair", Colour(0x90ee90)),
+    ("vertfonce", Colour(0x006400)),
+    ("violet", Colour(0x800080)),
+];
+
+const SPANISH: [(&str, Colour); 54] = [
+    ("amarilla", Colour(0xffff00)),
+    ("amarillo", Colour(0xffff00)),
+    ("azul", Colour(0x0000ff)),
+    ("azulclaro", Colour(0xadd8e6)),
+    ("azulmarino", Colour(0x000080)),
+    ("azuloscuro", Colour(0x00008b)),
+    ("beige", Colour(0xf5f5dc)),
+    ("beis", Colour(0xf5f5dc)),
+    ("blanca", Colour(0xffffff)),
+    ("blanco", Colour(0xffffff)),
+    ("cafe", Colour(0xa52a2a)),
+    ("caqui", Colour(0xf0e68c)),
+    ("carmesi", Colour(0xdc143c)),
+    ("celeste", Colour(0x87ceeb)),
+    ("chocolate", Colour(0xd2691e)),
+    ("cian", Colour(0x00ffff)),
+    ("coral", Colour(0xff7f50)),
+    ("dorada", Colour(0xffd700)),
+    ("dorado", Colour(0xffd700)),
+    ("fucsia", Colour(0xff00ff)),
+    ("granate", Colour(0x800000)),
+    ("gris", Colour(0x808080)),
+    ("indigo", Colour(0x4b0082)),
+    ("lavanda", Colour(0xe6e6fa)),
+    ("lima", Colour(0x00ff00)),
+    ("magenta", Colour(0xff00ff)),
+    ("marfil", Colour(0xfffff0)),
+    ("marron", Colour(0xa52a2a)),
+    ("morada", Colour(0x800080)),
+    ("morado", Colour(0x800080)),
+    ("naranja", Colour(0xffa500)),
+    ("negra", Colour(0x000000)),
+    ("negro", Colour(0x000000)),
+    ("oliva", Colour(0x808000)),
+    ("oro", Colour(0xffd700)),
+    ("orquidea", Colour(0xda70d6)),
+    ("plata", Colour(0xc0c0c0)),
+    ("plateada", Colour(0xc0c0c0)),
+    ("plateado", Colour(0xc0c0c0)),
+    ("purpura", Colour(0x800080)),
+    ("roja", Colour(0xff0000)),
+    ("rojo", Colour(0xff0000)),
+    ("rojooscuro", Colour(0x8b0000)),
+    ("rosa", Colour(0xffc0cb)),
+    ("rosada", Colour(0xffc0cb)),
+    ("rosado", Colour(0xffc0cb)),
+    ("salmon", Colour(0xfa8072)),
+    ("tomate", Colour(0xff6347)),
+    ("turquesa", Colour(0x40e0d0)),
+    ("verde", Colour(0x008000)),
+    ("verdeazulado", Colour(0x008080)),
+    ("verdeclaro", Colour(0x90ee90)),
+    ("verdeoscuro", Colour(0x006400)),
+    ("violeta", Colour(0xee82ee)),
+];
+
+const ITALIAN: [(&str, Colour); 48] = [
+    ("arancione", Colour(0xffa500)),
+    ("argento", Colour(0xc0c0c0)),
+    ("avorio", Colour(0xfffff0)),
+    ("azzurra", Colour(0x87ceeb)),
+    ("azzurro", Colour(0x87ceeb)),
+    ("beige", Colour(0xf5f5dc)),
+    ("bianca", Colour(0xffffff)),
+    ("bianco", Colour(0xffffff)),
+    ("blu", Colour(0x0000ff)),
+    ("blunotte", Colour(0x000080)),
+    ("bluscuro", Colour(0x00008b)),
+    ("bordo", Colour(0x800000)),
+    ("cachi", Colour(0xf0e68c)),
+    ("celeste", Colour(0xadd8e6)),
+    ("ciano", Colour(0x00ffff)),
+    ("cioccolato", Colour(0xd2691e)),
+    ("corallo", Colour(0xff7f50)),
+    ("cremisi", Colour(0xdc143c)),
+    ("dorata", Colour(0xffd700)),
+    ("dorato", Colour(0xffd700)),
+    ("fucsia", Colour(0xff00ff)),
+    ("gialla", Colour(0xffff00)),
+    ("giallo", Colour(0xffff00)),
+    ("grigia", Colour(0x808080)),
+    ("grigio", Colour(0x808080)),
+    ("indaco", Colour(0x4b0082)),
+    ("lavanda", Colour(0xe6e6fa)),
+    ("lime", Colour(0x00ff00)),
+    ("magenta", Colour(0xff00ff)),
+    ("marrone", Colour(0xa52a2a)),
+    ("nera", Colour(0x000000)),
+    ("nero", Colour(0x000000)),
+    ("oliva", Colour(0x808000)),
+    ("orchidea", Colour(0xda70d6)),
+    ("oro", Colour(0xffd700)),
+    ("pomodoro", Colour(0xff6347)),
+    ("porpora", Colour(0x800080)),
+    ("rosa", Colour(0xffc0cb)),
+    ("rossa", Colour(0xff0000)),
+    ("rosso", Colour(0xff0000)),
+    ("rossoscuro", Colour(0x8b0000)),
+    ("salmone", Colour(0xfa8072)),
+    ("turchese", Colour(0x40e0d0)),
+    ("verde", Colour(0x008000)),
+    ("verdeacqua", Colour(0x008080)),
+    ("verdechiaro", Colour(0x90ee90)),
+    ("verdescuro", Colour(0x006400)),
+    ("viola", Colour(0x800080)),
+];
+
+const PORTUGUESE: [(&str, Colour); 54] = [
+    ("amarela", Colour(0xffff00)),
+    ("amarelo", Colour(0xffff00)),
+    ("azul", Colour(0x0000ff)),
+    ("azulceleste", Colour(0x87ceeb)),
+    ("azulclaro", Colour(0xadd8e6)),
+    ("azulescuro", Colour(0x00008b)),
+    ("azulmarinho", Colour(0x000080)),
+    ("bege", Colour(0xf5f5dc)),
+    ("bordo", Colour(0x800000)),
+    ("branca", Colour(0xffffff)),
+    ("branco", Colour(0xffffff)),
+    ("caqui", Colour(0xf0e68c)),
+    ("carmesim", Colour(0xdc143c)),
+    ("castanha", Colour(0xa52a2a)),
+    ("castanho", Colour(0xa52a2a)),
+    ("chocolate", Colour(0xd2691e)),
+    ("ciano", Colour(0x00ffff)),
+    ("cinza", Colour(0x808080)),
+    ("cinzenta", Colour(0x808080)),
+    ("cinzento", Colour(0x808080)),
+    ("coral", Colour(0xff7f50)),
+    ("dourada", Colour(0xffd700)),
+    ("dourado", Colour(0xffd700)),
+    ("fucsia", Colour(0xff00ff)),
+    ("indigo", Colour(0x4b0082)),
+    ("laranja", Colour(0xffa500)),
+    ("lavanda", Colour(0xe6e6fa)),
+    ("lima", Colour(0x00ff00)),
+    ("magenta", Colour(0xff00ff)),
+    ("marfim", Colour(0xfffff0)),
+    ("marrom", Colour(0xa52a2a)),
+    ("oliva", Colour(0x808000)),
+    ("orquidea", Colour(0xda70d6)),
+    ("ouro", Colour(0xffd700)),
+    ("prata", Colour(0xc0c0c0)),
+    ("prateada", Colour(0xc0c0c0)),
+    ("prateado", Colour(0xc0c0c0)),
+    ("preta", Colour(0x000000)),
+    ("preto", Colour(0x000000)),
+    ("purpura", Colour(0x800080)),
+    ("rosa", Colour(0xffc0cb)),
+    ("roxa", Colour(0x800080)),
+    ("roxo", Colour(0x800080)),
+    ("salmao", Colour(0xfa8072)),
+    ("tomate", Colour(0xff6347)),
+    ("turquesa", Colour(0x40e0d0)),
+    ("verde", Colour(0x008000)),
+    ("verdeazulado", Colour(0x008080)),
+    ("verdeclaro", Colour(0x90ee90)),
+    ("verdeescuro", Colour(0x006400)),
+    ("vermelha", Colour(0xff0000)),
+    ("vermelho", Colour(0xff0000)),
+    ("vermelhoescuro", Colour(0x8b0000)),
+    ("violeta", Colour(0xee82ee)),
+];
+
+const RUSSIAN: [(&str, Colour); 48] = [
+    ("бежевый", Colour(0xf5f5dc)),
+    ("белая", Colour(0xffffff)),
+    ("белый", Colour(0xffffff)),
+    ("бирюзовый", Colour(0x40e0d0)),
+    ("бордовый", Colour(0x800000)),
+    ("голубая", Colour(0x87ceeb)),
+    ("голубой", Colour(0x87ceeb)),
+    ("желтая", Colour(0xffff00)),
+    ("желтый", Colour(0xffff00)),
+    ("зеленая", Colour(0x008000)),
+    ("зеленый", Colour(0x008000)),
+    ("золотая", Colour(0xffd700)),
+    ("золотой", Colour(0xffd700)),
+    ("индиго", Colour(0x4b0082)),
+    ("коралловый", Colour(0xff7f50)),
+    ("коричневая", Colour(0xa52a2a)),
+    ("коричневый", Colour(0xa52a2a)),
+    ("красная", Colour(0xff0000)),
+    ("красный", Colour(0xff0000)),
+    ("лавандовый", Colour(0xe6e6fa)),
+    ("лаймовый", Colour(0x00ff00)),
+    ("лососевый", Colour(0xfa8072)),
+    ("малиновый", Colour(0xdc143c)),
+    ("оливковый", Colour(0x808000)),
+    ("оранжевая", Colour(0xffa500)),
+    ("оранжевый", Colour(0xffa500)),
+    ("пурпурная", Colour(0x800080)),
+    ("пурпурный", Colour(0x800080)),
+    ("розовая", Colour(0xffc0cb)),
+    ("розовый", Colour(0xffc0cb)),
+    ("светлозеленый", Colour(0x90ee90)),
+    ("серая", Colour(0x808080)),
+    ("серебряная", Colour(0xc0c0c0)),
+    ("серебряный", Colour(0xc0c0c0)),
+    ("серый", Colour(0x808080)),
+    ("синий", Colour(0x0000ff)),
+    ("синяя", Colour(0x0000ff)),
+    ("темнозеленый", Colour(0x006400)),
+    ("темнокрасный", Colour(0x8b0000)),
+    ("темносиний", Colour(0x000080)),
+    ("томатный", Colour(0xff6347)),
+    ("фиолетовая", Colour(0x9400d3)),
+    ("фиолетовый", Colour(0x9400d3)),
+    ("фуксия", Colour(0xff00ff)),
+    ("хаки", Colour(0xf0e68c)),
+    ("черная", Colour(0x000000)),
+    ("черный", Colour(0x000000)),
+    ("шоколадный", Colour(0xd2691e)),
+];
@@ -0,0 +1,178 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{lab::transform, space, Colour};
+
+/// Convert linear light sRGB to Oklab, using the matrices from Björn Ottosson
+pub(crate) fn linear_to_oklab(rgb: [f32; 3]) -> [f32; 3] {
+    let lms = transform(
+        [
+            [0.412_221_46, 0.536_332_55, 0.051_445_995],
+            [0.211_903_5, 0.680_699_5, 0.107_396_96],
+            [0.088_302_46, 0.281_718_85, 0.629_978_7],
+        ],
+        rgb,
+    );
+    transform(
+        [
+            [0.210_454_26, 0.793_617_8, -0.004_072_047],
+            [1.977_998_5, -2.428_592_2, 0.450_593_7],
+            [0.025_904_037, 0.782_771_77, -0.808_675_77],
+        ],
+        lms.map(f32::cbrt),
+    )
+}
+
+/// Convert Oklab to linear light sRGB, using the matrices from Björn Ottosson
+pub(crate) fn oklab_to_linear(lab: [f32; 3]) -> [f32; 3] {
+    let lms = transform(
+        [
+            [1.0, 0.396_337_78, 0.215_803_76],
+            [1.0, -0.105_561_346, -0.063_854_17],
+            [1.0, -0.089_484_18, -1.291_485_5],
+        ],
+        lab,
+    );
+    transform(
+        [
+            [4.076_741_7, -3.307_711_6, 0.230_969_94],
+            [-1.268_438, 2.609_757_4, -0.341_319_38],
+            [-0.004_196_086_3, -0.703_418_6, 1.707_614_7],
+        ],
+        lms.map(|c| c * c * c),
+    )
+}
+
+fn oklch_to_oklab([l, c, h]: [f32; 3]) -> [f32; 3] {
+    let (sin, cos) = h.to_radians().sin_cos();
+    [l, c * cos, c * sin]
+}
+
+/// Make a colour from Oklch, reducing the chroma until it is inside of the sRGB
+/// gamut so the hue is kept
+pub(crate) fn from_oklch_in_gamut([l, c, h]: [f32; 3]) -> Colour {
+    let l = l.clamp(0.0, 1.0);
+    let linear = |c| oklab_to_linear(oklch_to_oklab([l, c, h]));
+    if space::in_gamut(linear(c)) {
+        return Colour::from_linear(linear(c));
+    }
+
+    let (mut low, mut high) = (0.0, c);
+    for _ in 0..16 {
+        let mid = (low + high) / 2.0;
+        if space::in_gamut(linear(mid)) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Colour::from_linear(linear(low))
+}
+
+impl Colour {
+    /// Get the Oklab components of the colour
+    ///
+    /// `L` is in the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let [l, a, b] = Colour::from_rgb(255, 0, 0).to_oklab();
+    /// assert!((l - 0.62796).abs() < 1e-4);
+    /// assert!((a - 0.22486).abs() < 1e-4);
+    /// assert!((b - 0.12585).abs() < 1e-4);
+    ///
+    /// let [l, a, b] = Colour::WHITE.to_oklab();
+    /// assert!((l - 1.0).abs() < 1e-4 && a.abs() < 1e-4 && b.abs() < 1e-4);
+    /// ```
+    pub fn to_oklab(self) -> [f32; 3] {
+        linear_to_oklab(self.to_linear())
+    }
+
+    /// Make a new colour using Oklab components
+    ///
+    /// Colours outside of the sRGB gamut are clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let blue = Colour::BLUE;
+    /// assert_eq!(blue, Colour::from_oklab(blue.to_oklab()));
+    /// ```
+    pub fn from_oklab(lab: [f32; 3]) -> Self {
+        Self::from_linear(oklab_to_linear(lab))
+    }
+
+    /// Get the Oklch components of the colour
+    ///
+    /// These are the Oklab lightness, the chroma and the hue in degrees. The hue
+    /// of greys is `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let [l, c, h] = Colour::from_rgb(0, 0, 255).to_oklch();
+    /// assert!((l - 0.45201).abs() < 1e-4);
+    /// assert!((c - 0.31321).abs() < 1e-4);
+    /// assert!((h - 264.052).abs() < 1e-2);
+    /// ```
+    pub fn to_oklch(self) -> [f32; 3] {
+        let [l, a, b] = self.to_oklab();
+        let c = a.hypot(b);
+        let h = if c < 1e-4 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        };
+        [l, c, h]
+    }
+
+    /// Make a new colour using Oklch components
+    ///
+    /// Colours outside of the sRGB gamut are clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let blue = Colour::BLUE;
+    /// assert_eq!(blue, Colour::from_oklch(blue.to_oklch()));
+    /// ```
+    pub fn from_oklch(lch: [f32; 3]) -> Self {
+        Self::from_oklab(oklch_to_oklab(lch))
+    }
+
+    /// Get the colour with a different Oklab lightness, keeping its hue and
+    /// chroma
+    ///
+    /// `lightness` is in the range `0.0..=1.0`. If the colour would be outside
+    /// of the sRGB gamut, its chroma is reduced until it isn't, so the hue
+    /// doesn't change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let light = Colour::BLUE.normalise_lightness(0.8);
+    /// assert!((light.to_oklch()[0] - 0.8).abs() < 5e-3);
+    /// assert!((light.to_oklch()[2] - Colour::BLUE.to_oklch()[2]).abs() < 2.0);
+    ///
+    /// // Navy and a light blue look alike once they have the same lightness
+    /// let navy = Colour::from_rgb(0, 0, 128).normalise_lightness(0.7);
+    /// let light_blue = Colour::from_rgb(160, 192, 255).normalise_lightness(0.7);
+    /// assert!(navy.delta_e(light_blue) < 10.0);
+    ///
+    /// assert_eq!(Colour::WHITE, Colour::RED.normalise_lightness(1.0));
+    /// ```
+    pub fn normalise_lightness(self, lightness: f32) -> Self {
+        let [_, c, h] = self.to_oklch();
+        from_oklch_in_gamut([lightness, c, h])
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    lab::{linear_to_xyz, transform, xyz_to_linear},
+    space, Colour, OutOfGamutError,
+};
+
+fn p3_to_xyz(rgb: [f32; 3]) -> [f32; 3] {
+    transform(
+        [
+            [0.486_570_95, 0.265_667_7, 0.198_217_29],
+            [0.228_974_56, 0.691_738_5, 0.079_286_91],
+            [0.0, 0.045_113_38, 1.043_944_4],
+        ],
+        rgb,
+    )
+}
+
+fn xyz_to_p3(xyz: [f32; 3]) -> [f32; 3] {
+    transform(
+        [
+            [2.493_497, -0.931_383_6, -0.402_710_8],
+            [-0.829_489, 1.762_664_1, 0.023_624_687],
+            [0.035_845_83, -0.076_172_39, 0.956_884_5],
+        ],
+        xyz,
+    )
+}
+
+/// Convert Display P3 components to linear light sRGB, which may be out of gamut
+fn p3_to_linear(rgb: [f32; 3]) -> [f32; 3] {
+    xyz_to_linear(p3_to_xyz(rgb.map(space::decode_channel)))
+}
+
+impl Colour {
+    /// Make a new colour using Display P3 components
+    ///
+    /// Components are in the range `0.0..=1.0`. Display P3 has a wider gamut than
+    /// sRGB, colours outside of the sRGB gamut are clamped, use
+    /// [`Colour::from_display_p3_checked`] to detect them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(255, 0, 0), Colour::from_display_p3(0.9175, 0.2003, 0.1386));
+    /// assert_eq!(Colour::from_rgb(128, 128, 128), Colour::from_display_p3(0.5, 0.5, 0.5));
+    ///
+    /// // Display P3 red is redder than anything sRGB can show
+    /// assert_eq!(Colour::from_rgb(255, 0, 0), Colour::from_display_p3(1.0, 0.0, 0.0));
+    /// ```
+    pub fn from_display_p3(red: f32, green: f32, blue: f32) -> Self {
+        Self::from_linear(p3_to_linear([red, green, blue]))
+    }
+
+    /// Make a new colour using Display P3 components, failing if the colour is
+    /// outside of the sRGB gamut
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, OutOfGamutError};
+    ///
+    /// assert_eq!(Ok(Colour::WHITE), Colour::from_display_p3_checked(1.0, 1.0, 1.0));
+    /// assert_eq!(Err(OutOfGamutError), Colour::from_display_p3_checked(1.0, 0.0, 0.0));
+    /// assert_eq!(Err(OutOfGamutError), Colour::from_display_p3_checked(0.2, 0.6, 0.86));
+    /// ```
+    pub fn from_display_p3_checked(
+        red: f32,
+        green: f32,
+        blue: f32,
+    ) -> Result<Self, OutOfGamutError> {
+        let linear = p3_to_linear([red, green, blue]);
+        if space::in_gamut(linear) {
+            Ok(Self::from_linear(linear))
+        } else {
+            Err(OutOfGamutError)
+        }
+    }
+
+    /// Get the Display P3 components of the colour
+    ///
+    /// Every sRGB colour is inside the Display P3 gamut, so the components are
+    /// always in the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// # fn assert_close(a: [f32; 3], b: [f32; 3]) {
+    /// #     assert!(a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-3), "{a:?} != {b:?}");
+    /// # }
+    /// assert_close([0.9175, 0.2003, 0.1386], Colour::from_rgb(255, 0, 0).to_display_p3());
+    /// assert_close([0.4584, 0.9853, 0.2983], Colour::from_rgb(0, 255, 0).to_display_p3());
+    /// assert_close([0.3208, 0.5880, 0.8369], Colour::BLUE.to_display_p3());
+    /// assert_close([1.0, 1.0, 1.0], Colour::WHITE.to_display_p3());
+    /// ```
+    pub fn to_display_p3(self) -> [f32; 3] {
+        xyz_to_p3(linear_to_xyz(self.to_linear())).map(|c| space::encode_channel(c.clamp(0.0, 1.0)))
+    }
+}
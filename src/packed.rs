@@ -0,0 +1,97 @@
+use crate::Colour;
+
+/// The order of the channel bytes in memory, used by [`Colour::to_packed`]
+///
+/// Colours have no alpha, so it is `0xff` when packing and ignored when
+/// unpacking.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PackedFormat {
+    Rgba,
+    Argb,
+    Bgra,
+    Abgr,
+}
+
+/// The byte order used to read a packed colour as a `u32`, used by
+/// [`Colour::to_packed`]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Endian {
+    Little,
+    Big,
+}
+
+impl Endian {
+    /// The byte order of the target platform
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Endian;
+    ///
+    /// assert_eq!(cfg!(target_endian = "little"), Endian::NATIVE == Endian::Little);
+    /// ```
+    pub const NATIVE: Self = if cfg!(target_endian = "big") {
+        Self::Big
+    } else {
+        Self::Little
+    };
+}
+
+impl Colour {
+    /// Get the colour as a `u32` for passing to other libraries
+    ///
+    /// `format` is the order of the bytes in memory, and `endian` is how those
+    /// bytes are read as a `u32`. A C library which takes RGBA bytes as a
+    /// `uint32_t` on a little endian platform wants `0xAABBGGRR`, which is
+    /// `PackedFormat::Rgba` with `Endian::Little`. Prefer this over
+    /// [`Colour::as_u32`] for FFI, as that is always `0x00RRGGBB`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Endian, PackedFormat};
+    ///
+    /// assert_eq!(0xffdb9834, Colour::BLUE.to_packed(PackedFormat::Rgba, Endian::Little));
+    /// assert_eq!(0x3498dbff, Colour::BLUE.to_packed(PackedFormat::Rgba, Endian::Big));
+    /// ```
+    pub const fn to_packed(self, format: PackedFormat, endian: Endian) -> u32 {
+        let (red, green, blue, alpha) = (self.red(), self.green(), self.blue(), 0xff);
+        let bytes = match format {
+            PackedFormat::Rgba => [red, green, blue, alpha],
+            PackedFormat::Argb => [alpha, red, green, blue],
+            PackedFormat::Bgra => [blue, green, red, alpha],
+            PackedFormat::Abgr => [alpha, blue, green, red],
+        };
+        match endian {
+            Endian::Little => u32::from_le_bytes(bytes),
+            Endian::Big => u32::from_be_bytes(bytes),
+        }
+    }
+
+    /// Make a new colour from a `u32` given by other libraries
+    ///
+    /// See [`Colour::to_packed`] for what `format` and `endian` mean. The
+    /// alpha channel is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Endian, PackedFormat};
+    ///
+    /// assert_eq!(Colour::BLUE, Colour::from_packed(0x80db9834, PackedFormat::Rgba, Endian::Little));
+    /// assert_eq!(Colour::BLUE, Colour::from_packed(0x803498db, PackedFormat::Argb, Endian::Big));
+    /// ```
+    pub const fn from_packed(value: u32, format: PackedFormat, endian: Endian) -> Self {
+        let bytes = match endian {
+            Endian::Little => value.to_le_bytes(),
+            Endian::Big => value.to_be_bytes(),
+        };
+        let [red, green, blue] = match format {
+            PackedFormat::Rgba => [bytes[0], bytes[1], bytes[2]],
+            PackedFormat::Argb => [bytes[1], bytes[2], bytes[3]],
+            PackedFormat::Bgra => [bytes[2], bytes[1], bytes[0]],
+            PackedFormat::Abgr => [bytes[3], bytes[2], bytes[1]],
+        };
+        Self::from_rgb(red, green, blue)
+    }
+}
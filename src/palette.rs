@@ -0,0 +1,148 @@
+use crate::Colour;
+use core::ops;
+
+/// A palette with room for up to `N` colours, which doesn't allocate
+///
+/// The palette dereferences to a slice of its colours.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, Palette};
+///
+/// let mut palette = Palette::<4>::new();
+/// palette.push(Colour::RED).unwrap();
+/// palette.push(Colour::GREEN).unwrap();
+/// palette.push(Colour::BLUE).unwrap();
+///
+/// assert_eq!(3, palette.len());
+/// assert_eq!(Some(Colour::RED), palette.nearest(Colour::DARK_RED));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Palette<const N: usize> {
+    colours: [Colour; N],
+    len: usize,
+}
+
+impl<const N: usize> Palette<N> {
+    /// Make a new empty palette
+    pub const fn new() -> Self {
+        Self {
+            colours: [Colour::BLACK; N],
+            len: 0,
+        }
+    }
+
+    /// Get the number of colours the palette has room for
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Check whether the palette has no room for more colours
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Add a colour to the end of the palette
+    ///
+    /// The colour is given back if the palette is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Palette};
+    ///
+    /// let mut palette = Palette::<1>::new();
+    /// assert_eq!(Ok(()), palette.push(Colour::RED));
+    /// assert_eq!(Err(Colour::BLUE), palette.push(Colour::BLUE));
+    /// ```
+    pub fn push(&mut self, colour: Colour) -> Result<(), Colour> {
+        if self.is_full() {
+            return Err(colour);
+        }
+
+        self.colours[self.len] = colour;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Remove the last colour of the palette
+    pub fn pop(&mut self) -> Option<Colour> {
+        self.len = self.len.checked_sub(1)?;
+        Some(self.colours[self.len])
+    }
+
+    /// Remove every colour from the palette
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Get the colour in the palette which looks closest to `colour`
+    ///
+    /// Closeness is measured with [`Colour::delta_e`].
+    pub fn nearest(&self, colour: Colour) -> Option<Colour> {
+        self.iter()
+            .copied()
+            .min_by(|a, b| colour.delta_e(*a).total_cmp(&colour.delta_e(*b)))
+    }
+
+    /// Sort the colours of the palette by their value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Palette};
+    ///
+    /// let mut palette = Palette::<3>::from_iter([Colour::WHITE, Colour::BLACK, Colour::BLUE]);
+    /// palette.sort();
+    /// assert_eq!([Colour::BLACK, Colour::BLUE, Colour::WHITE], palette[..]);
+    /// ```
+    pub fn sort(&mut self) {
+        self.sort_unstable();
+    }
+}
+
+impl<const N: usize> Default for Palette<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> PartialEq for Palette<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<const N: usize> Eq for Palette<N> {}
+
+impl<const N: usize> ops::Deref for Palette<N> {
+    type Target = [Colour];
+
+    fn deref(&self) -> &Self::Target {
+        &self.colours[..self.len]
+    }
+}
+
+impl<const N: usize> ops::DerefMut for Palette<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.colours[..self.len]
+    }
+}
+
+impl<const N: usize> From<[Colour; N]> for Palette<N> {
+    fn from(colours: [Colour; N]) -> Self {
+        Self { colours, len: N }
+    }
+}
+
+/// Collect colours into a palette, ignoring any that don't fit
+impl<const N: usize> FromIterator<Colour> for Palette<N> {
+    fn from_iter<I: IntoIterator<Item = Colour>>(iter: I) -> Self {
+        let mut palette = Self::new();
+        for colour in iter.into_iter().take(N) {
+            let _ = palette.push(colour);
+        }
+        palette
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{Colour, ColourLike};
+use alloc::vec::Vec;
+use core::{error, fmt};
+
+/// How entries of two palettes are paired up by [`palette_diff_with`]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Pairing {
+    /// Pair entries with the same index, the palettes must have the same length
+    #[default]
+    Index,
+    /// Pair each entry of the first palette with the closest entry of the second
+    Nearest,
+}
+
+/// An error returned when two palettes can't be compared
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PaletteDiffError {
+    /// The palettes have different lengths but were paired by index
+    LengthMismatch { left: usize, right: usize },
+    /// The second palette is empty so there is nothing to pair with
+    Empty,
+}
+
+impl fmt::Display for PaletteDiffError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch { left, right } => write!(
+                f,
+                "palettes have different lengths ({left} and {right}) and can't be paired by index"
+            ),
+            Self::Empty => f.write_str("palette to pair with is empty"),
+        }
+    }
+}
+
+impl error::Error for PaletteDiffError {}
+
+/// A pair of colours compared by [`palette_diff`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PalettePair {
+    /// Index of the colour in the first palette
+    pub left_index: usize,
+    /// Index of the colour in the second palette
+    pub right_index: usize,
+    pub left: Colour,
+    pub right: Colour,
+    /// The Delta E between the two colours
+    pub delta: f32,
+}
+
+/// A comparison between two palettes
+///
+/// The [`Display`](fmt::Display) implementation prints a table of every pair.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{palette_diff, Colour};
+///
+/// let approved = [Colour::BLUE, Colour::GOLD, Colour::RED];
+/// let generated = [Colour::BLUE, Colour::from_rgb(241, 196, 20), Colour::ORANGE];
+/// let diff = palette_diff(&approved, &generated).unwrap();
+///
+/// assert_eq!(0.0, diff.pairs()[0].delta);
+/// assert!(diff.mean_delta() < diff.max_delta());
+/// assert_eq!(vec![2], diff.exceeding(5.0).map(|pair| pair.left_index).collect::<Vec<_>>());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PaletteDiff {
+    pairs: Vec<PalettePair>,
+}
+
+impl PaletteDiff {
+    /// Get every compared pair, in the order of the first palette
+    pub fn pairs(&self) -> &[PalettePair] {
+        &self.pairs
+    }
+
+    /// Get the largest Delta E of any pair, or `0.0` if there are no pairs
+    pub fn max_delta(&self) -> f32 {
+        self.pairs.iter().map(|pair| pair.delta).fold(0.0, f32::max)
+    }
+
+    /// Get the mean Delta E of all pairs, or `0.0` if there are no pairs
+    pub fn mean_delta(&self) -> f32 {
+        if self.pairs.is_empty() {
+            return 0.0;
+        }
+
+        self.pairs.iter().map(|pair| pair.delta).sum::<f32>() / self.pairs.len() as f32
+    }
+
+    /// Get the pairs with a Delta E greater than `threshold`
+    pub fn exceeding(&self, threshold: f32) -> impl Iterator<Item = &PalettePair> {
+        self.pairs.iter().filter(move |pair| pair.delta > threshold)
+    }
+}
+
+impl fmt::Display for PaletteDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>5}  {:<7}  {:>5}  {:<7}  {:>7}",
+            "#", "left", "#", "right", "ΔE"
+        )?;
+        for pair in &self.pairs {
+            writeln!(
+                f,
+                "{:>5}  {}  {:>5}  {}  {:>7.2}",
+                pair.left_index, pair.left, pair.right_index, pair.right, pair.delta
+            )?;
+        }
+        write!(
+            f,
+            "max ΔE {:.2}, mean ΔE {:.2}",
+            self.max_delta(),
+            self.mean_delta()
+        )
+    }
+}
+
+/// Compare two palettes entry by entry
+///
+/// Entries are paired by index, see [`palette_diff_with`] for other pairings.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{palette_diff, Colour, PaletteDiffError};
+///
+/// let diff = palette_diff(&[Colour::WHITE], &[Colour::BLACK]).unwrap();
+/// assert_eq!(
+///     diff.to_string(),
+///     "    #  left         #  right         ΔE\n    0  #ffffff      0  #000000   100.00\nmax ΔE 100.00, mean ΔE 100.00",
+/// );
+///
+/// assert_eq!(
+///     Err(PaletteDiffError::LengthMismatch { left: 1, right: 0 }),
+///     palette_diff(&[Colour::WHITE], &[]),
+/// );
+/// ```
+pub fn palette_diff<C: ColourLike>(a: &[C], b: &[C]) -> Result<PaletteDiff, PaletteDiffError> {
+    palette_diff_with(a, b, Pairing::Index)
+}
+
+/// Compare two palettes using the given pairing
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{palette_diff_with, Colour, Pairing};
+///
+/// let approved = [Colour::RED, Colour::GREEN, Colour::BLUE];
+/// let generated = [Colour::BLUE, Colour::RED];
+/// let diff = palette_diff_with(&approved, &generated, Pairing::Nearest).unwrap();
+///
+/// let pairs = diff.pairs();
+/// assert_eq!((0, 1), (pairs[0].left_index, pairs[0].right_index));
+/// assert_eq!((2, 0), (pairs[2].left_index, pairs[2].right_index));
+/// ```
+pub fn palette_diff_with<C: ColourLike>(
+    a: &[C],
+    b: &[C],
+    pairing: Pairing,
+) -> Result<PaletteDiff, PaletteDiffError> {
+    let pair = |left_index: usize, right_index: usize| PalettePair {
+        left_index,
+        right_index,
+        left: a[left_index].as_colour(),
+        right: b[right_index].as_colour(),
+        delta: a[left_index]
+            .as_colour()
+            .delta_e(b[right_index].as_colour()),
+    };
+
+    let pairs = match pairing {
+        Pairing::Index if a.len() != b.len() => {
+            return Err(PaletteDiffError::LengthMismatch {
+                left: a.len(),
+                right: b.len(),
+            })
+        }
+        Pairing::Index => (0..a.len()).map(|i| pair(i, i)).collect(),
+        Pairing::Nearest if b.is_empty() && !a.is_empty() => return Err(PaletteDiffError::Empty),
+        Pairing::Nearest => (0..a.len())
+            .map(|i| {
+                (0..b.len())
+                    .map(|j| pair(i, j))
+                    .min_by(|x, y| x.delta.total_cmp(&y.delta))
+                    .unwrap()
+            })
+            .collect(),
+    };
+
+    Ok(PaletteDiff { pairs })
+}
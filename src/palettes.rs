@@ -0,0 +1,118 @@
+//! Categorical palettes for data visualisation
+//!
+//! # Examples
+//!
+//! ```
+//! use chrom::{palettes, Colour};
+//!
+//! assert_eq!(Colour::new(0xe69f00), palettes::OKABE_ITO[1]);
+//! assert_eq!(12, palettes::categorical(12).len());
+//! ```
+
+use crate::Colour;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The Tableau 10 palette
+///
+/// The default categorical palette of Tableau since version 10, designed by
+/// Maureen Stone. Values are the ones distributed with d3-scale-chromatic under
+/// the ISC license.
+pub const TABLEAU_10: [Colour; 10] = [
+    Colour(0x4e79a7),
+    Colour(0xf28e2b),
+    Colour(0xe15759),
+    Colour(0x76b7b2),
+    Colour(0x59a14f),
+    Colour(0xedc948),
+    Colour(0xb07aa1),
+    Colour(0xff9da7),
+    Colour(0x9c755f),
+    Colour(0xbab0ac),
+];
+
+/// The ColorBrewer Set1 palette
+///
+/// Designed by Cynthia Brewer for ColorBrewer, licensed under Apache 2.0.
+pub const SET1: [Colour; 9] = [
+    Colour(0xe41a1c),
+    Colour(0x377eb8),
+    Colour(0x4daf4a),
+    Colour(0x984ea3),
+    Colour(0xff7f00),
+    Colour(0xffff33),
+    Colour(0xa65628),
+    Colour(0xf781bf),
+    Colour(0x999999),
+];
+
+/// The ColorBrewer Set2 palette
+///
+/// Designed by Cynthia Brewer for ColorBrewer, licensed under Apache 2.0.
+pub const SET2: [Colour; 8] = [
+    Colour(0x66c2a5),
+    Colour(0xfc8d62),
+    Colour(0x8da0cb),
+    Colour(0xe78ac3),
+    Colour(0xa6d854),
+    Colour(0xffd92f),
+    Colour(0xe5c494),
+    Colour(0xb3b3b3),
+];
+
+/// The ColorBrewer Dark2 palette
+///
+/// Designed by Cynthia Brewer for ColorBrewer, licensed under Apache 2.0.
+pub const DARK2: [Colour; 8] = [
+    Colour(0x1b9e77),
+    Colour(0xd95f02),
+    Colour(0x7570b3),
+    Colour(0xe7298a),
+    Colour(0x66a61e),
+    Colour(0xe6ab02),
+    Colour(0xa6761d),
+    Colour(0x666666),
+];
+
+/// The Okabe-Ito palette
+///
+/// A colour-blind safe palette from Masataka Okabe and Kei Ito's "Color Universal
+/// Design" (2002), in its published order. The colours are not subject to
+/// copyright.
+pub const OKABE_ITO: [Colour; 8] = [
+    Colour(0x000000),
+    Colour(0xe69f00),
+    Colour(0x56b4e9),
+    Colour(0x009e73),
+    Colour(0xf0e442),
+    Colour(0x0072b2),
+    Colour(0xd55e00),
+    Colour(0xcc79a7),
+];
+
+/// Get `n` colours for a categorical chart
+///
+/// Uses [`OKABE_ITO`] when it has enough colours, since it is colour-blind safe,
+/// and [`TABLEAU_10`] otherwise. Colours are repeated when `n` is larger than
+/// the palette.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::palettes::{self, OKABE_ITO, TABLEAU_10};
+///
+/// assert_eq!(OKABE_ITO[..3], palettes::categorical(3));
+/// assert_eq!(TABLEAU_10[..9], palettes::categorical(9));
+/// assert_eq!(TABLEAU_10[0], palettes::categorical(11)[10]);
+/// assert!(palettes::categorical(0).is_empty());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn categorical(n: usize) -> Vec<Colour> {
+    let palette: &[Colour] = if n <= OKABE_ITO.len() {
+        &OKABE_ITO
+    } else {
+        &TABLEAU_10
+    };
+
+    palette.iter().copied().cycle().take(n).collect()
+}
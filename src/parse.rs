@@ -0,0 +1,701 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{
+    literal::HEX_DIGITS, Colour, ColourStop, CssColourSpace, Gradient, GradientError, Rgba,
+};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::{error, fmt, str};
+
+/// An error returned when a string can't be parsed as a colour
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseColourError {
+    /// A hex colour has a number of digits other than 3 or 6
+    InvalidLength(usize),
+    /// A hex colour contains a character that isn't a hex digit
+    InvalidDigit(char),
+    /// A `color()` function uses a colour space that isn't supported
+    UnknownColourSpace(String),
+    /// A word which isn't one of the [`CSS_NAMES`](crate::CSS_NAMES)
+    UnknownName(String),
+    /// The string isn't in any of the supported formats
+    InvalidSyntax,
+    /// The string is longer than [`MAX_COLOUR_LENGTH`] bytes
+    TooLong(usize),
+}
+
+impl fmt::Display for ParseColourError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(length) => {
+                write!(f, "hex colour has {length} digits, expected 3 or 6")
+            }
+            Self::InvalidDigit(digit) => write!(f, "invalid hex digit {digit:?}"),
+            Self::UnknownColourSpace(space) => write!(f, "unknown colour space {space:?}"),
+            Self::UnknownName(name) => write!(f, "unknown colour name {name:?}"),
+            Self::InvalidSyntax => f.write_str("invalid colour syntax"),
+            Self::TooLong(length) => write!(
+                f,
+                "colour is {length} bytes long, the limit is {MAX_COLOUR_LENGTH}"
+            ),
+        }
+    }
+}
+
+impl error::Error for ParseColourError {}
+
+/// The longest string, in bytes, which will be parsed as a [`Colour`]
+///
+/// No supported colour needs anywhere near this many, so longer strings are
+/// rejected before doing any work on them.
+pub const MAX_COLOUR_LENGTH: usize = 256;
+
+/// An error returned when a string can't be parsed as a gradient
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseGradientError {
+    /// A colour stop has a colour which can't be parsed
+    InvalidColour(ParseColourError),
+    /// The colour stops don't make a valid gradient
+    InvalidStops(GradientError),
+    /// The string isn't a `linear-gradient()`, or a stop position isn't a
+    /// percentage
+    InvalidSyntax,
+}
+
+impl fmt::Display for ParseGradientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidColour(error) => write!(f, "invalid gradient colour: {error}"),
+            Self::InvalidStops(error) => error.fmt(f),
+            Self::InvalidSyntax => f.write_str("invalid gradient syntax"),
+        }
+    }
+}
+
+impl error::Error for ParseGradientError {}
+
+/// Get the arguments of a CSS function call like `name(arguments)`
+fn function<'a>(s: &'a str, name: &str) -> Option<&'a str> {
+    let (function, rest) = s.split_once('(')?;
+    if !function.trim_end().eq_ignore_ascii_case(name) {
+        return None;
+    }
+
+    rest.strip_suffix(')')
+}
+
+/// Combine hex digits into a number, or `None` if any of them are invalid
+fn hex_value<const N: usize>(digits: [u8; N]) -> Option<u32> {
+    let digits = digits.map(|byte| HEX_DIGITS[usize::from(byte)]);
+    if digits.contains(&0xff) {
+        return None;
+    }
+
+    Some(
+        digits
+            .iter()
+            .fold(0, |value, &digit| value << 4 | u32::from(digit)),
+    )
+}
+
+fn parse_hex(digits: &str) -> Result<Colour, ParseColourError> {
+    // The common cases take a single pass over the bytes
+    match *digits.as_bytes() {
+        [red, green, blue] => {
+            if let Some(value) = hex_value([red, green, blue]) {
+                let [red, green, blue] =
+                    [value >> 8, value >> 4, value].map(|c| (c & 0xf) as u8 * 17);
+                return Ok(Colour::from_rgb(red, green, blue));
+            }
+        }
+        [a, b, c, d, e, f] => {
+            if let Some(value) = hex_value([a, b, c, d, e, f]) {
+                return Ok(Colour(value));
+            }
+        }
+        _ => {}
+    }
+
+    match digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+        Some(digit) => Err(ParseColourError::InvalidDigit(digit)),
+        None => Err(ParseColourError::InvalidLength(digits.len())),
+    }
+}
+
+/// Parse a number or percentage, where `100%` is `1.0`
+fn parse_component(s: &str) -> Result<f32, ParseColourError> {
+    let (number, scale) = match s.strip_suffix('%') {
+        Some(number) => (number, 0.01),
+        None => (s, 1.0),
+    };
+
+    match number.parse::<f32>() {
+        Ok(value) if value.is_finite() => Ok(value * scale),
+        _ => Err(ParseColourError::InvalidSyntax),
+    }
+}
+
+fn parse_color_function(arguments: &str) -> Result<Colour, ParseColourError> {
+    let mut arguments = arguments.split_whitespace();
+    let space = arguments.next().ok_or(ParseColourError::InvalidSyntax)?;
+    let mut component = || arguments.next().ok_or(ParseColourError::InvalidSyntax);
+    let [a, b, c] = [component()?, component()?, component()?];
+    if arguments.next().is_some() {
+        return Err(ParseColourError::InvalidSyntax);
+    }
+    let [a, b, c] = [
+        parse_component(a)?,
+        parse_component(b)?,
+        parse_component(c)?,
+    ];
+
+    match CssColourSpace::from_keyword(space) {
+        Some(space) => Ok(space.colour([a, b, c])),
+        None => Err(ParseColourError::UnknownColourSpace(space.to_owned())),
+    }
+}
+
+/// Parse the arguments of an `rgb()` function, either separated by commas
+/// like `52, 152, 219` or by spaces like `20% 60% 86%`
+fn parse_rgb_function(arguments: &str) -> Result<Colour, ParseColourError> {
+    let components: Vec<&str> = if arguments.contains(',') {
+        arguments.split(',').map(str::trim).collect()
+    } else {
+        arguments.split_whitespace().collect()
+    };
+    let [red, green, blue] = components[..] else {
+        return Err(ParseColourError::InvalidSyntax);
+    };
+
+    let mut channels = [0; 3];
+    for (channel, component) in channels.iter_mut().zip([red, green, blue]) {
+        let value = match component.strip_suffix('%') {
+            Some(_) => parse_component(component)? * 255.0,
+            None => parse_component(component)?,
+        };
+        *channel = value.clamp(0.0, 255.0).round() as u8;
+    }
+    let [red, green, blue] = channels;
+    Ok(Colour::from_rgb(red, green, blue))
+}
+
+/// Parse an `rgb()` or `color()` function, or get `None` if the string isn't
+/// one
+fn parse_function(s: &str) -> Option<Result<Colour, ParseColourError>> {
+    match function(s, "rgb") {
+        Some(arguments) => Some(parse_rgb_function(arguments)),
+        None => function(s, "color").map(parse_color_function),
+    }
+}
+
+/// Parse a CSS colour
+///
+/// These formats are supported:
+///
+/// - Hex colours, `#rgb` and `#rrggbb`
+/// - The `rgb()` function, with numbers from 0 to 255 or percentages
+///   separated by commas like `rgb(52, 152, 219)` or spaces like
+///   `rgb(20% 60% 86%)`
+/// - The `color()` function, with the colour spaces in [`CssColourSpace`]
+/// - The [`CSS_NAMES`](crate::CSS_NAMES), ignoring case
+///
+/// Colours outside of the sRGB gamut are clamped. Alpha isn't supported,
+/// since colours are opaque. Strings longer than [`MAX_COLOUR_LENGTH`] are
+/// rejected without being parsed.
+///
+/// [`Colour::parse`] and `Colour::try_from` do the same.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ParseColourError};
+///
+/// assert_eq!(Ok(Colour::BLUE), "#3498db".parse());
+/// assert_eq!(Ok(Colour::new(0xffcc00)), "#fc0".parse());
+/// assert_eq!(Ok(Colour::BLUE), "rgb(52, 152, 219)".parse());
+/// assert_eq!(Ok(Colour::BLUE), "RGB(52 152 219)".parse());
+/// assert_eq!(Ok(Colour::new(0x3399db)), "rgb(20% 60% 86%)".parse());
+/// assert_eq!(Ok(Colour::new(0xff0080)), "rgb(300, -5, 50%)".parse());
+/// assert_eq!(Ok(Colour::new(0x663399)), "rebeccapurple".parse());
+/// assert_eq!(Ok(Colour::new(0xff6347)), " Tomato ".parse());
+/// assert_eq!(Ok(Colour::from_rgb(255, 0, 0)), "color(display-p3 0.9175 0.2003 0.1386)".parse());
+/// assert_eq!(Ok(Colour::from_rgb(0, 156, 225)), "color(display-p3 0.2 0.6 0.86)".parse());
+/// assert_eq!(Ok(Colour::WHITE), "color(display-p3 100% 100% 100%)".parse());
+/// assert_eq!(Ok(Colour::new(0x3399db)), "color(srgb 0.2 0.6 0.86)".parse());
+/// assert_eq!(Ok(Colour::new(0x7ccbef)), "color(SRGB-Linear 0.2 0.6 0.86)".parse());
+/// assert_eq!(Ok(Colour::WHITE), "color(xyz-d65 0.9505 1 1.0891)".parse());
+///
+/// assert_eq!(Err(ParseColourError::InvalidLength(4)), "#fc00".parse::<Colour>());
+/// assert_eq!(Err(ParseColourError::InvalidDigit('g')), "#fcg".parse::<Colour>());
+/// assert_eq!(
+///     Err(ParseColourError::UnknownName("blurple".to_owned())),
+///     "blurple".parse::<Colour>(),
+/// );
+/// assert_eq!(Err(ParseColourError::InvalidSyntax), "rgb(52, 152)".parse::<Colour>());
+/// assert_eq!(Err(ParseColourError::InvalidSyntax), "rgb(52 152 219 / 50%)".parse::<Colour>());
+/// assert_eq!(Err(ParseColourError::InvalidSyntax), "rgb(52, 152 219)".parse::<Colour>());
+/// assert_eq!(
+///     Err(ParseColourError::UnknownColourSpace("rec2020".to_owned())),
+///     "color(rec2020 1 0 0)".parse::<Colour>(),
+/// );
+/// assert_eq!(Err(ParseColourError::InvalidSyntax), "color(display-p3 1 0)".parse::<Colour>());
+/// assert_eq!(Err(ParseColourError::InvalidSyntax), "color(display-p3 1 0 0 0)".parse::<Colour>());
+/// assert_eq!(Err(ParseColourError::InvalidLength(7)), "#3498db0".parse::<Colour>());
+/// assert_eq!(
+///     Err(ParseColourError::TooLong(1016)),
+///     format!("color(srgb {} 0 0)", "1".repeat(1000)).parse::<Colour>(),
+/// );
+/// ```
+impl str::FromStr for Colour {
+    type Err = ParseColourError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() > MAX_COLOUR_LENGTH {
+            return Err(ParseColourError::TooLong(s.len()));
+        }
+
+        // Skip trimming, which is slower than parsing, when there can't be
+        // whitespace at either end
+        if let [b'#', .., last] = s.as_bytes() {
+            if last.is_ascii_hexdigit() {
+                return parse_hex(&s[1..]);
+            }
+        }
+
+        let s = s.trim();
+        if let Some(digits) = s.strip_prefix('#') {
+            parse_hex(digits)
+        } else if let Some(result) = parse_function(s) {
+            result
+        } else if !s.is_empty() && s.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+            Colour::from_name(s).ok_or_else(|| ParseColourError::UnknownName(s.to_owned()))
+        } else {
+            Err(ParseColourError::InvalidSyntax)
+        }
+    }
+}
+
+/// Parse a CSS colour with alpha
+///
+/// Hex colours with alpha, `#rgba` and `#rrggbbaa`, are supported, as well
+/// as every format a [`Colour`] can be parsed from, which are opaque, and
+/// `transparent` like [`ParsedColour`].
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ParseColourError, Rgba};
+///
+/// assert_eq!(Ok(Colour::BLUE.with_alpha(0x80)), "#3498db80".parse());
+/// assert_eq!(Ok(Rgba::from_u32(0xffcc0088)), "#fc08".parse());
+/// assert_eq!(Ok(Colour::BLUE.with_alpha(0xff)), "#3498db".parse());
+/// assert_eq!(Ok(Colour::new(0xff6347).with_alpha(0xff)), "tomato".parse());
+/// assert_eq!(Ok(Rgba::TRANSPARENT), "transparent".parse());
+///
+/// assert_eq!(Err(ParseColourError::InvalidDigit('g')), "#3498db8g".parse::<Rgba>());
+/// assert_eq!(Err(ParseColourError::InvalidLength(5)), "#3498d".parse::<Rgba>());
+/// ```
+impl str::FromStr for Rgba {
+    type Err = ParseColourError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() > MAX_COLOUR_LENGTH {
+            return Err(ParseColourError::TooLong(s.len()));
+        }
+
+        if let Some(digits) = s.trim().strip_prefix('#') {
+            match *digits.as_bytes() {
+                [red, green, blue, alpha] => {
+                    if let Some(value) = hex_value([red, green, blue, alpha]) {
+                        let [red, green, blue, alpha] =
+                            [value >> 12, value >> 8, value >> 4, value]
+                                .map(|c| (c & 0xf) as u8 * 17);
+                        return Ok(Self::from_rgba(red, green, blue, alpha));
+                    }
+                }
+                [a, b, c, d, e, f, g, h] => {
+                    if let Some(value) = hex_value([a, b, c, d, e, f, g, h]) {
+                        return Ok(Self::from_u32(value));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match s.parse()? {
+            ParsedColour::Opaque(colour) => Ok(colour.into()),
+            ParsedColour::Transparent => Ok(Self::TRANSPARENT),
+        }
+    }
+}
+
+impl TryFrom<&str> for Colour {
+    type Error = ParseColourError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl Colour {
+    /// Parse a CSS colour, the same as [`str::parse`]
+    ///
+    /// See the [`FromStr`](str::FromStr) implementation for the supported
+    /// formats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ParseColourError};
+    ///
+    /// assert_eq!(Ok(Colour::BLUE), Colour::parse("#3498db"));
+    /// assert_eq!(Ok(Colour::new(0xff6347)), Colour::try_from("tomato"));
+    /// assert_eq!(Err(ParseColourError::InvalidLength(2)), Colour::parse("#34"));
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, ParseColourError> {
+        s.parse()
+    }
+}
+
+/// The results of parsing many colours with [`parse_many`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParseReport {
+    /// The colours which were parsed, in the order of the input
+    pub colours: Vec<Colour>,
+    /// The index in the input and the error of every string which couldn't be
+    /// parsed
+    pub failures: Vec<(usize, ParseColourError)>,
+}
+
+impl ParseReport {
+    /// Get the number of strings which were parsed, successfully or not
+    pub fn total(&self) -> usize {
+        self.colours.len() + self.failures.len()
+    }
+
+    /// Check whether every string was parsed successfully
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Get the colours if every string was parsed successfully, or the
+    /// failures otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{parse_many, Colour, ParseColourError};
+    ///
+    /// assert_eq!(
+    ///     Ok(vec![Colour::BLUE, Colour::WHITE]),
+    ///     parse_many(["#3498db", "#fff"]).into_result(),
+    /// );
+    /// assert_eq!(
+    ///     Err(vec![(1, ParseColourError::InvalidDigit('g'))]),
+    ///     parse_many(["#3498db", "#ggg"]).into_result(),
+    /// );
+    /// ```
+    pub fn into_result(self) -> Result<Vec<Colour>, Vec<(usize, ParseColourError)>> {
+        if self.failures.is_empty() {
+            Ok(self.colours)
+        } else {
+            Err(self.failures)
+        }
+    }
+}
+
+/// Parse many colours, collecting every failure instead of stopping at the
+/// first
+///
+/// Each string is parsed like [`Colour`].
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{parse_many, Colour, ParseColourError};
+///
+/// let report = parse_many(["#3498db", "blurple", "#fff", "", "rgb(255, 0, 0)", "#12345"]);
+/// assert_eq!(vec![Colour::BLUE, Colour::WHITE, Colour::new(0xff0000)], report.colours);
+/// assert_eq!(
+///     vec![
+///         (1, ParseColourError::UnknownName("blurple".to_owned())),
+///         (3, ParseColourError::InvalidSyntax),
+///         (5, ParseColourError::InvalidLength(5)),
+///     ],
+///     report.failures,
+/// );
+/// assert_eq!(6, report.total());
+/// assert!(!report.is_ok());
+///
+/// let lines = "#000\n#fff\n";
+/// let report = parse_many(lines.lines());
+/// assert!(report.is_ok());
+/// assert_eq!(Ok(vec![Colour::BLACK, Colour::WHITE]), report.into_result());
+///
+/// assert_eq!(Ok(vec![]), parse_many([]).into_result());
+/// ```
+pub fn parse_many<'a>(inputs: impl IntoIterator<Item = &'a str>) -> ParseReport {
+    let mut report = ParseReport::default();
+    for (i, input) in inputs.into_iter().enumerate() {
+        match input.parse() {
+            Ok(colour) => report.colours.push(colour),
+            Err(error) => report.failures.push((i, error)),
+        }
+    }
+    report
+}
+
+/// Split the arguments of a CSS function at commas which aren't nested inside
+/// of another function
+fn split_arguments(arguments: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0_usize, 0);
+    for (i, c) in arguments.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(arguments[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(arguments[start..].trim());
+    parts
+}
+
+/// Parse a CSS `linear-gradient()`
+///
+/// Stops are a colour, parsed like [`Colour`], and optionally a percentage.
+/// Either every stop has a percentage, or none do and they are evenly spaced.
+/// A direction before the stops, like `to right` or `90deg`, is allowed but
+/// ignored.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ColourStop, Gradient, GradientError, ParseGradientError};
+///
+/// let gradient: Gradient = "linear-gradient(#ff0000 0%, #3498db 25%, color(srgb 1 1 1) 100%)"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(
+///     [
+///         ColourStop::new(0.0, Colour::from_rgb(255, 0, 0)),
+///         ColourStop::new(0.25, Colour::BLUE),
+///         ColourStop::new(1.0, Colour::WHITE),
+///     ],
+///     gradient.stops(),
+/// );
+///
+/// let gradient: Gradient = "linear-gradient(to right, #000, #fff)".parse().unwrap();
+/// assert_eq!(Gradient::new(Colour::BLACK, Colour::WHITE), gradient);
+///
+/// assert_eq!(
+///     Err(ParseGradientError::InvalidStops(GradientError::Unsorted)),
+///     "linear-gradient(#000 50%, #fff 20%)".parse::<Gradient>(),
+/// );
+/// assert_eq!(
+///     Err(ParseGradientError::InvalidSyntax),
+///     "linear-gradient(#000 50%, #fff)".parse::<Gradient>(),
+/// );
+/// assert!(matches!(
+///     "linear-gradient(#000, #ggg)".parse::<Gradient>(),
+///     Err(ParseGradientError::InvalidColour(_)),
+/// ));
+/// ```
+impl str::FromStr for Gradient {
+    type Err = ParseGradientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let arguments =
+            function(s.trim(), "linear-gradient").ok_or(ParseGradientError::InvalidSyntax)?;
+        let mut arguments = split_arguments(arguments);
+        if let Some(first) = arguments.first() {
+            let is_angle = ["deg", "grad", "rad", "turn"]
+                .iter()
+                .any(|unit| first.ends_with(unit));
+            if first.starts_with("to ") || is_angle {
+                arguments.remove(0);
+            }
+        }
+
+        let mut colours = Vec::new();
+        let mut positions = Vec::new();
+        for stop in arguments {
+            let (colour, position) = match stop.rsplit_once(char::is_whitespace) {
+                Some((colour, position)) if position.ends_with('%') => {
+                    let position =
+                        parse_component(position).map_err(|_| ParseGradientError::InvalidSyntax)?;
+                    (colour, Some(position))
+                }
+                _ => (stop, None),
+            };
+            colours.push(colour.parse().map_err(ParseGradientError::InvalidColour)?);
+            positions.extend(position);
+        }
+
+        let gradient = if positions.is_empty() {
+            Self::from_colours(&colours)
+        } else if positions.len() == colours.len() {
+            let stops = positions.into_iter().zip(colours);
+            Self::from_stops(stops.map(|(p, c)| ColourStop::new(p, c)).collect())
+        } else {
+            return Err(ParseGradientError::InvalidSyntax);
+        };
+        gradient.map_err(ParseGradientError::InvalidStops)
+    }
+}
+
+impl Colour {
+    /// Parse a colour the way browsers parse legacy HTML attributes like
+    /// `bgcolor`
+    ///
+    /// This follows the WHATWG rules for parsing a legacy colour value, which
+    /// accept CSS named colours, `#rgb`, and otherwise make a colour out of any
+    /// string by replacing characters that aren't hex digits, padding and
+    /// truncating it. The only strings which can't be parsed are the empty
+    /// string and `transparent`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Some(Colour::new(0xc00000)), Colour::from_html_legacy("chucknorris"));
+    /// assert_eq!(Some(Colour::new(0x00a000)), Colour::from_html_legacy("ninjaturtle"));
+    /// assert_eq!(Some(Colour::new(0xc0a000)), Colour::from_html_legacy("crap"));
+    /// assert_eq!(Some(Colour::new(0x0f0f0f)), Colour::from_html_legacy("FFF"));
+    /// assert_eq!(Some(Colour::new(0x0f0f00)), Colour::from_html_legacy("#FF"));
+    /// assert_eq!(Some(Colour::new(0xff00ff)), Colour::from_html_legacy("ff0000ff"));
+    /// assert_eq!(Some(Colour::new(0xffcc00)), Colour::from_html_legacy("#fc0"));
+    /// assert_eq!(Some(Colour::new(0xff6347)), Colour::from_html_legacy(" Tomato "));
+    /// assert_eq!(Some(Colour::new(0x1278cd)), Colour::from_html_legacy("#1234567890abcdef"));
+    /// assert_eq!(Some(Colour::BLACK), Colour::from_html_legacy(" "));
+    /// assert_eq!(Some(Colour::BLACK), Colour::from_html_legacy("😀😀😀"));
+    /// assert_eq!(Some(Colour::WHITE), Colour::from_html_legacy(&"f".repeat(100_000)));
+    /// assert_eq!(None, Colour::from_html_legacy(""));
+    /// assert_eq!(None, Colour::from_html_legacy("Transparent"));
+    /// ```
+    pub fn from_html_legacy(s: &str) -> Option<Self> {
+        if s.is_empty() {
+            return None;
+        }
+        let s = s.trim_matches(|c: char| c.is_ascii_whitespace());
+        if s.eq_ignore_ascii_case("transparent") {
+            return None;
+        }
+        if let Some(colour) = Self::from_name(s) {
+            return Some(colour);
+        }
+        if let Some(digits) = s.strip_prefix('#') {
+            if digits.len() == 3 {
+                if let Ok(colour) = parse_hex(digits) {
+                    return Some(colour);
+                }
+            }
+        }
+
+        // Characters outside of the basic multilingual plane count as two, as
+        // they would in UTF-16. Only the first 128 are used, so stop there
+        // rather than copying all of a long string.
+        let mut chars = Vec::new();
+        for c in s.chars().take(128) {
+            match c {
+                '\u{10000}'.. => chars.extend(['0', '0']),
+                c => chars.push(c),
+            }
+        }
+        chars.truncate(128);
+        if chars.first() == Some(&'#') {
+            chars.remove(0);
+        }
+        let mut digits: Vec<u8> = chars
+            .into_iter()
+            .map(|c| c.to_digit(16).unwrap_or(0) as u8)
+            .collect();
+        while digits.is_empty() || !digits.len().is_multiple_of(3) {
+            digits.push(0);
+        }
+
+        let length = digits.len() / 3;
+        let mut components: Vec<&[u8]> = digits
+            .chunks_exact(length)
+            .map(|component| &component[length.saturating_sub(8)..])
+            .collect();
+        while components[0].len() > 2 && components.iter().all(|c| c[0] == 0) {
+            components.iter_mut().for_each(|c| *c = &c[1..]);
+        }
+        let [red, green, blue] = [0, 1, 2].map(|i| {
+            let component = &components[i][..components[i].len().min(2)];
+            component.iter().fold(0, |value, digit| value << 4 | digit)
+        });
+
+        Some(Self::from_rgb(red, green, blue))
+    }
+}
+
+/// A parsed CSS colour, which may be transparent
+///
+/// `transparent` and `none` parse as [`ParsedColour::Transparent`], everything
+/// else is parsed like [`Colour`].
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ParsedColour};
+///
+/// assert_eq!(Ok(ParsedColour::Opaque(Colour::BLUE)), "#3498db".parse());
+/// assert_eq!(Ok(ParsedColour::Transparent), "transparent".parse());
+/// assert_eq!(Ok(ParsedColour::Transparent), "None".parse());
+/// assert!("#3498d".parse::<ParsedColour>().is_err());
+///
+/// let colour: Option<Colour> = "transparent".parse::<ParsedColour>().unwrap().into();
+/// assert_eq!(None, colour);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParsedColour {
+    Opaque(Colour),
+    Transparent,
+}
+
+impl ParsedColour {
+    /// Get the colour, or `None` if it is transparent
+    pub fn colour(self) -> Option<Colour> {
+        match self {
+            Self::Opaque(colour) => Some(colour),
+            Self::Transparent => None,
+        }
+    }
+}
+
+impl str::FromStr for ParsedColour {
+    type Err = ParseColourError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("transparent") || s.eq_ignore_ascii_case("none") {
+            Ok(Self::Transparent)
+        } else {
+            s.parse().map(Self::Opaque)
+        }
+    }
+}
+
+impl From<Colour> for ParsedColour {
+    fn from(colour: Colour) -> Self {
+        Self::Opaque(colour)
+    }
+}
+
+impl From<Option<Colour>> for ParsedColour {
+    fn from(colour: Option<Colour>) -> Self {
+        colour.map_or(Self::Transparent, Self::Opaque)
+    }
+}
+
+impl From<ParsedColour> for Option<Colour> {
+    fn from(colour: ParsedColour) -> Self {
+        colour.colour()
+    }
+}
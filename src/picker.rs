@@ -0,0 +1,77 @@
+use crate::Colour;
+
+impl Colour {
+    /// Make a new colour from a position on the saturation and value plane of
+    /// a colour picker
+    ///
+    /// `x` is the HSV saturation, from `0.0` on the left to `1.0` on the right.
+    /// `y` goes from `0.0` at the top, where the HSV value is `1.0`, to `1.0`
+    /// at the bottom, where it is `0.0`. Both are clamped to the range
+    /// `0.0..=1.0`, and the hue is in degrees and wraps around. This is
+    /// [`Colour::from_hsv`] with the value flipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// for hue in [0.0, 45.0, 120.0, 200.0, 359.0] {
+    ///     assert_eq!(Colour::WHITE, Colour::from_sv_plane(hue, 0.0, 0.0));
+    ///     assert_eq!(Colour::hue_slider(hue / 360.0), Colour::from_sv_plane(hue, 1.0, 0.0));
+    ///     assert_eq!(Colour::BLACK, Colour::from_sv_plane(hue, 0.0, 1.0));
+    ///     assert_eq!(Colour::BLACK, Colour::from_sv_plane(hue, 1.0, 1.0));
+    /// }
+    /// assert_eq!(Colour::from_rgb(255, 0, 0), Colour::from_sv_plane(0.0, 1.0, 0.0));
+    /// assert_eq!(Colour::from_rgb(64, 32, 32), Colour::from_sv_plane(0.0, 0.5, 0.75));
+    /// assert_eq!(Colour::WHITE, Colour::from_sv_plane(120.0, -1.0, -1.0));
+    /// ```
+    pub fn from_sv_plane(hue: f32, x: f32, y: f32) -> Self {
+        Self::from_hsv(hue, x.clamp(0.0, 1.0), 1.0 - y.clamp(0.0, 1.0))
+    }
+
+    /// Get the position of the colour on the saturation and value plane of a
+    /// colour picker, as `(hue, x, y)`
+    ///
+    /// This is the inverse of [`Colour::from_sv_plane`], and is the same as
+    /// [`Colour::to_hsv`] with the value flipped. Greys have no hue, so
+    /// `0.0` is returned for them, and black has no saturation, so `x` is
+    /// `0.0` for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!((0.0, 1.0, 0.0), Colour::from_rgb(255, 0, 0).to_sv_plane());
+    /// assert_eq!((0.0, 0.0, 0.0), Colour::WHITE.to_sv_plane());
+    /// assert_eq!((0.0, 0.0, 1.0), Colour::BLACK.to_sv_plane());
+    ///
+    /// let (hue, x, y) = Colour::BLUE.to_sv_plane();
+    /// assert_eq!(Colour::BLUE, Colour::from_sv_plane(hue, x, y));
+    /// ```
+    pub fn to_sv_plane(self) -> (f32, f32, f32) {
+        let hsv = self.to_hsv();
+        (hsv.hue, hsv.saturation, 1.0 - hsv.value)
+    }
+
+    /// Get the colour at a position along the hue slider of a colour picker
+    ///
+    /// `t` goes from `0.0` to `1.0` around the colour wheel, starting and
+    /// ending at red, and is clamped to that range. The colours are fully
+    /// saturated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(255, 0, 0), Colour::hue_slider(0.0));
+    /// assert_eq!(Colour::from_rgb(0, 255, 0), Colour::hue_slider(1.0 / 3.0));
+    /// assert_eq!(Colour::from_rgb(0, 0, 255), Colour::hue_slider(2.0 / 3.0));
+    /// assert_eq!(Colour::from_rgb(255, 0, 0), Colour::hue_slider(1.0));
+    /// assert_eq!(Colour::from_rgb(255, 0, 0), Colour::hue_slider(2.0));
+    /// ```
+    pub fn hue_slider(t: f32) -> Self {
+        Self::from_sv_plane(t.clamp(0.0, 1.0) * 360.0, 1.0, 0.0)
+    }
+}
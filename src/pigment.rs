@@ -0,0 +1,143 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::Colour;
+
+/// The number of wavelength bands in a reflectance spectrum, evenly spaced
+/// from 380 nm to 720 nm
+const BANDS: usize = 10;
+
+type Spectrum = [f32; BANDS];
+
+// The reflectance spectra of Smits' "An RGB to Spectrum Conversion for
+// Reflectances" (1999), clamped to 1
+const WHITE: Spectrum = [1.0; BANDS];
+const CYAN: Spectrum = [0.9710, 0.9426, 1.0, 1.0, 1.0, 1.0, 0.1564, 0.0, 0.0, 0.0];
+const MAGENTA: Spectrum = [
+    1.0, 1.0, 0.9685, 0.2229, 0.0, 0.0458, 0.8369, 1.0, 1.0, 0.9959,
+];
+const YELLOW: Spectrum = [
+    0.0001, 0.0, 0.1088, 0.6651, 1.0, 1.0, 0.9996, 0.9586, 0.9685, 0.9840,
+];
+const RED: Spectrum = [0.1012, 0.0515, 0.0, 0.0, 0.0, 0.0, 0.8325, 1.0, 1.0, 1.0];
+const GREEN: Spectrum = [
+    0.0, 0.0, 0.0273, 0.7937, 1.0, 0.9418, 0.1719, 0.0, 0.0, 0.0025,
+];
+const BLUE: Spectrum = [
+    1.0, 1.0, 0.8916, 0.3323, 0.0, 0.0, 0.0003, 0.0369, 0.0483, 0.0496,
+];
+
+/// The linear light RGB of each band of a spectrum
+///
+/// This is the CIE 1931 observer under an equal energy illuminant, adjusted
+/// as little as possible so the spectra above give back exactly the colours
+/// they were made from.
+const TO_RGB: [Spectrum; 3] = [
+    [
+        0.095096, -0.011530, -0.053896, -0.162446, -0.206191, 0.243362, 0.624683, 0.392341,
+        0.072774, 0.005806,
+    ],
+    [
+        0.006078, -0.085786, 0.013919, 0.209783, 0.503012, 0.334931, 0.085134, -0.068771,
+        -0.010785, 0.012485,
+    ],
+    [
+        0.034156, 0.460244, 0.529834, 0.100873, -0.033746, -0.060439, -0.022463, -0.008292,
+        -0.000771, 0.000604,
+    ],
+];
+
+/// The reflectance every pigment is treated as having at the least, so that
+/// no pigment overwhelms a mix
+const BASE_REFLECTANCE: f32 = 0.1;
+
+/// Get a smooth reflectance spectrum with the colour, using Smits' method
+fn spectrum(colour: Colour) -> Spectrum {
+    let [red, green, blue] = colour.to_linear();
+    // Everything is made of white and the two spectra between the smallest
+    // channel and the largest
+    let (white, [(a, a_amount), (b, b_amount)]) = if red <= green && red <= blue {
+        if green <= blue {
+            (red, [(CYAN, green - red), (BLUE, blue - green)])
+        } else {
+            (red, [(CYAN, blue - red), (GREEN, green - blue)])
+        }
+    } else if green <= red && green <= blue {
+        if red <= blue {
+            (green, [(MAGENTA, red - green), (BLUE, blue - red)])
+        } else {
+            (green, [(MAGENTA, blue - green), (RED, red - blue)])
+        }
+    } else if red <= green {
+        (blue, [(YELLOW, red - blue), (GREEN, green - red)])
+    } else {
+        (blue, [(YELLOW, green - blue), (RED, red - green)])
+    };
+    let mut spectrum = [0.0; BANDS];
+    for (i, band) in spectrum.iter_mut().enumerate() {
+        *band = WHITE[i] * white + a[i] * a_amount + b[i] * b_amount;
+    }
+    spectrum
+}
+
+/// Get the Kubelka-Munk ratio of absorption to scattering of a pigment with a
+/// reflectance
+fn absorption(reflectance: f32) -> f32 {
+    let reflectance = BASE_REFLECTANCE + (1.0 - BASE_REFLECTANCE) * reflectance;
+    (1.0 - reflectance).powi(2) / (2.0 * reflectance)
+}
+
+/// Get the reflectance of a pigment from its Kubelka-Munk ratio of absorption
+/// to scattering, the inverse of [`absorption`]
+fn reflectance(absorption: f32) -> f32 {
+    // `1 + k - sqrt(k^2 + 2k)`, rearranged so it doesn't lose precision when
+    // `k` is large
+    let reflectance = 1.0 / (1.0 + absorption + (absorption * (absorption + 2.0)).sqrt());
+    (reflectance - BASE_REFLECTANCE) / (1.0 - BASE_REFLECTANCE)
+}
+
+impl Colour {
+    /// Mix two colours like paints
+    ///
+    /// `t` is the proportion of `other` in the result, `0.0` giving `self` and
+    /// `1.0` giving `other`. It is clamped to the range `0.0..=1.0`.
+    ///
+    /// [`Colour::mix`] mixes light, so blue and yellow give grey. This mixes
+    /// the colours as pigments which absorb light, so blue and yellow give
+    /// green, and mixes darken. Each colour is turned into a smooth
+    /// reflectance spectrum, the spectra are mixed with the Kubelka-Munk
+    /// model, and the result is turned back into a colour.
+    ///
+    /// This is a simple model and not a simulation of real paint. Colours are
+    /// treated as paints of equal strength, which they aren't: real
+    /// pigments with the same colour can mix differently. A saturated blue
+    /// like `#0000ff` absorbs most of the green a yellow reflects, so it mixes
+    /// with yellow to a dark teal rather than a green.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let green = Colour::BLUE.mix_subtractive(Colour::YELLOW, 0.5);
+    /// assert!((90.0..=170.0).contains(&green.hue()));
+    /// assert!(green.saturation() > Colour::BLUE.mix(Colour::YELLOW, 0.5).saturation());
+    ///
+    /// assert_eq!(Colour::RED, Colour::RED.mix_subtractive(Colour::BLUE, 0.0));
+    /// assert_eq!(Colour::BLUE, Colour::RED.mix_subtractive(Colour::BLUE, 1.0));
+    /// ```
+    pub fn mix_subtractive(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let (a, b) = (spectrum(self), spectrum(other));
+        let mut mixed = [0.0; BANDS];
+        for (i, band) in mixed.iter_mut().enumerate() {
+            *band = reflectance(absorption(a[i]) * (1.0 - t) + absorption(b[i]) * t);
+        }
+        Self::from_linear(TO_RGB.map(|weights| {
+            weights
+                .iter()
+                .zip(mixed)
+                .map(|(weight, band)| weight * band)
+                .sum()
+        }))
+    }
+}
@@ -0,0 +1,182 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::Colour;
+
+/// Convert an sRGB encoded channel in the range `0.0..=1.0` to linear light
+fn decode_channel(value: f64) -> f64 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert a linear light channel in the range `0.0..=1.0` to sRGB encoding
+fn encode_channel(value: f64) -> f64 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn transform(matrix: [[f64; 3]; 3], vector: [f64; 3]) -> [f64; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+/// The matrix from linear light sRGB to XYZ, exactly as defined by CSS Color 4
+const LINEAR_TO_XYZ: [[f64; 3]; 3] = [
+    [
+        506_752.0 / 1_228_815.0,
+        87_881.0 / 245_763.0,
+        12_673.0 / 70_218.0,
+    ],
+    [
+        87_098.0 / 409_605.0,
+        175_762.0 / 245_763.0,
+        12_673.0 / 175_545.0,
+    ],
+    [
+        7_918.0 / 409_605.0,
+        87_881.0 / 737_289.0,
+        1_001_167.0 / 1_053_270.0,
+    ],
+];
+
+/// The matrix from XYZ to linear light sRGB, exactly as defined by CSS Color 4
+pub(crate) const XYZ_TO_LINEAR: [[f64; 3]; 3] = [
+    [12_831.0 / 3_959.0, -329.0 / 214.0, -1_974.0 / 3_959.0],
+    [
+        -851_781.0 / 878_810.0,
+        1_648_619.0 / 878_810.0,
+        36_519.0 / 878_810.0,
+    ],
+    [705.0 / 12_673.0, -2_585.0 / 12_673.0, 705.0 / 667.0],
+];
+
+/// Convert linear light sRGB to XYZ
+pub(crate) fn linear_to_xyz(rgb: [f64; 3]) -> [f64; 3] {
+    transform(LINEAR_TO_XYZ, rgb)
+}
+
+/// Convert XYZ to linear light sRGB, which may be out of gamut
+pub(crate) fn xyz_to_linear(xyz: [f64; 3]) -> [f64; 3] {
+    transform(XYZ_TO_LINEAR, xyz)
+}
+
+impl Colour {
+    /// Get the sRGB encoded components of the colour in the range `0.0..=1.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!([0.2, 0.6, 1.0], Colour::from_rgb(51, 153, 255).to_rgb_f64());
+    /// ```
+    pub fn to_rgb_f64(self) -> [f64; 3] {
+        [self.red(), self.green(), self.blue()].map(|c| f64::from(c) / 255.0)
+    }
+
+    /// Make a new colour using sRGB encoded components in the range `0.0..=1.0`
+    ///
+    /// Components are clamped to the range and rounded to the nearest value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(51, 153, 255), Colour::from_rgb_f64([0.2, 0.6, 1.5]));
+    /// ```
+    pub fn from_rgb_f64(rgb: [f64; 3]) -> Self {
+        let [red, green, blue] = rgb.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+        Self::from_rgb(red, green, blue)
+    }
+
+    /// Get the linear light RGB components of the colour, see
+    /// [`Colour::to_linear`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let [red, _, _] = Colour::from_rgb(255, 0, 0).to_linear_f64();
+    /// assert_eq!(1.0, red);
+    /// let [grey, _, _] = Colour::from_rgb(51, 51, 51).to_linear_f64();
+    /// assert!((grey - 0.033_104_766_570_885_055).abs() < 1e-15);
+    /// ```
+    pub fn to_linear_f64(self) -> [f64; 3] {
+        self.to_rgb_f64().map(decode_channel)
+    }
+
+    /// Make a new colour using linear light RGB components, see
+    /// [`Colour::from_linear`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let blue = Colour::BLUE;
+    /// assert_eq!(blue, Colour::from_linear_f64(blue.to_linear_f64()));
+    /// ```
+    pub fn from_linear_f64(rgb: [f64; 3]) -> Self {
+        Self::from_rgb_f64(rgb.map(|c| encode_channel(c.clamp(0.0, 1.0))))
+    }
+
+    /// Get the CIE XYZ components of the colour, see [`Colour::to_xyz`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// // The D65 white point as defined by CSS Color 4
+    /// let [x, y, z] = Colour::WHITE.to_xyz_f64();
+    /// assert!((x - 0.3127 / 0.3290).abs() < 1e-12);
+    /// assert!((y - 1.0).abs() < 1e-12);
+    /// assert!((z - (1.0 - 0.3127 - 0.3290) / 0.3290).abs() < 1e-12);
+    ///
+    /// let [x, y, z] = Colour::from_rgb(255, 0, 0).to_xyz_f64();
+    /// assert!((x - 0.412_390_799_265_959_5).abs() < 1e-12);
+    /// assert!((y - 0.212_639_005_871_510_36).abs() < 1e-12);
+    /// assert!((z - 0.019_330_818_715_591_85).abs() < 1e-12);
+    /// ```
+    pub fn to_xyz_f64(self) -> [f64; 3] {
+        linear_to_xyz(self.to_linear_f64())
+    }
+
+    /// Make a new colour using CIE XYZ components, see [`Colour::from_xyz`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let blue = Colour::BLUE;
+    /// assert_eq!(blue, Colour::from_xyz_f64(blue.to_xyz_f64()));
+    /// ```
+    pub fn from_xyz_f64(xyz: [f64; 3]) -> Self {
+        Self::from_linear_f64(xyz_to_linear(xyz))
+    }
+
+    /// Get the relative luminance of the colour, see
+    /// [`Colour::relative_luminance`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(1.0, Colour::WHITE.relative_luminance_f64());
+    /// assert_eq!(0.0722, Colour::from_rgb(0, 0, 255).relative_luminance_f64());
+    /// let grey = Colour::from_rgb(128, 128, 128).relative_luminance_f64();
+    /// assert!((grey - 0.215_860_500_113_899_23).abs() < 1e-12);
+    /// ```
+    pub fn relative_luminance_f64(self) -> f64 {
+        let [red, green, blue] = self.to_linear_f64();
+        0.2126 * red + 0.7152 * green + 0.0722 * blue
+    }
+}
@@ -0,0 +1,10 @@
+//! Commonly used items
+//!
+//! ```
+//! use chrom::prelude::*;
+//!
+//! let blue = Colour::BLUE;
+//! assert_eq!(blue, Srgb(blue).decode().encode().into());
+//! ```
+
+pub use crate::{Color, Colour, ColourLike, LinearRgb, Rgba, Srgb};
@@ -0,0 +1,312 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{codec, Colour, ColourStop, Gradient};
+use alloc::vec::Vec;
+use core::{error, fmt};
+
+/// An error returned when a buffer is the wrong length for an image, by
+/// [`Gradient::fill_linear`] and the other fills
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BufferSizeError {
+    pub width: usize,
+    pub height: usize,
+    /// The length of the buffer, in the units of the buffer
+    pub length: usize,
+}
+
+impl fmt::Display for BufferSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "buffer of length {} doesn't fit a {}x{} image",
+            self.length, self.width, self.height
+        )
+    }
+}
+
+impl error::Error for BufferSizeError {}
+
+/// Check a buffer has `stride` elements for each pixel of an image
+fn check_size(
+    width: usize,
+    height: usize,
+    length: usize,
+    stride: usize,
+) -> Result<(), BufferSizeError> {
+    match width
+        .checked_mul(height)
+        .and_then(|n| n.checked_mul(stride))
+    {
+        Some(needed) if needed == length => Ok(()),
+        _ => Err(BufferSizeError {
+            width,
+            height,
+            length,
+        }),
+    }
+}
+
+/// Get the position along a linear gradient of the point `(x, y)`, see
+/// [`Gradient::fill_linear`]
+fn linear_position(width: usize, height: usize, angle_degrees: f32) -> impl Fn(f32, f32) -> f32 {
+    let (sin, cos) = angle_degrees.to_radians().sin_cos();
+    let (width, height) = (width as f32, height as f32);
+    let length = (width * sin).abs() + (height * cos).abs();
+    move |x, y| ((x - width / 2.0) * sin - (y - height / 2.0) * cos) / length + 0.5
+}
+
+/// Get the position along a radial gradient of the point `(x, y)`, see
+/// [`Gradient::fill_radial`]
+fn radial_position(cx: f32, cy: f32, radius: f32) -> impl Fn(f32, f32) -> f32 {
+    move |x, y| {
+        if radius > 0.0 {
+            (x - cx).hypot(y - cy) / radius
+        } else {
+            1.0
+        }
+    }
+}
+
+/// The part of a gradient between two neighbouring stops, with the channels
+/// of its colours ready to mix
+struct Segment {
+    start: f32,
+    end: f32,
+    from: [f32; 3],
+    delta: [f32; 3],
+}
+
+impl Segment {
+    fn new(start: ColourStop, end: ColourStop) -> Self {
+        let channels =
+            |colour: Colour| [colour.red(), colour.green(), colour.blue()].map(f32::from);
+        let (from, to) = (channels(start.colour), channels(end.colour));
+        Self {
+            start: start.position,
+            end: end.position,
+            from,
+            delta: [0, 1, 2].map(|i| to[i] - from[i]),
+        }
+    }
+
+    /// Get the colour at `t`, exactly like [`Colour::mix`]
+    fn at(&self, t: f32) -> Colour {
+        let weight = ((t - self.start) / (self.end - self.start)).clamp(0.0, 1.0);
+        let [red, green, blue] =
+            [0, 1, 2].map(|i| (self.from[i] + self.delta[i] * weight).round() as u8);
+        Colour::from_rgb(red, green, blue)
+    }
+}
+
+impl<S: AsRef<[ColourStop]>> Gradient<S> {
+    /// Set each pixel of a row-major image from the gradient at the position
+    /// `position` gives for the centre of the pixel
+    ///
+    /// The segments between stops are worked out once, and every pixel gets
+    /// exactly the colour [`Gradient::at`] would give.
+    fn fill<'a, P: ?Sized + 'a>(
+        &self,
+        width: usize,
+        pixels: impl Iterator<Item = &'a mut P>,
+        position: impl Fn(f32, f32) -> f32,
+        set: impl Fn(&mut P, Colour),
+    ) {
+        let stops = self.stops();
+        let (first, last) = (stops[0], stops[stops.len() - 1]);
+        let segments: Vec<_> = stops
+            .windows(2)
+            .map(|pair| Segment::new(pair[0], pair[1]))
+            .collect();
+        let at = |t: f32| {
+            let t = t.clamp(0.0, 1.0);
+            if t.is_nan() {
+                return last.colour;
+            }
+            if t <= first.position {
+                return first.colour;
+            }
+            // The first segment ending at or after `t`
+            match segments.get(segments.partition_point(|segment| segment.end < t)) {
+                Some(segment) => segment.at(t),
+                None => last.colour,
+            }
+        };
+
+        // Neighbouring pixels often have the same position, like every pixel
+        // in a row of a vertical gradient
+        let mut previous = None;
+        for (i, pixel) in pixels.enumerate() {
+            let (x, y) = ((i % width) as f32 + 0.5, (i / width) as f32 + 0.5);
+            let t = position(x, y);
+            let colour = match previous {
+                Some((previous_t, colour)) if previous_t == t => colour,
+                _ => at(t),
+            };
+            previous = Some((t, colour));
+            set(pixel, colour);
+        }
+    }
+
+    /// Fill a row-major image with the gradient along a line at an angle
+    ///
+    /// Angles are in degrees clockwise from the top, like CSS
+    /// `linear-gradient`: `0.0` goes from the bottom up and `90.0` from left
+    /// to right. Also like CSS, the gradient line goes through the centre of
+    /// the image and is long enough that the corners are at its ends.
+    ///
+    /// The pixel in column `x` and row `y` is at `buffer[y * width + x]`, and
+    /// has the colour at the position of its centre `(x + 0.5, y + 0.5)`. An
+    /// error is returned if the buffer isn't `width * height` long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Gradient};
+    ///
+    /// let gradient = Gradient::new(Colour::BLACK, Colour::WHITE);
+    /// let mut buffer = [Colour::RED; 4 * 2];
+    /// gradient.fill_linear(&mut buffer, 4, 2, 90.0).unwrap();
+    /// assert_eq!(gradient.at(0.125), buffer[0]);
+    /// assert_eq!(gradient.at(0.875), buffer[3]);
+    /// assert_eq!(buffer[..4], buffer[4..]);
+    ///
+    /// assert!(gradient.fill_linear(&mut buffer, 3, 2, 90.0).is_err());
+    /// ```
+    pub fn fill_linear(
+        &self,
+        buffer: &mut [Colour],
+        width: usize,
+        height: usize,
+        angle_degrees: f32,
+    ) -> Result<(), BufferSizeError> {
+        check_size(width, height, buffer.len(), 1)?;
+        let position = linear_position(width, height, angle_degrees);
+        self.fill(width, buffer.iter_mut(), position, |pixel, colour| {
+            *pixel = colour
+        });
+        Ok(())
+    }
+
+    /// Fill a row-major image with the gradient in circles around a centre
+    ///
+    /// `(cx, cy)` is the centre and `radius` the distance to the end of the
+    /// gradient, both in pixels from the top left corner of the image. Past the
+    /// radius is the colour at the end. A radius which isn't positive fills the
+    /// image with the colour at the end.
+    ///
+    /// See [`Gradient::fill_linear`] for the layout of the buffer and where
+    /// pixels are sampled. An error is returned if the buffer isn't
+    /// `width * height` long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Gradient};
+    ///
+    /// let gradient = Gradient::new(Colour::WHITE, Colour::BLACK);
+    /// let mut buffer = [Colour::RED; 4 * 4];
+    /// gradient.fill_radial(&mut buffer, 4, 4, 2.0, 2.0, 2.0).unwrap();
+    /// assert_eq!(gradient.at(0.5f32.hypot(0.5) / 2.0), buffer[5]);
+    /// assert_eq!(buffer[5], buffer[10]);
+    /// assert_eq!(gradient.at(1.5f32.hypot(1.5) / 2.0), buffer[0]);
+    /// ```
+    pub fn fill_radial(
+        &self,
+        buffer: &mut [Colour],
+        width: usize,
+        height: usize,
+        cx: f32,
+        cy: f32,
+        radius: f32,
+    ) -> Result<(), BufferSizeError> {
+        check_size(width, height, buffer.len(), 1)?;
+        let position = radial_position(cx, cy, radius);
+        self.fill(width, buffer.iter_mut(), position, |pixel, colour| {
+            *pixel = colour
+        });
+        Ok(())
+    }
+
+    /// Fill a row-major image of 3 bytes a pixel, in RGB order, with the
+    /// gradient along a line at an angle
+    ///
+    /// This is [`Gradient::fill_linear`] written like
+    /// [`codec::encode_rgb24`](crate::codec::encode_rgb24), without making
+    /// the colours first. An error is returned if the buffer isn't
+    /// `width * height * 3` long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{codec, Colour, Gradient};
+    ///
+    /// let gradient = Gradient::new(Colour::BLACK, Colour::WHITE);
+    /// let mut colours = [Colour::BLACK; 6 * 4];
+    /// gradient.fill_linear(&mut colours, 6, 4, 30.0).unwrap();
+    /// let mut bytes = [0; 6 * 4 * 3];
+    /// gradient.fill_linear_rgb24(&mut bytes, 6, 4, 30.0).unwrap();
+    /// assert_eq!(codec::encode_rgb24(&colours), bytes);
+    /// ```
+    pub fn fill_linear_rgb24(
+        &self,
+        buffer: &mut [u8],
+        width: usize,
+        height: usize,
+        angle_degrees: f32,
+    ) -> Result<(), BufferSizeError> {
+        check_size(width, height, buffer.len(), 3)?;
+        let position = linear_position(width, height, angle_degrees);
+        self.fill(
+            width,
+            buffer.chunks_exact_mut(3),
+            position,
+            |pixel, colour| pixel.copy_from_slice(&codec::to_rgb24(colour)),
+        );
+        Ok(())
+    }
+
+    /// Fill a row-major image of 3 bytes a pixel, in RGB order, with the
+    /// gradient in circles around a centre
+    ///
+    /// This is [`Gradient::fill_radial`] written like
+    /// [`codec::encode_rgb24`](crate::codec::encode_rgb24), without making
+    /// the colours first. An error is returned if the buffer isn't
+    /// `width * height * 3` long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{codec, BufferSizeError, Colour, Gradient};
+    ///
+    /// let gradient = Gradient::new(Colour::WHITE, Colour::BLUE);
+    /// let mut colours = [Colour::BLACK; 5 * 3];
+    /// gradient.fill_radial(&mut colours, 5, 3, 1.0, 1.0, 4.0).unwrap();
+    /// let mut bytes = [0; 5 * 3 * 3];
+    /// gradient.fill_radial_rgb24(&mut bytes, 5, 3, 1.0, 1.0, 4.0).unwrap();
+    /// assert_eq!(codec::encode_rgb24(&colours), bytes);
+    ///
+    /// assert_eq!(
+    ///     Err(BufferSizeError { width: 5, height: 4, length: 45 }),
+    ///     gradient.fill_radial_rgb24(&mut bytes, 5, 4, 1.0, 1.0, 4.0),
+    /// );
+    /// ```
+    pub fn fill_radial_rgb24(
+        &self,
+        buffer: &mut [u8],
+        width: usize,
+        height: usize,
+        cx: f32,
+        cy: f32,
+        radius: f32,
+    ) -> Result<(), BufferSizeError> {
+        check_size(width, height, buffer.len(), 3)?;
+        let position = radial_position(cx, cy, radius);
+        self.fill(
+            width,
+            buffer.chunks_exact_mut(3),
+            position,
+            |pixel, colour| pixel.copy_from_slice(&codec::to_rgb24(colour)),
+        );
+        Ok(())
+    }
+}
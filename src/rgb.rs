@@ -0,0 +1,70 @@
+use crate::{Colour, Rgba};
+use ::rgb::{RGB8, RGBA8};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+impl From<RGB8> for Colour {
+    fn from(pixel: RGB8) -> Self {
+        Self::from_rgb(pixel.r, pixel.g, pixel.b)
+    }
+}
+
+impl From<Colour> for RGB8 {
+    fn from(colour: Colour) -> Self {
+        Self::new(colour.red(), colour.green(), colour.blue())
+    }
+}
+
+impl From<RGBA8> for Rgba {
+    fn from(pixel: RGBA8) -> Self {
+        Self::from_rgba(pixel.r, pixel.g, pixel.b, pixel.a)
+    }
+}
+
+impl From<Rgba> for RGBA8 {
+    fn from(rgba: Rgba) -> Self {
+        let colour = rgba.colour;
+        Self::new(colour.red(), colour.green(), colour.blue(), rgba.alpha)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Colour {
+    /// Convert pixels from the `rgb` crate into colours
+    ///
+    /// Colours are packed into a `u32`, so this copies rather than casting the
+    /// slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    /// use rgb::RGB8;
+    ///
+    /// let pixels = [RGB8::new(52, 152, 219), RGB8::new(0, 0, 0)];
+    /// assert_eq!(vec![Colour::BLUE, Colour::BLACK], Colour::from_rgb8_slice(&pixels));
+    /// assert_eq!(pixels[..], Colour::to_rgb8_vec(&Colour::from_rgb8_slice(&pixels)));
+    /// ```
+    pub fn from_rgb8_slice(pixels: &[RGB8]) -> Vec<Self> {
+        pixels.iter().copied().map(Self::from).collect()
+    }
+
+    /// Convert colours into pixels from the `rgb` crate
+    ///
+    /// Colours are packed into a `u32`, so this copies rather than casting the
+    /// slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    /// use rgb::RGB8;
+    ///
+    /// let colours = [Colour::GOLD, Colour::WHITE];
+    /// assert_eq!(vec![RGB8::new(241, 196, 15), RGB8::new(255, 255, 255)], Colour::to_rgb8_vec(&colours));
+    /// assert_eq!(colours[..], Colour::from_rgb8_slice(&Colour::to_rgb8_vec(&colours)));
+    /// ```
+    pub fn to_rgb8_vec(colours: &[Self]) -> Vec<RGB8> {
+        colours.iter().copied().map(RGB8::from).collect()
+    }
+}
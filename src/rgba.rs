@@ -0,0 +1,232 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{Colour, ColourOps};
+use core::fmt;
+
+/// A colour with an alpha channel
+///
+/// [`Colour`] is always opaque and always fits in 24 bits, so transparency is
+/// kept beside it instead of in the top byte. The alpha is straight, not
+/// premultiplied: `0` is fully transparent and `255` is opaque, and the colour
+/// is the same whatever the alpha.
+///
+/// Colours display and parse as `#rrggbbaa`, and with the `serde` feature
+/// serialise as that string.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, Rgba};
+///
+/// let overlay = Colour::BLACK.with_alpha(0x80);
+/// assert_eq!("#00000080", overlay.to_string());
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(Ok(overlay), "#00000080".parse());
+/// assert_eq!(Colour::new(0x1a4c6d), overlay.flatten(Colour::BLUE));
+/// assert_eq!(Rgba::new(Colour::BLUE, 0xff), Colour::BLUE.into());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Rgba {
+    pub colour: Colour,
+    pub alpha: u8,
+}
+
+impl Rgba {
+    /// Fully transparent black, the same as `Rgba::default()` and CSS
+    /// `transparent`
+    pub const TRANSPARENT: Self = Self::new(Colour::BLACK, 0);
+
+    /// Make a new colour with an alpha
+    pub const fn new(colour: Colour, alpha: u8) -> Self {
+        Self { colour, alpha }
+    }
+
+    /// Make a new colour with an alpha using RGBA values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Rgba};
+    ///
+    /// assert_eq!(Colour::BLUE.with_alpha(0x80), Rgba::from_rgba(52, 152, 219, 128));
+    /// ```
+    pub const fn from_rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self::new(Colour::from_rgb(red, green, blue), alpha)
+    }
+
+    /// Make a new colour with an alpha using a `0xrrggbbaa` value, in the same
+    /// order as a CSS hex colour
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Rgba};
+    ///
+    /// assert_eq!(Colour::BLUE.with_alpha(0x80), Rgba::from_u32(0x3498db80));
+    /// ```
+    pub const fn from_u32(value: u32) -> Self {
+        Self::new(Colour::new(value >> 8), value as u8)
+    }
+
+    /// Get the colour as a `0xrrggbbaa` value, see [`Rgba::from_u32`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(0x3498db80, Colour::BLUE.with_alpha(0x80).to_u32());
+    /// ```
+    pub const fn to_u32(self) -> u32 {
+        self.colour.0 << 8 | self.alpha as u32
+    }
+
+    /// Check if the colour is fully opaque
+    pub const fn is_opaque(self) -> bool {
+        self.alpha == u8::MAX
+    }
+
+    /// Check if the colour is fully transparent
+    pub const fn is_transparent(self) -> bool {
+        self.alpha == 0
+    }
+
+    /// Composite the colour over a background, like painting it on top
+    ///
+    /// This is Porter-Duff source-over with straight alpha in sRGB, the same
+    /// as browsers composite CSS colours. The result is as opaque as both
+    /// colours together, so over an opaque background it is opaque, and a
+    /// fully transparent result is [`Rgba::TRANSPARENT`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Rgba};
+    ///
+    /// let glass = Colour::WHITE.with_alpha(0x80);
+    /// assert_eq!(Rgba::from_u32(0x9accedff), glass.over(Colour::BLUE.into()));
+    /// assert_eq!(Rgba::from_u32(0xffffffc0), glass.over(glass));
+    ///
+    /// assert_eq!(Rgba::from(Colour::BLUE), Rgba::TRANSPARENT.over(Colour::BLUE.into()));
+    /// assert_eq!(glass, glass.over(Rgba::TRANSPARENT));
+    /// assert_eq!(Rgba::TRANSPARENT, Rgba::TRANSPARENT.over(Colour::RED.with_alpha(0)));
+    /// ```
+    pub fn over(self, background: Self) -> Self {
+        let top = f32::from(self.alpha) / 255.0;
+        let bottom = f32::from(background.alpha) / 255.0 * (1.0 - top);
+        let alpha = top + bottom;
+        if alpha == 0.0 {
+            return Self::TRANSPARENT;
+        }
+
+        let channel =
+            |a: u8, b: u8| ((f32::from(a) * top + f32::from(b) * bottom) / alpha).round() as u8;
+        let (a, b) = (self.colour, background.colour);
+        Self::from_rgba(
+            channel(a.red(), b.red()),
+            channel(a.green(), b.green()),
+            channel(a.blue(), b.blue()),
+            (alpha * 255.0).round() as u8,
+        )
+    }
+
+    /// Composite the colour over an opaque background, see [`Rgba::over`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let glass = Colour::WHITE.with_alpha(0x80);
+    /// assert_eq!(Colour::new(0x9acced), glass.flatten(Colour::BLUE));
+    /// assert_eq!(Colour::RED, Colour::RED.with_alpha(0xff).flatten(Colour::BLUE));
+    /// assert_eq!(Colour::BLUE, Colour::RED.with_alpha(0).flatten(Colour::BLUE));
+    /// ```
+    pub fn flatten(self, background: Colour) -> Colour {
+        self.over(background.into()).colour
+    }
+}
+
+impl Colour {
+    /// Get the colour with an alpha
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Rgba};
+    ///
+    /// assert_eq!(Rgba { colour: Colour::BLUE, alpha: 0x80 }, Colour::BLUE.with_alpha(0x80));
+    /// ```
+    pub const fn with_alpha(self, alpha: u8) -> Rgba {
+        Rgba::new(self, alpha)
+    }
+}
+
+impl From<Colour> for Rgba {
+    fn from(colour: Colour) -> Self {
+        colour.with_alpha(u8::MAX)
+    }
+}
+
+impl From<[u8; 4]> for Rgba {
+    fn from([red, green, blue, alpha]: [u8; 4]) -> Self {
+        Self::from_rgba(red, green, blue, alpha)
+    }
+}
+
+impl From<Rgba> for [u8; 4] {
+    fn from(rgba: Rgba) -> Self {
+        let colour = rgba.colour;
+        [colour.red(), colour.green(), colour.blue(), rgba.alpha]
+    }
+}
+
+/// Format as a CSS hex colour with alpha, `#rrggbbaa`
+impl fmt::Display for Rgba {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{:02x}", self.colour, self.alpha)
+    }
+}
+
+/// Interpolation is premultiplied like CSS, so a transparent colour adds no
+/// colour to a mix
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ColourOps, Rgba};
+///
+/// let fade = Colour::BLUE.with_alpha(0xff).lerp(Rgba::TRANSPARENT, 0.5);
+/// assert_eq!(Colour::BLUE.with_alpha(0x80), fade);
+/// ```
+impl ColourOps for Rgba {
+    fn map_channels(self, f: impl FnMut(f32) -> f32) -> Self {
+        Self::new(self.colour.map_channels(f), self.alpha)
+    }
+
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let (a, b) = (f32::from(self.alpha), f32::from(other.alpha));
+        let alpha = a + (b - a) * t;
+        if alpha == 0.0 {
+            return Self::new(self.colour.mix(other.colour, t), 0);
+        }
+
+        let weight = b * t / alpha;
+        Self::new(self.colour.mix(other.colour, weight), alpha.round() as u8)
+    }
+
+    fn to_linear_rgba(self) -> [f32; 4] {
+        let [red, green, blue, _] = self.colour.to_linear_rgba();
+        [red, green, blue, f32::from(self.alpha) / 255.0]
+    }
+
+    fn from_linear_rgba(rgba: [f32; 4]) -> Self {
+        let alpha = (rgba[3].clamp(0.0, 1.0) * 255.0).round() as u8;
+        Self::new(Colour::from_linear_rgba(rgba), alpha)
+    }
+
+    fn luminance(self) -> f32 {
+        self.colour.relative_luminance()
+    }
+}
@@ -0,0 +1,147 @@
+use crate::Colour;
+
+/// A colour in a [`ColourScheme`], with its contrast against the background
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SchemeColour {
+    pub colour: Colour,
+    /// The contrast ratio with the background, see [`Colour::contrast_ratio`]
+    pub contrast: f32,
+}
+
+impl SchemeColour {
+    fn new(colour: Colour, background: Colour) -> Self {
+        Self {
+            colour,
+            contrast: colour.contrast_ratio(background),
+        }
+    }
+}
+
+/// Colours from a palette assigned to the roles they play in a design
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, ColourScheme};
+///
+/// // A dark, moody palette, most common colour first
+/// let scheme = ColourScheme::from_palette(&[
+///     Colour::new(0x1b1f2a),
+///     Colour::new(0x2e3440),
+///     Colour::new(0xd8dee9),
+///     Colour::new(0xbf616a),
+///     Colour::new(0x4c566a),
+/// ]);
+/// assert_eq!(Colour::new(0x1b1f2a), scheme.background);
+/// assert_eq!(Colour::new(0xd8dee9), scheme.foreground.colour);
+/// assert_eq!(Colour::new(0xbf616a), scheme.accent.colour);
+/// assert_eq!(Colour::new(0x2e3440), scheme.muted.colour);
+/// assert!(scheme.foreground.contrast >= 4.5);
+///
+/// // A pastel palette, where the foreground is darkened to have enough contrast
+/// let scheme = ColourScheme::from_palette(&[
+///     Colour::new(0xfdf6e3),
+///     Colour::new(0xf4c2c2),
+///     Colour::new(0xb5ead7),
+///     Colour::new(0xc7ceea),
+///     Colour::new(0xffdac1),
+/// ]);
+/// assert_eq!(Colour::new(0xfdf6e3), scheme.background);
+/// assert_eq!(Colour::new(0x8d6161), scheme.foreground.colour);
+/// assert_eq!(Colour::new(0xb5ead7), scheme.accent.colour);
+/// assert_eq!(Colour::new(0xc7ceea), scheme.muted.colour);
+/// assert!(scheme.foreground.contrast >= 4.5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColourScheme {
+    pub background: Colour,
+    /// The colour for text and icons, with a contrast of at least 4.5
+    pub foreground: SchemeColour,
+    /// The colour to draw attention with
+    pub accent: SchemeColour,
+    /// The colour for borders and other details
+    pub muted: SchemeColour,
+}
+
+impl ColourScheme {
+    /// Assign the colours of a palette to roles
+    ///
+    /// The colours should be sorted with the most common first, like the
+    /// dominant colours of an image. The background is the least saturated of
+    /// the most common third of the colours, and the foreground is whichever
+    /// other colour has the most contrast with it, adjusted with
+    /// [`Colour::ensure_contrast`] to have a contrast ratio of at least 4.5.
+    /// Of the rest, the most saturated is the accent and the least saturated
+    /// is muted.
+    ///
+    /// Roles without a colour of their own are filled in: the foreground is
+    /// [`Colour::on_colour`] of the background, the accent is the foreground,
+    /// and muted is halfway between the background and the foreground. An
+    /// empty palette has a white background.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, ColourScheme};
+    ///
+    /// let scheme = ColourScheme::from_palette(&[Colour::WHITE, Colour::BLUE]);
+    /// assert_eq!(Colour::WHITE, scheme.background);
+    /// assert!(scheme.foreground.contrast >= 4.5);
+    /// assert_eq!(scheme.foreground, scheme.accent);
+    ///
+    /// let scheme = ColourScheme::from_palette(&[]);
+    /// assert_eq!(Colour::WHITE, scheme.background);
+    /// assert!(scheme.foreground.contrast >= 4.5);
+    /// assert_eq!(Colour::WHITE.mix(scheme.foreground.colour, 0.5), scheme.muted.colour);
+    /// ```
+    pub fn from_palette(colours: &[Colour]) -> Self {
+        let chroma = |colour: Colour| colour.to_oklch()[1];
+
+        let candidates = colours.len().div_ceil(3);
+        let background = max_position(&colours[..candidates], &[], |colour| -chroma(colour));
+        let background_colour = background.map_or(Colour::WHITE, |i| colours[i]);
+        let foreground = max_position(colours, &[background], |colour| {
+            colour.contrast_ratio(background_colour)
+        });
+        let foreground_colour = foreground.map_or_else(
+            || background_colour.on_colour(),
+            |i| colours[i].ensure_contrast(background_colour, 4.5),
+        );
+        let accent = max_position(colours, &[background, foreground], chroma);
+        let muted = max_position(colours, &[background, foreground, accent], |colour| {
+            -chroma(colour)
+        });
+
+        Self {
+            background: background_colour,
+            foreground: SchemeColour::new(foreground_colour, background_colour),
+            accent: SchemeColour::new(
+                accent.map_or(foreground_colour, |i| colours[i]),
+                background_colour,
+            ),
+            muted: SchemeColour::new(
+                muted.map_or_else(
+                    || background_colour.mix(foreground_colour, 0.5),
+                    |i| colours[i],
+                ),
+                background_colour,
+            ),
+        }
+    }
+}
+
+/// Get the index of the colour with the largest key, other than the ones
+/// already taken, preferring earlier colours on ties
+fn max_position(
+    colours: &[Colour],
+    taken: &[Option<usize>],
+    key: impl Fn(Colour) -> f32,
+) -> Option<usize> {
+    colours
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !taken.contains(&Some(*i)))
+        .rev()
+        .max_by(|(_, &a), (_, &b)| key(a).total_cmp(&key(b)))
+        .map(|(i, _)| i)
+}
@@ -0,0 +1,97 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{oklab, Colour};
+use alloc::vec::Vec;
+use core::f32::consts::{FRAC_PI_2, PI};
+
+/// The Oklab lightness of the darkest colour of a palette
+const DARKEST: f32 = 0.28;
+/// The Oklab lightness of the lightest colour of a palette
+const LIGHTEST: f32 = 0.96;
+/// The Oklch chroma at the most colourful point of a palette, before it is
+/// reduced to fit in the sRGB gamut
+const PEAK_CHROMA: f32 = 0.16;
+
+/// Get `n` colours for showing ordered data, from dark to light
+///
+/// The colours have the Oklch hue `base_hue` in degrees, with Oklab lightness
+/// evenly spaced and strictly increasing, so they stay in order for people
+/// with any colour vision deficiency and when printed in greyscale. Chroma
+/// peaks in the middle, like the ColorBrewer sequential schemes, and is
+/// reduced wherever it doesn't fit in the sRGB gamut. Up to 12 colours,
+/// adjacent colours are at least 5 apart by [`Colour::delta_e`].
+///
+/// Reverse the palette to go from light to dark.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{sequential_palette, Colour};
+///
+/// let blues = sequential_palette(Colour::BLUE.to_oklch()[2], 5);
+/// assert_eq!(5, blues.len());
+/// assert!(blues.windows(2).all(|pair| pair[0].to_oklab()[0] < pair[1].to_oklab()[0]));
+/// assert!(blues.windows(2).all(|pair| pair[0].delta_e(pair[1]) > 5.0));
+///
+/// assert_eq!(1, sequential_palette(0.0, 1).len());
+/// assert!(sequential_palette(0.0, 0).is_empty());
+/// ```
+pub fn sequential_palette(base_hue: f32, n: usize) -> Vec<Colour> {
+    (0..n)
+        .map(|i| {
+            let t = position(i, n);
+            let chroma = PEAK_CHROMA * (0.25 + 0.75 * (PI * t).sin());
+            colour(t, chroma, base_hue)
+        })
+        .collect()
+}
+
+/// Get `n` colours for showing data either side of a midpoint, going from a
+/// dark colour with the hue `hue_a` through a light grey to a dark colour with
+/// the hue `hue_b`
+///
+/// Hues are Oklch hues in degrees. Each arm is like a [`sequential_palette`]:
+/// Oklab lightness strictly increases towards the middle from either end, and
+/// both arms have the same lightness at the same distance from the middle. An
+/// odd `n` has a grey in the middle, and an even `n` has the lightest colour
+/// of each arm. Up to 12 colours, adjacent colours are at least 5 apart by
+/// [`Colour::delta_e`] when the hues are at least 90° apart.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{diverging_palette, Colour};
+///
+/// let [red, blue] = [Colour::RED, Colour::BLUE].map(|colour| colour.to_oklch()[2]);
+/// let palette = diverging_palette(red, blue, 7);
+/// let lightness: Vec<_> = palette.iter().map(|colour| colour.to_oklab()[0]).collect();
+/// assert!(lightness[..4].windows(2).all(|pair| pair[0] < pair[1]));
+/// assert!(lightness[3..].windows(2).all(|pair| pair[0] > pair[1]));
+/// assert!(palette[3].to_oklch()[1] < 0.01);
+/// ```
+pub fn diverging_palette(hue_a: f32, hue_b: f32, n: usize) -> Vec<Colour> {
+    (0..n)
+        .map(|i| {
+            // From -1 at the first colour to 1 at the last
+            let s = 2.0 * position(i, n) - 1.0;
+            let chroma = PEAK_CHROMA * (FRAC_PI_2 * s.abs()).sin();
+            colour(1.0 - s.abs(), chroma, if s < 0.0 { hue_a } else { hue_b })
+        })
+        .collect()
+}
+
+/// Get how far the `i`th of `n` colours is from the first to the last, with a
+/// single colour in the middle
+fn position(i: usize, n: usize) -> f32 {
+    if n < 2 {
+        0.5
+    } else {
+        i as f32 / (n - 1) as f32
+    }
+}
+
+/// Get the colour `t` of the way from darkest to lightest
+fn colour(t: f32, chroma: f32, hue: f32) -> Colour {
+    let lightness = DARKEST + (LIGHTEST - DARKEST) * t;
+    oklab::from_oklch_in_gamut([lightness, chroma, hue])
+}
@@ -0,0 +1,624 @@
+//! Alternative serde representations for use with `#[serde(with = "...")]`
+//!
+//! Every module deserialises human readable formats from any of the
+//! representations, so switching between them doesn't break existing data.
+//!
+//! # Examples
+//!
+//! ```
+//! use chrom::Colour;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Theme {
+//!     #[serde(with = "chrom::serde::hex_string")]
+//!     accent: Colour,
+//!     #[serde(with = "chrom::serde::rgb_struct")]
+//!     background: Colour,
+//! }
+//!
+//! let theme = Theme { accent: Colour::BLUE, background: Colour::BLACK };
+//! assert_eq!(
+//!     r##"{"accent":"#3498db","background":{"r":0,"g":0,"b":0}}"##,
+//!     serde_json::to_string(&theme).unwrap(),
+//! );
+//! ```
+
+#[cfg(feature = "alloc")]
+use crate::ParsedColour;
+use crate::{Colour, ColourStop, Gradient, Rgba};
+use ::serde::{
+    de::{self, value::MapAccessDeserializer},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct Rgb {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+struct ColourVisitor;
+
+impl<'de> de::Visitor<'de> for ColourVisitor {
+    type Value = Colour;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a hex colour string, an RGB struct or an integer")
+    }
+
+    #[cfg(feature = "alloc")]
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    /// Without `alloc` there's no parser, so only hex strings are accepted
+    #[cfg(not(feature = "alloc"))]
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Colour::from_hex_str(v).ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Signed(v), &self)),
+        }
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        match u32::try_from(v) {
+            Ok(v) if v <= 0xffffff => Ok(Colour::new(v)),
+            _ => Err(E::invalid_value(de::Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let Rgb { r, g, b } = Rgb::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(Colour::from_rgb(r, g, b))
+    }
+}
+
+fn deserialize_any<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Colour, D::Error> {
+    deserializer.deserialize_any(ColourVisitor)
+}
+
+/// Deserialise a colour from its hex value, rejecting values that don't fit in
+/// 24 bits
+///
+/// Human readable formats also accept a `"#rrggbb"` string or an RGB struct,
+/// like [`hex_string`], so a field can switch to another representation
+/// without breaking existing data. Binary formats only accept the integer.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use chrom::Colour;
+///
+/// assert_eq!(Colour::BLUE, serde_json::from_str("3447003").unwrap());
+/// assert_eq!(Colour::BLUE, serde_json::from_str(r##""#3498db""##).unwrap());
+/// assert!(serde_json::from_str::<Colour>("16777216").is_err());
+/// # }
+/// ```
+impl<'de> Deserialize<'de> for Colour {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            return deserialize_any(deserializer);
+        }
+
+        let value = u32::deserialize(deserializer)?;
+        Colour::from_u32_checked(value).map_err(de::Error::custom)
+    }
+}
+
+/// A colour which serialises as a `"#rrggbb"` string
+#[derive(Deserialize, Serialize)]
+struct Hex(#[serde(with = "hex_string")] Colour);
+
+/// A gradient stop, or a colour without a position
+#[cfg(feature = "alloc")]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Stop {
+    Positioned(f32, Hex),
+    Colour(Hex),
+}
+
+/// Serialise a gradient as a list of `[position, "#rrggbb"]` stops
+///
+/// Binary formats, which aren't human readable, get `(position, colour)`
+/// pairs with the colour as an integer instead.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use chrom::{Colour, ColourStop, Gradient};
+///
+/// let stops = [ColourStop::new(0.0, Colour::BLACK), ColourStop::new(0.25, Colour::BLUE)];
+/// let gradient = Gradient::from_stops(stops).unwrap();
+/// assert_eq!(
+///     r##"[[0.0,"#000000"],[0.25,"#3498db"]]"##,
+///     serde_json::to_string(&gradient).unwrap(),
+/// );
+/// # }
+/// ```
+impl<S: AsRef<[ColourStop]>> Serialize for Gradient<S> {
+    fn serialize<T: Serializer>(&self, serializer: T) -> Result<T::Ok, T::Error> {
+        let stops = self.stops().iter();
+        if serializer.is_human_readable() {
+            serializer.collect_seq(stops.map(|stop| (stop.position, Hex(stop.colour))))
+        } else {
+            serializer.collect_seq(stops.map(|stop| (stop.position, stop.colour)))
+        }
+    }
+}
+
+/// Deserialise a gradient from a list of `[position, "#rrggbb"]` stops, or a
+/// list of colours which are evenly spaced
+///
+/// The stops are checked like [`Gradient::from_stops`]. Binary formats only
+/// accept the `(position, colour)` pairs they are serialised as, since the
+/// other forms need a self-describing format.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use chrom::{Colour, ColourStop, Gradient};
+///
+/// let gradient: Gradient = serde_json::from_str(r##"[[0, "#000000"], [0.25, "#3498db"]]"##).unwrap();
+/// assert_eq!(
+///     [ColourStop::new(0.0, Colour::BLACK), ColourStop::new(0.25, Colour::BLUE)],
+///     gradient.stops(),
+/// );
+/// let gradient: Gradient = serde_json::from_str(r##"["#000000", "#ffffff"]"##).unwrap();
+/// assert_eq!(Gradient::new(Colour::BLACK, Colour::WHITE), gradient);
+/// assert_eq!(gradient, serde_json::from_str(&serde_json::to_string(&gradient).unwrap()).unwrap());
+///
+/// assert!(serde_json::from_str::<Gradient>("[]").is_err());
+/// assert!(serde_json::from_str::<Gradient>(r##"[[1.5, "#000000"]]"##).is_err());
+/// assert!(serde_json::from_str::<Gradient>(r##"[[0.5, "#000000"], [0.2, "#ffffff"]]"##).is_err());
+/// assert!(serde_json::from_str::<Gradient>(r##"[[0, "#000000"], "#ffffff"]"##).is_err());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for Gradient {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            let stops = Vec::<(f32, Colour)>::deserialize(deserializer)?;
+            let stops = stops
+                .into_iter()
+                .map(|(position, colour)| ColourStop::new(position, colour));
+            return Gradient::from_stops(stops.collect()).map_err(de::Error::custom);
+        }
+
+        let stops = Vec::<Stop>::deserialize(deserializer)?;
+        let gradient = if stops.iter().all(|stop| matches!(stop, Stop::Colour(_))) {
+            let colours: Vec<_> = stops
+                .into_iter()
+                .filter_map(|stop| match stop {
+                    Stop::Colour(Hex(colour)) => Some(colour),
+                    Stop::Positioned(..) => None,
+                })
+                .collect();
+            Gradient::from_colours(&colours)
+        } else {
+            let stops = stops
+                .into_iter()
+                .map(|stop| match stop {
+                    Stop::Positioned(position, Hex(colour)) => {
+                        Ok(ColourStop::new(position, colour))
+                    }
+                    Stop::Colour(_) => Err(de::Error::custom(
+                        "either every gradient stop or none must have a position",
+                    )),
+                })
+                .collect::<Result<_, _>>()?;
+            Gradient::from_stops(stops)
+        };
+        gradient.map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "alloc")]
+struct RgbaVisitor;
+
+#[cfg(feature = "alloc")]
+impl de::Visitor<'_> for RgbaVisitor {
+    type Value = Rgba;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a hex colour string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+/// Serialise a colour with alpha as a `"#rrggbbaa"` string
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use chrom::Colour;
+///
+/// let overlay = Colour::BLACK.with_alpha(0x80);
+/// assert_eq!(r##""#00000080""##, serde_json::to_string(&overlay).unwrap());
+/// # }
+/// ```
+impl Serialize for Rgba {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserialise a colour with alpha from a string, parsed like
+/// [`Rgba`](crate::Rgba#impl-FromStr-for-Rgba)
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use chrom::{Colour, Rgba};
+///
+/// let overlay: Rgba = serde_json::from_str(r##""#00000080""##).unwrap();
+/// assert_eq!(Colour::BLACK.with_alpha(0x80), overlay);
+/// assert_eq!(Rgba::from(Colour::BLUE), serde_json::from_str(r##""#3498db""##).unwrap());
+/// assert_eq!(Rgba::TRANSPARENT, serde_json::from_str(r#""transparent""#).unwrap());
+/// assert!(serde_json::from_str::<Rgba>("2147483647").is_err());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for Rgba {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(RgbaVisitor)
+    }
+}
+
+/// Represent a colour as a `"#rrggbb"` string
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(with = "chrom::serde::hex_string")]
+///     colour: Colour,
+/// }
+///
+/// let config: Config = serde_json::from_str(r##"{"colour": "#3498db"}"##).unwrap();
+/// assert_eq!(Colour::BLUE, config.colour);
+/// let config: Config = serde_json::from_str(r#"{"colour": {"r": 52, "g": 152, "b": 219}}"#).unwrap();
+/// assert_eq!(Colour::BLUE, config.colour);
+/// ```
+pub mod hex_string {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(colour: &Colour, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(colour)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Colour, D::Error> {
+        deserialize_any(deserializer)
+    }
+}
+
+/// Represent a colour as a struct with `r`, `g` and `b` fields
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(with = "chrom::serde::rgb_struct")]
+///     colour: Colour,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"colour": {"r": 52, "g": 152, "b": 219}}"#).unwrap();
+/// assert_eq!(Colour::BLUE, config.colour);
+/// let config: Config = serde_json::from_str(r##"{"colour": "#3498db"}"##).unwrap();
+/// assert_eq!(Colour::BLUE, config.colour);
+/// ```
+pub mod rgb_struct {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(colour: &Colour, serializer: S) -> Result<S::Ok, S::Error> {
+        Rgb {
+            r: colour.red(),
+            g: colour.green(),
+            b: colour.blue(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Colour, D::Error> {
+        deserialize_any(deserializer)
+    }
+}
+
+/// Represent an optional colour as a `"#rrggbb"` string, or `"transparent"`
+///
+/// `"transparent"` and `"none"` deserialise as `None`, as does a missing value
+/// for formats that have one. Colours can use any of the other
+/// representations. Add `#[serde(default)]` to allow the field to be left out.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Config {
+///     #[serde(default, with = "chrom::serde::optional")]
+///     border: Option<Colour>,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"border": "transparent"}"#).unwrap();
+/// assert_eq!(None, config.border);
+/// let config: Config = serde_json::from_str(r#"{"border": "none"}"#).unwrap();
+/// assert_eq!(None, config.border);
+/// let config: Config = serde_json::from_str(r#"{"border": null}"#).unwrap();
+/// assert_eq!(None, config.border);
+/// let config: Config = serde_json::from_str("{}").unwrap();
+/// assert_eq!(None, config.border);
+/// let config: Config = serde_json::from_str(r##"{"border": "#3498db"}"##).unwrap();
+/// assert_eq!(Some(Colour::BLUE), config.border);
+/// let config: Config = serde_json::from_str(r#"{"border": {"r": 52, "g": 152, "b": 219}}"#).unwrap();
+/// assert_eq!(Some(Colour::BLUE), config.border);
+///
+/// let config = Config { border: None };
+/// assert_eq!(r#"{"border":"transparent"}"#, serde_json::to_string(&config).unwrap());
+/// let config = Config { border: Some(Colour::BLUE) };
+/// assert_eq!(r##"{"border":"#3498db"}"##, serde_json::to_string(&config).unwrap());
+/// ```
+#[cfg(feature = "alloc")]
+pub mod optional {
+    use super::*;
+
+    struct OptionalVisitor;
+
+    impl<'de> de::Visitor<'de> for OptionalVisitor {
+        type Value = Option<Colour>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a hex colour string, an RGB struct, an integer or \"transparent\"")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse::<ParsedColour>()
+                .map(ParsedColour::colour)
+                .map_err(E::custom)
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            ColourVisitor.visit_i64(v).map(Some)
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            ColourVisitor.visit_u64(v).map(Some)
+        }
+
+        fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+            ColourVisitor.visit_map(map).map(Some)
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_any(self)
+        }
+    }
+
+    pub fn serialize<S: Serializer>(
+        colour: &Option<Colour>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&ParsedColour::from(*colour))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Colour>, D::Error> {
+        deserializer.deserialize_any(OptionalVisitor)
+    }
+}
+
+/// Represent a colour as a `"#rrggbb"` string in human readable formats, and
+/// as an integer in binary formats
+///
+/// Formats like JSON and TOML are human readable, formats like bincode and
+/// postcard aren't. Human readable formats also accept any of the other
+/// representations when deserialising.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Config {
+///     #[serde(with = "chrom::serde::human_readable")]
+///     colour: Colour,
+/// }
+///
+/// let config = Config { colour: Colour::BLUE };
+/// assert_eq!(r##"{"colour":"#3498db"}"##, serde_json::to_string(&config).unwrap());
+/// let config: Config = serde_json::from_str(r#"{"colour": 3447003}"#).unwrap();
+/// assert_eq!(Colour::BLUE, config.colour);
+/// ```
+pub mod human_readable {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(colour: &Colour, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(colour)
+        } else {
+            colour.serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Colour, D::Error> {
+        Colour::deserialize(deserializer)
+    }
+}
+
+/// Represent a colour as an integer, like `0x3498db`, in every format
+///
+/// This is what Discord and other APIs which take colours as numbers expect.
+/// Human readable formats also accept any of the other representations when
+/// deserialising.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Embed {
+///     #[serde(with = "chrom::serde::int")]
+///     color: Colour,
+/// }
+///
+/// let embed = Embed { color: Colour::BLUE };
+/// assert_eq!(r#"{"color":3447003}"#, serde_json::to_string(&embed).unwrap());
+/// let embed: Embed = serde_json::from_str(r##"{"color": "#3498db"}"##).unwrap();
+/// assert_eq!(Colour::BLUE, embed.color);
+/// ```
+pub mod int {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(colour: &Colour, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(colour.as_u32())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Colour, D::Error> {
+        Colour::deserialize(deserializer)
+    }
+}
+
+/// Rewrite the colours at `paths` in a JSON document in canonical form, see
+/// [`Colour::to_canonical_string`]
+///
+/// Paths are JSON pointers, like `/colors/editor.background`, where a `*`
+/// matches every key of an object or every element of an array. Paths which
+/// aren't in the document, and `null` values, are skipped. Colours can be
+/// anything [`human_readable`] deserialises, so strings, integers and RGB
+/// objects. Anything else is left untouched, and the JSON pointers to those
+/// values are returned in the order they were found, so they can be looked up
+/// with [`serde_json::Value::pointer`].
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+///
+/// let mut theme = json!({
+///     "colors": { "background": "#FFF", "foreground": 3447003, "border": "#12345678" },
+///     "tokens": [{ "colour": { "r": 255, "g": 0, "b": 0 } }, { "colour": "#f00" }],
+/// });
+/// let unparsed = chrom::serde::canonicalise_json(&mut theme, &["/colors/*", "/tokens/*/colour"]);
+/// assert_eq!(vec!["/colors/border"], unparsed);
+/// assert_eq!(
+///     json!({
+///         "colors": { "background": "#ffffff", "foreground": "#3498db", "border": "#12345678" },
+///         "tokens": [{ "colour": "#ff0000" }, { "colour": "#ff0000" }],
+///     }),
+///     theme,
+/// );
+/// ```
+#[cfg(feature = "serde_json")]
+pub fn canonicalise_json(value: &mut serde_json::Value, paths: &[&str]) -> Vec<String> {
+    let mut unparsed = Vec::new();
+    for path in paths {
+        let tokens: Vec<_> = if path.is_empty() {
+            Vec::new()
+        } else {
+            path.strip_prefix('/')
+                .unwrap_or(path)
+                .split('/')
+                .map(|token| token.replace("~1", "/").replace("~0", "~"))
+                .collect()
+        };
+        canonicalise_at(value, &tokens, &mut String::new(), &mut unparsed);
+    }
+    unparsed
+}
+
+/// Canonicalise the colours at `tokens` below `value`, which is at `pointer`
+#[cfg(feature = "serde_json")]
+fn canonicalise_at(
+    value: &mut serde_json::Value,
+    tokens: &[String],
+    pointer: &mut String,
+    unparsed: &mut Vec<String>,
+) {
+    use serde_json::Value;
+
+    let Some((token, rest)) = tokens.split_first() else {
+        if !value.is_null() {
+            match deserialize_any(&*value) {
+                Ok(colour) => *value = Value::String(colour.to_canonical_string()),
+                Err(_) if !unparsed.contains(pointer) => unparsed.push(pointer.clone()),
+                Err(_) => {}
+            }
+        }
+        return;
+    };
+
+    let len = pointer.len();
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                if token == "*" || key == token {
+                    pointer.push('/');
+                    pointer.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                    canonicalise_at(child, rest, pointer, unparsed);
+                    pointer.truncate(len);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (i, child) in items.iter_mut().enumerate() {
+                let index = i.to_string();
+                if token == "*" || index == *token {
+                    pointer.push('/');
+                    pointer.push_str(&index);
+                    canonicalise_at(child, rest, pointer, unparsed);
+                    pointer.truncate(len);
+                }
+            }
+        }
+        _ => {}
+    }
+}
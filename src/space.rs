@@ -0,0 +1,220 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::Colour;
+
+/// Convert an sRGB encoded channel in the range `0.0..=1.0` to linear light
+pub(crate) fn decode_channel(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert a linear light channel in the range `0.0..=1.0` to sRGB encoding
+pub(crate) fn encode_channel(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Check whether linear light components round to a valid sRGB colour
+pub(crate) fn in_gamut(rgb: [f32; 3]) -> bool {
+    const TOLERANCE: f32 = 0.5 / 255.0;
+    rgb.iter()
+        .all(|&c| (-TOLERANCE..=1.0 + TOLERANCE).contains(&encode_channel(c)))
+}
+
+impl Colour {
+    /// Get the linear light RGB components of the colour
+    ///
+    /// Each component is in the range `0.0..=1.0`. Prefer [`Srgb::decode`] when the
+    /// result is passed around, so that it can't be mistaken for encoded values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!([1.0, 0.0, 0.0], Colour::from_rgb(255, 0, 0).to_linear());
+    /// ```
+    pub fn to_linear(self) -> [f32; 3] {
+        if cfg!(feature = "precise") {
+            return self.to_linear_f64().map(|c| c as f32);
+        }
+
+        [self.red(), self.green(), self.blue()].map(|c| decode_channel(c as f32 / 255.0))
+    }
+
+    /// Make a new colour using linear light RGB components
+    ///
+    /// Components are clamped to the range `0.0..=1.0` before encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let blue = Colour::BLUE;
+    /// assert_eq!(blue, Colour::from_linear(blue.to_linear()));
+    /// ```
+    pub fn from_linear(rgb: [f32; 3]) -> Self {
+        if cfg!(feature = "precise") {
+            return Self::from_linear_f64(rgb.map(f64::from));
+        }
+
+        let [red, green, blue] =
+            rgb.map(|c| (encode_channel(c.clamp(0.0, 1.0)) * 255.0).round() as u8);
+        Self::from_rgb(red, green, blue)
+    }
+
+    /// Get the relative luminance of the colour
+    ///
+    /// This is the luminance defined by WCAG, in the range `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(1.0, Colour::WHITE.relative_luminance());
+    /// assert_eq!(0.0, Colour::BLACK.relative_luminance());
+    /// assert!((Colour::from_rgb(128, 128, 128).relative_luminance() - 0.21586).abs() < 1e-5);
+    /// ```
+    pub fn relative_luminance(self) -> f32 {
+        if cfg!(feature = "precise") {
+            return self.relative_luminance_f64() as f32;
+        }
+
+        Srgb(self).luminance()
+    }
+}
+
+/// A colour tagged as being sRGB encoded
+///
+/// This is the space [`Colour`] values are stored in. The tag only exists so that
+/// functions which care about the difference can say so in their signature.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, Srgb};
+///
+/// let blue = Srgb(Colour::BLUE);
+/// assert_eq!(blue, blue.decode().encode());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Srgb(pub Colour);
+
+impl Srgb {
+    /// Convert the colour to linear light
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, LinearRgb, Srgb};
+    ///
+    /// let white = Srgb(Colour::WHITE);
+    /// assert_eq!(LinearRgb([1.0, 1.0, 1.0]), white.decode());
+    /// ```
+    pub fn decode(self) -> LinearRgb {
+        LinearRgb(self.0.to_linear())
+    }
+
+    /// Get the relative luminance of the colour
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, Srgb};
+    ///
+    /// assert_eq!(1.0, Srgb(Colour::WHITE).luminance());
+    /// ```
+    pub fn luminance(self) -> f32 {
+        self.decode().luminance()
+    }
+}
+
+impl From<Colour> for Srgb {
+    fn from(colour: Colour) -> Self {
+        Self(colour)
+    }
+}
+
+impl From<Srgb> for Colour {
+    fn from(colour: Srgb) -> Self {
+        colour.0
+    }
+}
+
+/// A colour in linear light RGB
+///
+/// Components are nominally in the range `0.0..=1.0`. There is deliberately no
+/// conversion between this and [`Colour`] apart from [`LinearRgb::encode`] and
+/// [`Srgb::decode`], so values can't be encoded or decoded twice by accident.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, LinearRgb, Srgb};
+///
+/// let grey = LinearRgb([0.5, 0.5, 0.5]).encode();
+/// assert_eq!(Srgb(Colour::new(0xbcbcbc)), grey);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct LinearRgb(pub [f32; 3]);
+
+impl LinearRgb {
+    /// Convert the colour to sRGB encoding
+    ///
+    /// Components are clamped to the range `0.0..=1.0` before encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, LinearRgb, Srgb};
+    ///
+    /// assert_eq!(Srgb(Colour::BLACK), LinearRgb([0.0, 0.0, 0.0]).encode());
+    /// ```
+    pub fn encode(self) -> Srgb {
+        Srgb(Colour::from_linear(self.0))
+    }
+
+    /// Get the relative luminance of the colour
+    ///
+    /// Uses the Rec. 709 coefficients, as in WCAG.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::LinearRgb;
+    ///
+    /// assert_eq!(0.2126, LinearRgb([1.0, 0.0, 0.0]).luminance());
+    /// ```
+    pub fn luminance(self) -> f32 {
+        let [red, green, blue] = self.0;
+        0.2126 * red + 0.7152 * green + 0.0722 * blue
+    }
+
+    /// Mix two colours in linear light
+    ///
+    /// `t` is the proportion of `other` in the result, `0.0` giving `self` and
+    /// `1.0` giving `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::LinearRgb;
+    ///
+    /// let black = LinearRgb([0.0, 0.0, 0.0]);
+    /// let white = LinearRgb([1.0, 1.0, 1.0]);
+    /// assert_eq!(LinearRgb([0.25, 0.25, 0.25]), black.mix(white, 0.25));
+    /// ```
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        let [r1, g1, b1] = self.0;
+        let [r2, g2, b2] = other.0;
+        Self([r1 + (r2 - r1) * t, g1 + (g2 - g1) * t, b1 + (b2 - b1) * t])
+    }
+}
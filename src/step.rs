@@ -0,0 +1,227 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::Colour;
+
+/// A colour as integers which map exactly onto HSL
+///
+/// Every colour with the same largest and smallest channels has the same HSL
+/// saturation and lightness, and those colours form a ring of `6 * chroma`
+/// hues spaced evenly around the colour wheel. Stepping along the ring, or
+/// between rings while keeping the position on them, never rounds the same
+/// value twice, which is what makes the steps undo each other.
+#[derive(Clone, Copy, Debug)]
+struct Ring {
+    /// The largest channel plus the smallest, so lightness is `sum / 510`
+    sum: i32,
+    /// The largest channel minus the smallest
+    chroma: i32,
+    /// The position on the ring, so hue is `60 * index / chroma` degrees
+    index: i32,
+}
+
+impl Ring {
+    fn new(colour: Colour) -> Self {
+        let [red, green, blue] = [colour.red(), colour.green(), colour.blue()].map(i32::from);
+        let (max, min) = (red.max(green).max(blue), red.min(green).min(blue));
+        let chroma = max - min;
+        let index = if chroma == 0 {
+            0
+        } else if red == max && blue == min {
+            green - min
+        } else if green == max && blue == min {
+            2 * chroma - (red - min)
+        } else if green == max && red == min {
+            2 * chroma + (blue - min)
+        } else if blue == max && red == min {
+            4 * chroma - (green - min)
+        } else if blue == max && green == min {
+            4 * chroma + (red - min)
+        } else {
+            (6 * chroma - (blue - min)) % (6 * chroma)
+        };
+        Self {
+            sum: max + min,
+            chroma,
+            index,
+        }
+    }
+
+    /// The largest chroma a colour with this lightness can have
+    const fn range(sum: i32) -> i32 {
+        if sum < 255 {
+            sum
+        } else {
+            510 - sum
+        }
+    }
+
+    /// Move to another ring, keeping the same position on the wheel
+    fn with(self, sum: i32, chroma: i32) -> Self {
+        let index = if self.chroma == 0 {
+            0
+        } else {
+            div_round(self.index * chroma, self.chroma) % (6 * chroma).max(1)
+        };
+        Self { sum, chroma, index }
+    }
+
+    fn colour(self) -> Colour {
+        let Self { sum, chroma, index } = self;
+        let (max, min) = ((sum + chroma) / 2, (sum - chroma) / 2);
+        let (sector, offset) = if chroma == 0 {
+            (0, 0)
+        } else {
+            (index / chroma, index % chroma)
+        };
+        let (rising, falling) = (min + offset, max - offset);
+        let [red, green, blue] = match sector {
+            0 => [max, rising, min],
+            1 => [falling, max, min],
+            2 => [min, max, rising],
+            3 => [min, falling, max],
+            4 => [rising, min, max],
+            _ => [max, min, falling],
+        }
+        .map(|channel| channel as u8);
+        Colour::from_rgb(red, green, blue)
+    }
+}
+
+/// Divide, rounding to the nearest integer with halves rounded up
+const fn div_round(n: i32, d: i32) -> i32 {
+    (2 * n + d).div_euclid(2 * d)
+}
+
+/// Turn `amount` into a whole number of steps, at least one if it isn't zero
+fn steps(amount: f32) -> i32 {
+    let steps = amount.round() as i32;
+    if steps == 0 && amount != 0.0 {
+        amount.signum() as i32
+    } else {
+        steps
+    }
+}
+
+impl Colour {
+    /// Rotate the hue of the colour by about `degrees`, so that rotating back
+    /// gives the original colour
+    ///
+    /// This is for stepping through colours one key press at a time, where
+    /// [`Colour::rotate_hue`] can get stuck or drift: rounding to RGB after
+    /// each step can undo a small rotation, or leave a colour which doesn't
+    /// rotate back to where it started. Colours with little saturation have
+    /// few hues to choose from, so the step is rounded to the nearest one,
+    /// and is always at least one hue so that the colour changes. Saturation
+    /// and lightness stay exactly the same.
+    ///
+    /// Greys have no hue, so they are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let red = Colour::from_rgb(255, 0, 0);
+    /// assert_eq!(Colour::from_rgb(0, 255, 0), red.step_hue(120.0));
+    ///
+    /// // A barely saturated colour, which `rotate_hue` doesn't change
+    /// let colour = Colour::from_rgb(130, 128, 128);
+    /// assert_eq!(colour, colour.rotate_hue(1.0));
+    /// let stepped = colour.step_hue(1.0);
+    /// assert_ne!(colour, stepped);
+    /// assert_eq!(colour, stepped.step_hue(-1.0));
+    /// assert_eq!(colour.saturation(), stepped.saturation());
+    /// assert_eq!(colour.lightness(), stepped.lightness());
+    ///
+    /// assert_eq!(Colour::GREY, Colour::GREY.step_hue(1.0).step_hue(-1.0));
+    /// assert_eq!(Colour::WHITE, Colour::WHITE.step_hue(90.0));
+    /// ```
+    pub fn step_hue(self, degrees: f32) -> Self {
+        let ring = Ring::new(self);
+        if ring.chroma == 0 {
+            return self;
+        }
+
+        let index = ring.index + steps(degrees * ring.chroma as f32 / 60.0);
+        Ring {
+            index: index.rem_euclid(6 * ring.chroma),
+            ..ring
+        }
+        .colour()
+    }
+
+    /// Increase the HSL saturation of the colour by about `amount`, so that
+    /// decreasing it again gives the original colour
+    ///
+    /// The saturation changes by at least the smallest amount it can, so
+    /// that the colour always changes until it is as saturated, or as close
+    /// to grey, as it can be. Hue and lightness stay the same, with hue
+    /// rounded to the nearest one available.
+    ///
+    /// Stepping back gives the original colour unless the saturation was
+    /// clamped, or the first step was towards grey: less saturated colours
+    /// have fewer hues to choose from, so some colours have to end up on the
+    /// same one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let colour = Colour::from_rgb(130, 128, 128);
+    /// assert_eq!(colour, colour.saturate(0.001));
+    /// let stepped = colour.step_saturation(0.001);
+    /// assert!(stepped.saturation() > colour.saturation());
+    /// assert_eq!(colour, stepped.step_saturation(-0.001));
+    ///
+    /// assert_eq!(Colour::from_rgb(255, 0, 0), Colour::from_rgb(204, 51, 51).step_saturation(0.4));
+    /// assert_eq!(Colour::new(0x808080), Colour::from_rgb(255, 1, 1).step_saturation(-1.0));
+    /// ```
+    pub fn step_saturation(self, amount: f32) -> Self {
+        let ring = Ring::new(self);
+        let range = Ring::range(ring.sum);
+        let change = 2 * steps(amount * range as f32 / 2.0);
+        let chroma = (ring.chroma + change).clamp(ring.sum % 2, range);
+        ring.with(ring.sum, chroma).colour()
+    }
+
+    /// Increase the HSL lightness of the colour by about `amount`, so that
+    /// decreasing it again gives the original colour
+    ///
+    /// The lightness changes by a whole number of 255ths, and at least one,
+    /// so that the colour always changes until it is black or white. Hue and
+    /// saturation stay the same, rounded to the nearest ones available.
+    ///
+    /// Stepping back gives the original colour unless the lightness was
+    /// clamped, or the first step was towards black or white: very dark and
+    /// very light colours have fewer hues and saturations to choose from, so
+    /// some colours have to end up on the same one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let colour = Colour::from_rgb(130, 128, 128);
+    /// let stepped = colour.step_lightness(-0.001);
+    /// assert!(stepped.lightness() < colour.lightness());
+    /// assert_eq!(colour, stepped.step_lightness(0.001));
+    ///
+    /// assert_eq!(Colour::from_rgb(255, 102, 102), Colour::from_rgb(255, 0, 0).step_lightness(0.2));
+    /// assert_eq!(Colour::WHITE, Colour::BLUE.step_lightness(1.0));
+    /// ```
+    pub fn step_lightness(self, amount: f32) -> Self {
+        let ring = Ring::new(self);
+        // Clamping goes to black and white themselves, which colours with an
+        // odd sum can't otherwise reach
+        let sum = (ring.sum + 2 * steps(amount * 255.0)).clamp(0, 510);
+        let (from, to) = (Ring::range(ring.sum), Ring::range(sum));
+        let parity = sum % 2;
+        let chroma = if from == 0 {
+            0
+        } else {
+            parity + 2 * div_round(ring.chroma * to - parity * from, 2 * from)
+        };
+        ring.with(sum, chroma.clamp(parity, to)).colour()
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{Channel, Colour};
+use core::ops;
+
+/// A colour whose operators panic when a channel overflows, instead of
+/// saturating
+///
+/// This is for catching arithmetic mistakes while debugging, the same way
+/// integer overflow checks do in debug builds. The operators panic whether or
+/// not debug assertions are enabled.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::{Colour, Strict};
+///
+/// let sum = Colour::new(0x102030).strict() + Strict(Colour::new(0x010203));
+/// assert_eq!(Colour::new(0x112233), sum.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Strict(pub Colour);
+
+impl Strict {
+    /// Combine two colours channel by channel, panicking with a message about
+    /// the first channel `f` returns `None` for
+    fn zip_channels(
+        self,
+        other: Self,
+        operation: &str,
+        symbol: &str,
+        f: impl Fn(u8, u8) -> Option<u8>,
+    ) -> Self {
+        let [red, green, blue] = [Channel::Red, Channel::Green, Channel::Blue].map(|channel| {
+            let (a, b) = (self.0.channel(channel), other.0.channel(channel));
+            f(a, b).unwrap_or_else(|| {
+                let name = match channel {
+                    Channel::Red => "red",
+                    Channel::Green => "green",
+                    Channel::Blue => "blue",
+                };
+                panic!("attempt to {operation} in the {name} channel: {a} {symbol} {b}")
+            })
+        });
+        Self(Colour::from_rgb(red, green, blue))
+    }
+}
+
+impl Colour {
+    /// Wrap the colour so that its operators panic on overflow, see [`Strict`]
+    pub const fn strict(self) -> Strict {
+        Strict(self)
+    }
+}
+
+impl From<Colour> for Strict {
+    fn from(colour: Colour) -> Self {
+        Self(colour)
+    }
+}
+
+impl From<Strict> for Colour {
+    fn from(strict: Strict) -> Self {
+        strict.0
+    }
+}
+
+/// Add channel by channel, panicking if a channel goes over 255
+impl ops::Add for Strict {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_channels(rhs, "add with overflow", "+", u8::checked_add)
+    }
+}
+
+impl ops::AddAssign for Strict {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// Divide channel by channel, panicking if a channel is divided by zero
+impl ops::Div for Strict {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.zip_channels(rhs, "divide by zero", "/", u8::checked_div)
+    }
+}
+
+impl ops::DivAssign for Strict {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+/// Multiply channel by channel, panicking if a channel goes over 255
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+///
+/// let mut colour = Colour::from_rgb(2, 3, 4).strict();
+/// colour *= Colour::from_rgb(100, 50, 10).strict();
+/// assert_eq!(Colour::from_rgb(200, 150, 40), colour.0);
+/// ```
+impl ops::Mul for Strict {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.zip_channels(rhs, "multiply with overflow", "*", u8::checked_mul)
+    }
+}
+
+impl ops::MulAssign for Strict {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Subtract channel by channel, panicking if a channel goes under 0
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+///
+/// let difference = Colour::WHITE.strict() - Colour::BLUE.strict();
+/// assert_eq!(Colour::BLUE.invert(), difference.0);
+/// ```
+impl ops::Sub for Strict {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_channels(rhs, "subtract with overflow", "-", u8::checked_sub)
+    }
+}
+
+impl ops::SubAssign for Strict {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
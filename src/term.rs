@@ -0,0 +1,211 @@
+use crate::Colour;
+use std::io::{self, Write};
+
+/// The ANSI escape sequence for the default foreground colour
+const RESET_FG: &[u8] = b"\x1b[39m";
+
+/// The escape sequences which reset the foreground, on its own or along with
+/// everything else
+const RESETS: [&[u8]; 3] = [RESET_FG, b"\x1b[0m", b"\x1b[m"];
+
+/// Get the index just after the first reset of the foreground in `bytes`
+fn reset_end(bytes: &[u8]) -> Option<usize> {
+    (0..bytes.len())
+        .filter(|&i| bytes[i] == 0x1b)
+        .find_map(|i| {
+            let reset = RESETS.iter().find(|reset| bytes[i..].starts_with(reset))?;
+            Some(i + reset.len())
+        })
+}
+
+/// The colours of the 16 system colours at the start of the xterm 256 colour
+/// palette, which terminals let users change
+const SYSTEM_COLOURS: [u32; 16] = [
+    0x000000, 0xcd0000, 0x00cd00, 0xcdcd00, 0x0000ee, 0xcd00cd, 0x00cdcd, 0xe5e5e5, 0x7f7f7f,
+    0xff0000, 0x00ff00, 0xffff00, 0x5c5cff, 0xff00ff, 0x00ffff, 0xffffff,
+];
+
+/// The levels of each channel in the 6x6x6 colour cube of the xterm 256
+/// colour palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Sets the terminal foreground colour until it is dropped, made by
+/// [`Colour::set_fg_guard`]
+///
+/// Dropping the guard resets the foreground, even when unwinding from a panic,
+/// so a panic in the middle of coloured output doesn't leave the terminal
+/// coloured. A guard made with [`TermColourGuard::nested`] restores the colour
+/// of its parent instead. Errors writing the reset can't be returned from
+/// `drop`, so they are ignored.
+///
+/// Text written to the guard goes to its writer. Text which resets the
+/// foreground itself, like painted text does after it, is followed by the
+/// colour of the guard again, so the rest of the text keeps it.
+///
+/// # Examples
+///
+/// ```
+/// use chrom::Colour;
+/// use std::io::Write;
+///
+/// let mut output = Vec::new();
+/// {
+///     let mut blue = Colour::BLUE.set_fg_guard(&mut output).unwrap();
+///     write!(blue, "blue ").unwrap();
+///     {
+///         let mut red = blue.nested(Colour::RED).unwrap();
+///         write!(red, "red ").unwrap();
+///     }
+///     write!(blue, "blue again").unwrap();
+/// }
+/// assert_eq!(
+///     b"\x1b[38;2;52;152;219mblue \x1b[38;2;237;66;69mred \x1b[38;2;52;152;219mblue again\x1b[39m",
+///     &output[..],
+/// );
+/// ```
+#[derive(Debug)]
+#[must_use = "the colour is reset as soon as the guard is dropped"]
+pub struct TermColourGuard<W: Write> {
+    writer: W,
+    colour: Colour,
+    /// The colour to restore on drop, or `None` to reset
+    previous: Option<Colour>,
+}
+
+impl<W: Write> TermColourGuard<W> {
+    fn new(mut writer: W, colour: Colour, previous: Option<Colour>) -> io::Result<Self> {
+        write!(writer, "{}", colour.ansi_fg_display())?;
+        Ok(Self {
+            writer,
+            colour,
+            previous,
+        })
+    }
+
+    /// Set the foreground to `colour` until the returned guard is dropped,
+    /// when it goes back to the colour of this guard
+    pub fn nested(&mut self, colour: Colour) -> io::Result<TermColourGuard<&mut W>> {
+        TermColourGuard::new(&mut self.writer, colour, Some(self.colour))
+    }
+
+    /// Get the colour this guard sets
+    pub fn colour(&self) -> Colour {
+        self.colour
+    }
+}
+
+impl<W: Write> Write for TermColourGuard<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut rest = buf;
+        while let Some(end) = reset_end(rest) {
+            self.writer.write_all(&rest[..end])?;
+            write!(self.writer, "{}", self.colour.ansi_fg_display())?;
+            rest = &rest[end..];
+        }
+        self.writer.write_all(rest)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Drop for TermColourGuard<W> {
+    fn drop(&mut self) {
+        let _ = match self.previous {
+            Some(colour) => write!(self.writer, "{}", colour.ansi_fg_display()),
+            None => self.writer.write_all(RESET_FG),
+        };
+    }
+}
+
+impl Colour {
+    /// Set the terminal foreground to the colour until the returned guard is
+    /// dropped, see [`TermColourGuard`]
+    ///
+    /// The escape sequence is written straight away, and the error is
+    /// returned if that fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    /// use std::io::Write;
+    ///
+    /// let mut output = Vec::new();
+    /// {
+    ///     let mut guard = Colour::BLUE.set_fg_guard(&mut output).unwrap();
+    ///     write!(guard, "blue").unwrap();
+    /// }
+    /// assert_eq!(b"\x1b[38;2;52;152;219mblue\x1b[39m", &output[..]);
+    /// ```
+    pub fn set_fg_guard<W: Write>(self, writer: W) -> io::Result<TermColourGuard<W>> {
+        TermColourGuard::new(writer, self, None)
+    }
+}
+
+impl Colour {
+    /// Get the index of the closest colour in the xterm 256 colour palette,
+    /// for terminals without 24-bit colour
+    ///
+    /// Only the 6x6x6 colour cube (16 to 231) and the grey ramp (232 to 255)
+    /// are used, since terminals let users change the 16 system colours.
+    /// Closeness is measured with [`Colour::delta_e`], like
+    /// [`Colour::to_palette_index`]. Write
+    /// `\x1b[38;5;{index}m` to set the foreground to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(32, Colour::BLUE.to_ansi256());
+    /// assert_eq!(16, Colour::BLACK.to_ansi256());
+    /// assert_eq!(231, Colour::WHITE.to_ansi256());
+    /// assert_eq!(244, Colour::new(0x808080).to_ansi256());
+    /// ```
+    pub fn to_ansi256(self) -> u8 {
+        let mut nearest = (16, f32::INFINITY);
+        for index in 16..=255 {
+            let distance = self.delta_e(Self::from_ansi256(index));
+            if distance < nearest.1 {
+                nearest = (index, distance);
+            }
+        }
+        nearest.0
+    }
+
+    /// Get the colour at an index in the xterm 256 colour palette
+    ///
+    /// The 16 system colours have xterm's default values, which depend on the
+    /// terminal and its theme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0x0087d7), Colour::from_ansi256(32));
+    /// assert_eq!(Colour::new(0xcd0000), Colour::from_ansi256(1));
+    /// assert_eq!(Colour::new(0x080808), Colour::from_ansi256(232));
+    /// assert_eq!(Colour::new(0xeeeeee), Colour::from_ansi256(255));
+    /// ```
+    pub const fn from_ansi256(index: u8) -> Self {
+        match index {
+            0..=15 => Self::new(SYSTEM_COLOURS[index as usize]),
+            16..=231 => {
+                let index = index - 16;
+                Self::from_rgb(
+                    CUBE_LEVELS[(index / 36) as usize],
+                    CUBE_LEVELS[(index / 6 % 6) as usize],
+                    CUBE_LEVELS[(index % 6) as usize],
+                )
+            }
+            _ => {
+                let grey = 8 + 10 * (index - 232);
+                Self::from_rgb(grey, grey, grey)
+            }
+        }
+    }
+}
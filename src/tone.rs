@@ -0,0 +1,75 @@
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{oklab, Colour, LinearRgb};
+
+impl Colour {
+    /// Make a new colour using linear light RGB components which can be
+    /// brighter than `1.0`, keeping the hue
+    ///
+    /// [`Colour::from_linear`] clamps each component on its own, which shifts
+    /// overbright colours towards red, green, blue and their secondaries: a
+    /// bright orange like `[4.0, 1.5, 0.0]` becomes yellow. Instead, when any
+    /// component is over `1.0`, this keeps the Oklch hue, clamps the lightness
+    /// to white and reduces the chroma until the colour fits in sRGB, like the
+    /// CSS Color 4 gamut mapping. So the brighter a colour is, the closer it
+    /// gets to white, and anything as light as white is white. Colours without
+    /// overbright components are the same as with [`Colour::from_linear`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// let orange = [1.6, 0.4, 0.0];
+    /// let [_, _, hue] = Colour::from_linear_clipped_preserve_hue(orange).to_oklch();
+    /// assert!((hue - 57.0).abs() < 1.0);
+    /// let [_, _, clamped_hue] = Colour::from_linear(orange).to_oklch();
+    /// assert!((clamped_hue - 73.0).abs() < 1.0);
+    ///
+    /// assert_eq!(Colour::WHITE, Colour::from_linear_clipped_preserve_hue([4.0, 3.0, 2.0]));
+    /// assert_eq!(Colour::BLUE, Colour::from_linear_clipped_preserve_hue(Colour::BLUE.to_linear()));
+    /// ```
+    pub fn from_linear_clipped_preserve_hue(rgb: [f32; 3]) -> Self {
+        if rgb.iter().all(|&c| c <= 1.0) {
+            return Self::from_linear(rgb);
+        }
+
+        let [l, a, b] = oklab::linear_to_oklab(rgb);
+        if l >= 1.0 {
+            return Self::WHITE;
+        }
+        oklab::from_oklch_in_gamut([l, a.hypot(b), b.atan2(a).to_degrees()])
+    }
+
+    /// Make a new colour using linear light RGB components which can be
+    /// brighter than `1.0`, with Reinhard tone mapping
+    ///
+    /// The colour is scaled so its luminance `Y` becomes `Y / (1 + Y)`, which
+    /// keeps the hue and brings any luminance into the range `0.0..1.0`. This
+    /// darkens every colour, so white becomes a mid grey, but keeps detail in
+    /// bright areas instead of clipping them. A saturated colour can still
+    /// have a component over `1.0` after scaling, which is clipped like
+    /// [`Colour::from_linear_clipped_preserve_hue`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::Colour;
+    ///
+    /// assert_eq!(Colour::new(0xbcbcbc), Colour::from_linear_reinhard([1.0, 1.0, 1.0]));
+    /// assert_eq!(Colour::new(0xf2f2f2), Colour::from_linear_reinhard([8.0, 8.0, 8.0]));
+    /// assert_eq!(Colour::BLACK, Colour::from_linear_reinhard([0.0, 0.0, 0.0]));
+    ///
+    /// let [_, _, hue] = Colour::from_linear_reinhard([1.6, 0.4, 0.0]).to_oklch();
+    /// assert!((hue - 57.0).abs() < 1.0);
+    /// ```
+    pub fn from_linear_reinhard(rgb: [f32; 3]) -> Self {
+        let luminance = LinearRgb(rgb).luminance();
+        if luminance <= 0.0 {
+            return Self::from_linear(rgb);
+        }
+
+        let scale = 1.0 / (1.0 + luminance);
+        Self::from_linear_clipped_preserve_hue(rgb.map(|c| c * scale))
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{lab::transform, Colour};
+
+/// The Bradford cone response matrix
+const BRADFORD: [[f32; 3]; 3] = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+/// The inverse of the Bradford cone response matrix
+const BRADFORD_INVERSE: [[f32; 3]; 3] = [
+    [0.986_992_9, -0.147_054_26, 0.159_962_65],
+    [0.432_305_3, 0.518_360_27, 0.049_291_23],
+    [-0.008_528_665, 0.040_042_82, 0.968_486_7],
+];
+
+/// A reference white, the colour of a light source
+///
+/// The standard illuminants use the chromaticities of the CIE 1931 2°
+/// observer, the same as CSS Color 4.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WhitePoint {
+    /// Horizon light, used by ICC profiles and most print work
+    D50,
+    /// Noon daylight, the white point of sRGB
+    D65,
+    /// North sky daylight
+    D75,
+    /// Incandescent light
+    A,
+    /// Equal energy
+    E,
+    /// A white point given by its XYZ components, with `Y` normally `1.0`
+    Custom([f32; 3]),
+}
+
+impl WhitePoint {
+    /// Get the XYZ components of the white point, with `Y` of `1.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, WhitePoint};
+    ///
+    /// assert_eq!([1.0, 1.0, 1.0], WhitePoint::E.xyz());
+    /// assert_eq!([0.5, 1.0, 0.5], WhitePoint::Custom([0.5, 1.0, 0.5]).xyz());
+    ///
+    /// let [x, y, z] = WhitePoint::D65.xyz();
+    /// let [wx, wy, wz] = Colour::WHITE.to_xyz();
+    /// assert!((x - wx).abs() < 1e-4 && (y - wy).abs() < 1e-4 && (z - wz).abs() < 1e-4);
+    /// ```
+    pub const fn xyz(self) -> [f32; 3] {
+        match self {
+            Self::D50 => [0.964_295_7, 1.0, 0.825_104_6],
+            Self::D65 => [0.950_455_9, 1.0, 1.089_057_8],
+            Self::D75 => [0.949_722_1, 1.0, 1.226_393_5],
+            Self::A => [1.098_466_1, 1.0, 0.355_822_8],
+            Self::E => [1.0, 1.0, 1.0],
+            Self::Custom(xyz) => xyz,
+        }
+    }
+
+    /// Get the Bradford matrix which adapts XYZ components from one white
+    /// point to another
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::WhitePoint;
+    ///
+    /// // The D50 to D65 matrix published in CSS Color 4
+    /// let expected = [
+    ///     [0.955_473_4, -0.023_098_455, 0.063_259_24],
+    ///     [-0.028_369_71, 1.009_995_4, 0.021_041_441],
+    ///     [0.012_314_015, -0.020_507_65, 1.330_365_9],
+    /// ];
+    /// let matrix = WhitePoint::bradford(WhitePoint::D50, WhitePoint::D65);
+    /// for (row, expected) in matrix.iter().zip(expected) {
+    ///     for (value, expected) in row.iter().zip(expected) {
+    ///         assert!((value - expected).abs() < 1e-4);
+    ///     }
+    /// }
+    /// ```
+    pub fn bradford(from: Self, to: Self) -> [[f32; 3]; 3] {
+        let [from, to] = [from, to].map(|white| transform(BRADFORD, white.xyz()));
+        let scale = [0, 1, 2].map(|i| to[i] / from[i]);
+        BRADFORD_INVERSE
+            .map(|row| [0, 1, 2].map(|j| (0..3).map(|k| row[k] * scale[k] * BRADFORD[k][j]).sum()))
+    }
+
+    /// Adapt XYZ components seen under one white point to how they would
+    /// appear under another, using the Bradford transform
+    ///
+    /// Adapting between equal white points leaves the components unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::WhitePoint;
+    ///
+    /// // D50 white becomes D65 white
+    /// let [x, y, z] = WhitePoint::adapt_xyz(WhitePoint::D50.xyz(), WhitePoint::D50, WhitePoint::D65);
+    /// assert!((x - 0.950_456).abs() < 1e-4);
+    /// assert!((y - 1.0).abs() < 1e-4);
+    /// assert!((z - 1.089_058).abs() < 1e-4);
+    ///
+    /// // A mid green, checked against the CSS Color 4 matrix
+    /// let [x, y, z] = WhitePoint::adapt_xyz([0.2, 0.3, 0.1], WhitePoint::D50, WhitePoint::D65);
+    /// assert!((x - 0.190_491).abs() < 1e-4);
+    /// assert!((y - 0.299_429).abs() < 1e-4);
+    /// assert!((z - 0.129_347).abs() < 1e-4);
+    ///
+    /// let xyz = [0.2, 0.3, 0.1];
+    /// assert_eq!(xyz, WhitePoint::adapt_xyz(xyz, WhitePoint::A, WhitePoint::A));
+    /// ```
+    pub fn adapt_xyz(xyz: [f32; 3], from: Self, to: Self) -> [f32; 3] {
+        if from == to {
+            return xyz;
+        }
+
+        transform(Self::bradford(from, to), xyz)
+    }
+}
+
+impl Colour {
+    /// Adapt the colour from one white point to another, using the Bradford
+    /// transform
+    ///
+    /// The colour's XYZ components are treated as relative to `from`, and the
+    /// result is the colour which looks the same relative to `to`. Colours
+    /// outside of the sRGB gamut are clamped, and adapting between equal white
+    /// points returns the colour unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrom::{Colour, WhitePoint};
+    ///
+    /// assert_eq!(Colour::BLUE, Colour::BLUE.adapt_white_point(WhitePoint::D65, WhitePoint::D65));
+    ///
+    /// // Adapting there and back again gives the colour back
+    /// let print = Colour::GREY.adapt_white_point(WhitePoint::D65, WhitePoint::D50);
+    /// assert_ne!(Colour::GREY, print);
+    /// assert_eq!(Colour::GREY, print.adapt_white_point(WhitePoint::D50, WhitePoint::D65));
+    ///
+    /// // Incandescent light is warmer, so adapting to it shifts towards red
+    /// let warm = Colour::GREY.adapt_white_point(WhitePoint::D65, WhitePoint::A);
+    /// assert!(warm.red() > warm.blue());
+    /// ```
+    pub fn adapt_white_point(self, from: WhitePoint, to: WhitePoint) -> Self {
+        if from == to {
+            return self;
+        }
+
+        Self::from_xyz(WhitePoint::adapt_xyz(self.to_xyz(), from, to))
+    }
+}
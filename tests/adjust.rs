@@ -0,0 +1,19 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use chrom::ColourAdjustment;
+use common::colours;
+
+#[test]
+fn apply_rgba_matches_apply() {
+    let disabled = ColourAdjustment::new().desaturate(0.5).set_alpha(0x60);
+    for colour in colours(9973) {
+        let adjusted = disabled.apply_rgba(colour.with_alpha(0xff));
+        assert_eq!(
+            disabled.apply(colour).with_alpha(0x60),
+            adjusted,
+            "{colour}"
+        );
+    }
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use chrom::{breathe, pulse_between, rainbow_cycle, Colour};
+use common::colours;
+
+/// Times over a few periods of each length, in 64ths of a second
+fn times() -> impl Iterator<Item = (f32, f32)> {
+    [0.75, 1.5, 3.0]
+        .into_iter()
+        .flat_map(|period| (0..640).map(move |i| (i as f32 / 64.0, period)))
+}
+
+#[test]
+fn breathe_repeats_and_stays_lit() {
+    for (t, period) in times() {
+        for colour in colours(99991) {
+            let breathing = breathe(colour, t, period);
+            assert_eq!(
+                breathing,
+                breathe(colour, t + period, period),
+                "{colour} at {t}"
+            );
+            assert_eq!(
+                colour == Colour::BLACK,
+                breathing == Colour::BLACK,
+                "{colour} at {t}"
+            );
+        }
+    }
+}
+
+#[test]
+fn rainbow_cycle_repeats() {
+    for (t, period) in times() {
+        assert_eq!(
+            rainbow_cycle(t, period, 0.8, 0.6),
+            rainbow_cycle(t + period, period, 0.8, 0.6),
+            "{t}"
+        );
+    }
+}
+
+#[test]
+fn pulse_between_repeats() {
+    for (t, period) in times() {
+        assert_eq!(
+            pulse_between(Colour::RED, Colour::GOLD, t, period),
+            pulse_between(Colour::RED, Colour::GOLD, t + period, period),
+            "{t}"
+        );
+    }
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "serde")]
+
+use chrom::Colour;
+#[cfg(feature = "alloc")]
+use chrom::{ColourStop, Gradient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Config {
+    #[serde(with = "chrom::serde::human_readable")]
+    colour: Colour,
+}
+
+#[test]
+fn human_readable_uses_strings_in_json() {
+    let config = Config {
+        colour: Colour::BLUE,
+    };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(r##"{"colour":"#3498db"}"##, json);
+    assert_eq!(config, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn human_readable_uses_integers_in_bincode() {
+    let config = Config {
+        colour: Colour::BLUE,
+    };
+    let bytes = bincode::serialize(&config).unwrap();
+    assert_eq!(0x3498db_u32.to_le_bytes().to_vec(), bytes);
+    assert_eq!(config, bincode::deserialize(&bytes).unwrap());
+}
+
+#[test]
+fn human_readable_accepts_other_representations() {
+    for json in [
+        r#"{"colour": 3447003}"#,
+        r#"{"colour": {"r": 52, "g": 152, "b": 219}}"#,
+    ] {
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(Colour::BLUE, config.colour, "{json}");
+    }
+}
+
+#[test]
+fn rgb_objects_reject_unknown_fields() {
+    let json = r#"{"colour": {"r": 52, "g": 152, "b": 219, "a": 0}}"#;
+    assert!(serde_json::from_str::<Config>(json).is_err());
+    assert!(serde_json::from_str::<Colour>(r#"{"r": 0, "g": 0, "b": 0, "a": 0}"#).is_err());
+}
+
+#[test]
+fn human_readable_rejects_out_of_range_values() {
+    let bytes = 0x1000000_u32.to_le_bytes();
+    assert!(bincode::deserialize::<Config>(&bytes).is_err());
+    assert!(serde_json::from_str::<Config>(r#"{"colour": 16777216}"#).is_err());
+}
+
+#[test]
+fn default_representation_uses_integers_in_bincode() {
+    for colour in [Colour::BLACK, Colour::BLUE, Colour::WHITE] {
+        let bytes = bincode::serialize(&colour).unwrap();
+        assert_eq!(colour.as_u32().to_le_bytes().to_vec(), bytes);
+        assert_eq!(colour, bincode::deserialize(&bytes).unwrap());
+    }
+    assert!(bincode::deserialize::<Colour>(&0x1000000_u32.to_le_bytes()).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn gradient_round_trips_through_bincode() {
+    let stops = [
+        ColourStop::new(0.0, Colour::BLACK),
+        ColourStop::new(0.25, Colour::BLUE),
+        ColourStop::new(1.0, Colour::WHITE),
+    ];
+    let gradient = Gradient::from_stops(stops.to_vec()).unwrap();
+    let bytes = bincode::serialize(&gradient).unwrap();
+    assert_eq!(gradient, bincode::deserialize::<Gradient>(&bytes).unwrap());
+
+    let unsorted = [(0.5_f32, 0_u32), (0.2, 0xffffff)];
+    let bytes = bincode::serialize(&unsorted).unwrap();
+    assert!(bincode::deserialize::<Gradient>(&bytes).is_err());
+}
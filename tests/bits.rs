@@ -0,0 +1,166 @@
+mod common;
+
+use chrom::{Colour, Endian, PackedFormat, Rgba};
+use common::colours;
+
+#[test]
+fn get_bits_matches_shift_and_mask() {
+    for colour in colours(9973) {
+        for offset in 0..=24 {
+            for width in 0..=24 - offset {
+                let expected = (colour.as_u32() >> offset) & ((1 << width) - 1);
+                assert_eq!(
+                    expected,
+                    colour.get_bits(offset, width),
+                    "{colour} {offset} {width}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn with_bits_round_trips() {
+    for colour in colours(9973) {
+        for offset in 0..=24 {
+            for width in 0..=24 - offset {
+                let bits = colour.get_bits(offset, width);
+                assert_eq!(
+                    colour,
+                    colour.with_bits(offset, width, bits),
+                    "{colour} {offset} {width}"
+                );
+
+                let changed = colour.with_bits(offset, width, u32::MAX);
+                assert!(changed.as_u32() <= 0xffffff, "{colour} {offset} {width}");
+                assert_eq!(
+                    (1 << width) - 1,
+                    changed.get_bits(offset, width),
+                    "{colour} {offset} {width}"
+                );
+                // The other bits are untouched
+                assert_eq!(colour.get_bits(0, offset), changed.get_bits(0, offset));
+                assert_eq!(
+                    colour.get_bits(offset + width, 24 - offset - width),
+                    changed.get_bits(offset + width, 24 - offset - width)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn boundary_bits() {
+    let colour = Colour::new(0x800001);
+    assert_eq!(1, colour.get_bits(0, 1));
+    assert_eq!(1, colour.get_bits(23, 1));
+    assert_eq!(0, colour.get_bits(1, 22));
+    assert_eq!(Colour::new(0x800000), colour.with_bits(0, 1, 0));
+    assert_eq!(Colour::new(0x000001), colour.with_bits(23, 1, 0));
+    assert_eq!(Colour::WHITE, Colour::BLACK.with_bits(0, 24, u32::MAX));
+}
+
+#[test]
+fn nibbles() {
+    for colour in colours(9973) {
+        let nibbles = [
+            colour.high_nibble_of_red(),
+            colour.low_nibble_of_red(),
+            colour.high_nibble_of_green(),
+            colour.low_nibble_of_green(),
+            colour.high_nibble_of_blue(),
+            colour.low_nibble_of_blue(),
+        ];
+        for (i, nibble) in nibbles.into_iter().enumerate() {
+            let offset = 20 - 4 * i as u8;
+            assert_eq!(
+                u32::from(nibble),
+                colour.get_bits(offset, 4),
+                "{colour} {i}"
+            );
+        }
+    }
+}
+
+#[test]
+fn channel_setters() {
+    for colour in colours(9973) {
+        assert_eq!(colour, colour.with_red(colour.red()));
+        assert_eq!(colour, colour.with_green(colour.green()));
+        assert_eq!(colour, colour.with_blue(colour.blue()));
+        assert_eq!(colour.with_bits(16, 8, 0x12), colour.with_red(0x12));
+        assert_eq!(colour.with_bits(8, 8, 0x12), colour.with_green(0x12));
+        assert_eq!(colour.with_bits(0, 8, 0x12), colour.with_blue(0x12));
+    }
+}
+
+#[test]
+fn const_bits() {
+    const COLOUR: Colour = Colour::BLACK.with_bits(20, 4, 0xa).with_bits(0, 4, 0x5);
+    const NIBBLE: u32 = COLOUR.get_bits(20, 4);
+    assert_eq!(Colour::new(0xa00005), COLOUR);
+    assert_eq!(0xa, NIBBLE);
+}
+
+#[test]
+#[should_panic(expected = "bits past the 24 bits of a colour")]
+fn get_bits_past_24_bits() {
+    let width = std::hint::black_box(9);
+    Colour::WHITE.get_bits(16, width);
+}
+
+#[test]
+#[should_panic(expected = "bits past the 24 bits of a colour")]
+fn with_bits_past_24_bits() {
+    let offset = std::hint::black_box(24);
+    Colour::WHITE.with_bits(offset, 1, 0);
+}
+
+#[test]
+#[should_panic(expected = "bits past the 24 bits of a colour")]
+fn offset_past_24_bits() {
+    let offset = std::hint::black_box(200);
+    Colour::WHITE.get_bits(offset, 200);
+}
+
+#[test]
+fn packed_round_trips() {
+    use PackedFormat::*;
+
+    let packed = [
+        (Rgba, Endian::Little, 0xffdb9834),
+        (Rgba, Endian::Big, 0x3498dbff),
+        (Argb, Endian::Little, 0xdb9834ff),
+        (Argb, Endian::Big, 0xff3498db),
+        (Bgra, Endian::Little, 0xff3498db),
+        (Bgra, Endian::Big, 0xdb9834ff),
+        (Abgr, Endian::Little, 0x3498dbff),
+        (Abgr, Endian::Big, 0xffdb9834),
+    ];
+    for (format, endian, value) in packed {
+        let blue = Colour::BLUE.to_packed(format, endian);
+        assert_eq!(value, blue, "{format:?} {endian:?}");
+        let unpacked = Colour::from_packed(value, format, endian);
+        assert_eq!(Colour::BLUE, unpacked, "{format:?} {endian:?}");
+        for colour in colours(9973) {
+            let packed = colour.to_packed(format, endian);
+            assert_eq!(colour, Colour::from_packed(packed, format, endian));
+        }
+    }
+}
+
+#[test]
+fn rgba_u32_round_trips() {
+    for value in (0..=u32::MAX).step_by(99991) {
+        assert_eq!(value, Rgba::from_u32(value).to_u32());
+    }
+}
+
+#[test]
+fn discord_visible_only_changes_black() {
+    assert_eq!(0x010101, Colour::BLACK.to_discord_visible());
+    for colour in colours(9973).skip(1) {
+        assert_eq!(colour.as_u32(), colour.to_discord_visible());
+    }
+    assert_eq!(0xffffff, Colour::WHITE.to_discord_visible());
+}
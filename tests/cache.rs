@@ -0,0 +1,15 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use chrom::{ConversionCache, LinearRgb};
+use common::colours;
+
+#[test]
+fn cache_matches_conversions() {
+    for colour in colours(9973) {
+        let cache = ConversionCache::new(&[colour]);
+        assert_eq!(LinearRgb(colour.to_linear()), cache.linear(0), "{colour}");
+        assert_eq!(colour.to_oklab(), cache.oklab(0), "{colour}");
+    }
+}
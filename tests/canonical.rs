@@ -0,0 +1,124 @@
+#![cfg(feature = "serde_json")]
+
+use chrom::serde::canonicalise_json;
+use serde_json::Value;
+
+const PATHS: &[&str] = &[
+    "/colors/*",
+    "/tokenColors/*/settings/foreground",
+    "/tokenColors/*/settings/background",
+    "/semanticTokenColors/*",
+    "/semanticTokenColors/*/foreground",
+];
+
+fn theme() -> Value {
+    serde_json::from_str(include_str!("data/theme.json")).unwrap()
+}
+
+#[test]
+fn canonicalises_theme() {
+    let mut theme = theme();
+    let original = theme.clone();
+    let unparsed = canonicalise_json(&mut theme, PATHS);
+
+    let colors = &theme["colors"];
+    assert_eq!("#1e1f22", colors["editor.background"]);
+    assert_eq!("#ffffff", colors["editorCursor.foreground"]);
+    assert_eq!("#ffffff", colors["editorLineNumber.activeForeground"]);
+    assert_eq!("#ffffff", colors["statusBar.foreground"]);
+    assert_eq!("#ed4245", colors["terminal.ansiRed"]);
+    assert_eq!(Value::Null, colors["activityBar.border"]);
+
+    assert_eq!("#80848e", theme["tokenColors"][0]["settings"]["foreground"]);
+    assert_eq!("italic", theme["tokenColors"][0]["settings"]["fontStyle"]);
+    assert_eq!("#eb459e", theme["tokenColors"][1]["settings"]["foreground"]);
+    assert_eq!(None, theme["tokenColors"][3]["settings"].get("foreground"));
+    assert_eq!("#fee75c", theme["tokenColors"][4]["settings"]["foreground"]);
+
+    assert_eq!("#3498db", theme["semanticTokenColors"]["variable.readonly"]);
+    assert_eq!(
+        "#dbdee1",
+        theme["semanticTokenColors"]["parameter"]["foreground"]
+    );
+    assert_eq!(
+        "#5865f2",
+        theme["semanticTokenColors"]["type"]["foreground"]
+    );
+
+    // Colours with alpha, typos and objects which aren't colours are reported
+    // and left as they were
+    assert_eq!(
+        vec![
+            "/colors/activityBar.activeBorder",
+            "/colors/editor.selectionBackground",
+            "/colors/errorForeground",
+            "/tokenColors/4/settings/background",
+            "/semanticTokenColors/parameter",
+            "/semanticTokenColors/type",
+        ],
+        unparsed
+    );
+    // The semantic token objects have their foregrounds rewritten by another
+    // path
+    for pointer in &unparsed[..4] {
+        assert_eq!(
+            original.pointer(pointer),
+            theme.pointer(pointer),
+            "{pointer}"
+        );
+    }
+}
+
+#[test]
+fn canonicalising_is_idempotent() {
+    let mut once = theme();
+    let unparsed = canonicalise_json(&mut once, PATHS);
+    let mut twice = once.clone();
+    assert_eq!(unparsed, canonicalise_json(&mut twice, PATHS));
+    assert_eq!(once, twice);
+}
+
+#[test]
+fn equivalent_themes_are_equal() {
+    let mut theme = theme();
+    canonicalise_json(&mut theme, PATHS);
+
+    let mut rewritten = theme.clone();
+    rewritten["colors"]["statusBar.foreground"] = "#FFF".into();
+    rewritten["colors"]["editor.background"] = 0x1e1f22.into();
+    rewritten["colors"]["terminal.ansiRed"] = serde_json::json!({ "r": 237, "g": 66, "b": 69 });
+    rewritten["tokenColors"][2]["settings"]["foreground"] = "#57F287".into();
+    canonicalise_json(&mut rewritten, PATHS);
+    assert_eq!(theme, rewritten);
+}
+
+#[test]
+fn missing_paths_are_skipped() {
+    let mut theme = theme();
+    let original = theme.clone();
+    let unparsed = canonicalise_json(
+        &mut theme,
+        &[
+            "/missing",
+            "/colors/missing",
+            "/tokenColors/9/settings/foreground",
+            "/name/*",
+        ],
+    );
+    assert!(unparsed.is_empty());
+    assert_eq!(original, theme);
+}
+
+#[test]
+fn escaped_keys() {
+    let mut value = serde_json::json!({ "a/b": { "~c": "#ABC" }, "": "#DEF" });
+    assert!(canonicalise_json(&mut value, &["/a~1b/~0c", "/"]).is_empty());
+    assert_eq!(
+        serde_json::json!({ "a/b": { "~c": "#aabbcc" }, "": "#ddeeff" }),
+        value
+    );
+
+    let mut value = serde_json::json!("#ABC");
+    assert!(canonicalise_json(&mut value, &[""]).is_empty());
+    assert_eq!("#aabbcc", value);
+}
@@ -0,0 +1,20 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use chrom::codec;
+use common::colours;
+
+#[test]
+fn rgb24_round_trips() {
+    let colours: Vec<_> = colours(997).collect();
+    let bytes = codec::encode_rgb24(&colours);
+    assert_eq!(Ok(colours), codec::decode_rgb24(&bytes));
+}
+
+#[test]
+fn rgba32_round_trips() {
+    let colours: Vec<_> = colours(997).collect();
+    let bytes = codec::encode_rgba32(&colours, 0);
+    assert_eq!(Ok(colours), codec::decode_rgba32(&bytes));
+}
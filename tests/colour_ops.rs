@@ -0,0 +1,119 @@
+mod common;
+
+use chrom::{Colour, ColourOps, LinearRgb, Rgba, Srgb};
+use common::colours;
+use std::fmt::Debug;
+
+/// Blur each pixel with its neighbours in linear light, as a downstream image
+/// library might
+fn blur<C: ColourOps>(pixels: &[C]) -> Vec<C> {
+    (0..pixels.len())
+        .map(|i| {
+            let neighbours = &pixels[i.saturating_sub(1)..(i + 2).min(pixels.len())];
+            let mut sum = [0.0; 4];
+            for pixel in neighbours {
+                for (total, component) in sum.iter_mut().zip(pixel.to_linear_rgba()) {
+                    *total += component;
+                }
+            }
+            C::from_linear_rgba(sum.map(|total| total / neighbours.len() as f32))
+        })
+        .collect()
+}
+
+/// Check whether two colours are the same to within rounding
+fn close<C: ColourOps>(a: C, b: C) -> bool {
+    a.to_linear_rgba()
+        .into_iter()
+        .zip(b.to_linear_rgba())
+        .all(|(a, b)| (a - b).abs() < 1e-2)
+}
+
+/// Check the behaviour every implementation should share
+fn check_ops<C: ColourOps + PartialEq + Debug>(from_colour: impl Fn(Colour) -> C) {
+    for colour in colours(4999) {
+        let c = from_colour(colour);
+        let [.., alpha] = c.to_linear_rgba();
+        assert_eq!(1.0, alpha, "{colour}");
+        assert_eq!(c, C::from_linear_rgba(c.to_linear_rgba()), "{colour}");
+        assert_eq!(c, c.map_channels(|channel| channel), "{colour}");
+        assert!(
+            (colour.relative_luminance() - c.luminance()).abs() < 1e-6,
+            "{colour}"
+        );
+
+        let other = from_colour(Colour::new(colour.as_u32() ^ 0xffffff));
+        assert!(close(c, c.lerp(other, 0.0)), "{colour}");
+        assert!(close(other, c.lerp(other, 1.0)), "{colour}");
+        assert!(
+            blur(&[c, c, c]).into_iter().all(|pixel| close(c, pixel)),
+            "{colour}"
+        );
+    }
+
+    let black = from_colour(Colour::BLACK);
+    let white = from_colour(Colour::WHITE);
+    assert_eq!(white, black.map_channels(|channel| 1.0 - channel));
+    assert_eq!(vec![black; 4], blur(&[black; 4]));
+    assert!(black.luminance() < white.luminance());
+}
+
+#[test]
+fn colour_ops() {
+    check_ops(|colour| colour);
+}
+
+#[test]
+fn srgb_ops() {
+    check_ops(Srgb);
+}
+
+#[test]
+fn linear_rgb_ops() {
+    check_ops(|colour| Srgb(colour).decode());
+}
+
+#[test]
+fn rgba_ops() {
+    check_ops(Rgba::from);
+}
+
+#[test]
+fn rgba_lerp_is_premultiplied() {
+    for colour in colours(4999) {
+        let faded = colour.with_alpha(0xff).lerp(Rgba::TRANSPARENT, 0.25);
+        assert_eq!(colour.with_alpha(0xbf), faded, "{colour}");
+        let other = Colour::new(colour.as_u32() ^ 0xffffff).with_alpha(0x40);
+        let mixed = colour.with_alpha(0xc0).lerp(other, 0.5);
+        assert_eq!(0x80, mixed.alpha, "{colour}");
+        assert_eq!(colour.mix(other.colour, 0.25), mixed.colour, "{colour}");
+    }
+}
+
+#[test]
+fn blur_is_the_same_in_any_type() {
+    let pixels: Vec<_> = colours(4999).take(64).collect();
+    let linear: Vec<_> = pixels.iter().map(|&colour| Srgb(colour).decode()).collect();
+    let blurred: Vec<_> = blur(&linear)
+        .into_iter()
+        .map(|colour| colour.encode().0)
+        .collect();
+    assert_eq!(blurred, blur(&pixels));
+}
+
+#[test]
+fn map_channels_stays_in_range() {
+    for colour in colours(4999) {
+        let brighter = colour.map_channels(|channel| channel * 2.0);
+        assert!(brighter.red() >= colour.red(), "{colour}");
+        assert_eq!(
+            Colour::BLACK,
+            colour.map_channels(|channel| channel - 2.0),
+            "{colour}"
+        );
+    }
+    assert_eq!(
+        LinearRgb([-1.0, 0.0, 2.0]),
+        LinearRgb([-0.5, 0.0, 1.0]).map_channels(|channel| channel * 2.0)
+    );
+}
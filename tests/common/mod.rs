@@ -0,0 +1,26 @@
+//! Colours for the tests to sweep over
+
+#![allow(dead_code)]
+
+use chrom::Colour;
+
+/// Every `step`th colour, spread over the whole RGB cube
+pub fn colours(step: usize) -> impl Iterator<Item = Colour> + Clone {
+    (0..0x1000000).step_by(step).map(Colour::new)
+}
+
+/// Pairs of colours from [`colours`], each with the colour made by reversing
+/// its bits, so that both sides of a pair cover the cube
+pub fn pairs(step: usize) -> impl Iterator<Item = (Colour, Colour)> + Clone {
+    colours(step).map(|colour| {
+        let reversed = colour.as_u32().reverse_bits() >> 8;
+        (colour, Colour::new(reversed))
+    })
+}
+
+/// The largest difference between the channels of two colours
+pub fn channel_difference(a: Colour, b: Colour) -> u8 {
+    let red = a.red().abs_diff(b.red());
+    let green = a.green().abs_diff(b.green());
+    red.max(green).max(a.blue().abs_diff(b.blue()))
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use chrom::Colour;
+use common::colours;
+
+/// The worst delta E after a round trip, as documented, and the colour it
+/// happens for
+const WORST_RGB332: (f32, u32) = (34.3, 0x36132a);
+const WORST_RGB444: (f32, u32) = (16.4, 0x2a1a19);
+
+fn worst(round_trip: impl Fn(Colour) -> Colour) -> f32 {
+    colours(97)
+        .map(|colour| colour.delta_e(round_trip(colour)))
+        .fold(0.0, f32::max)
+}
+
+#[test]
+fn rgb332_worst_delta_e() {
+    let round_trip = |colour: Colour| Colour::from_rgb332(colour.to_rgb332());
+    assert!(worst(round_trip) <= WORST_RGB332.0);
+    let colour = Colour::new(WORST_RGB332.1);
+    assert!((colour.delta_e(round_trip(colour)) - WORST_RGB332.0).abs() < 0.05);
+}
+
+#[test]
+fn rgb444_worst_delta_e() {
+    let round_trip = |colour: Colour| Colour::from_rgb444(colour.to_rgb444());
+    assert!(worst(round_trip) <= WORST_RGB444.0);
+    let colour = Colour::new(WORST_RGB444.1);
+    assert!((colour.delta_e(round_trip(colour)) - WORST_RGB444.0).abs() < 0.05);
+}
+
+#[test]
+fn channels_round_to_nearest_level() {
+    // Every level a tier can decode to, for each number of bits
+    let levels = |bits: u32, decode: &dyn Fn(u8) -> u8| -> Vec<u8> {
+        (0..1 << bits).map(|value| decode(value as u8)).collect()
+    };
+    let red3 = levels(3, &|value| Colour::from_rgb332(value << 5).red());
+    let blue2 = levels(2, &|value| Colour::from_rgb332(value).blue());
+    let red4 = levels(4, &|value| Colour::from_rgb444(u16::from(value) << 8).red());
+    assert_eq!(vec![0, 36, 73, 109, 146, 182, 219, 255], red3);
+    assert_eq!(vec![0, 85, 170, 255], blue2);
+    assert_eq!((0..=255).step_by(17).collect::<Vec<u8>>(), red4);
+
+    for channel in 0..=255u8 {
+        let nearest = |levels: &[u8]| {
+            levels
+                .iter()
+                .map(|&level| level.abs_diff(channel))
+                .min()
+                .unwrap()
+        };
+        let colour = Colour::from_rgb(channel, channel, channel);
+        let rgb332 = Colour::from_rgb332(colour.to_rgb332());
+        let rgb444 = Colour::from_rgb444(colour.to_rgb444());
+        assert_eq!(nearest(&red3), rgb332.red().abs_diff(channel), "{channel}");
+        assert_eq!(
+            nearest(&blue2),
+            rgb332.blue().abs_diff(channel),
+            "{channel}"
+        );
+        assert_eq!(nearest(&red4), rgb444.red().abs_diff(channel), "{channel}");
+    }
+}
+
+#[test]
+fn black_and_white_round_trip() {
+    for colour in [Colour::BLACK, Colour::WHITE] {
+        assert_eq!(colour, Colour::from_rgb332(colour.to_rgb332()));
+        assert_eq!(colour, Colour::from_rgb444(colour.to_rgb444()));
+        let palette = [Colour::RED, colour];
+        let index = colour.to_palette_index(&palette);
+        assert_eq!(Some(colour), Colour::from_palette_index(index, &palette));
+    }
+}
+
+#[test]
+fn palette_index_is_nearest() {
+    let palette: Vec<_> = (0..=255).map(Colour::from_rgb332).collect();
+    for colour in colours(97).step_by(101) {
+        let index = colour.to_palette_index(&palette);
+        let decoded = Colour::from_palette_index(index, &palette).unwrap();
+        let distance = colour.delta_e(decoded);
+        assert!(
+            palette
+                .iter()
+                .all(|&other| distance <= colour.delta_e(other)),
+            "{colour}"
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "palette must have 1 to 256 colours")]
+fn empty_palette() {
+    Colour::RED.to_palette_index(&[]);
+}
+
+#[test]
+#[should_panic(expected = "palette must have 1 to 256 colours")]
+fn palette_too_large() {
+    Colour::RED.to_palette_index(&[Colour::BLACK; 257]);
+}
+
+#[test]
+fn packed_values_round_trip() {
+    for value in 0..=u8::MAX {
+        assert_eq!(value, Colour::from_rgb332(value).to_rgb332());
+    }
+    for value in 0..=0xfff {
+        assert_eq!(value, Colour::from_rgb444(value).to_rgb444());
+    }
+}
+
+#[test]
+fn palette_colours_are_their_own_index() {
+    let palette = [Colour::BLACK, Colour::WHITE, Colour::RED, Colour::BLUE];
+    for colour in palette {
+        let index = colour.to_palette_index(&palette);
+        assert_eq!(Some(colour), Colour::from_palette_index(index, &palette));
+    }
+}
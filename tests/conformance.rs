@@ -0,0 +1,337 @@
+//! Pinned outputs of every conversion and adjustment, see
+//! `chrom::CONFORMANCE_VERSION`
+//!
+//! Each line of the table is the output colours of an operation, then the
+//! operation and its arguments, separated by tabs. The same table holds with
+//! and without the `precise` feature.
+//!
+//! After an intentional change, bump `CONFORMANCE_VERSION` and regenerate the
+//! table with:
+//!
+//! ```text
+//! cargo test --test conformance -- --ignored
+//! ```
+
+#![cfg(feature = "alloc")]
+
+use chrom::{
+    breathe, diverging_palette, pulse_between, rainbow_cycle, sequential_palette, Colour, Curve,
+    Gradient, WhitePoint, CONFORMANCE_VERSION,
+};
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+const SAMPLES: [Colour; 10] = [
+    Colour::BLACK,
+    Colour::WHITE,
+    Colour::new(0x808080),
+    Colour::BLUE,
+    Colour::RED,
+    Colour::GOLD,
+    Colour::DARK_GREEN,
+    Colour::DARK_NAVY,
+    Colour::new(0x010203),
+    Colour::new(0xfefdfc),
+];
+
+fn table_path() -> PathBuf {
+    [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "data",
+        "conformance.tsv",
+    ]
+    .iter()
+    .collect()
+}
+
+/// Every operation and its arguments which is pinned
+fn cases() -> Vec<Vec<String>> {
+    let mut cases = Vec::new();
+    let mut case = |operation: &str, arguments: &[&dyn ToString]| {
+        let mut case = vec![operation.to_owned()];
+        case.extend(arguments.iter().map(|argument| argument.to_string()));
+        cases.push(case);
+    };
+
+    for (i, colour) in SAMPLES.into_iter().enumerate() {
+        let other = SAMPLES[(i + 3) % SAMPLES.len()];
+        let c = &colour;
+        for operation in [
+            "invert",
+            "only_red",
+            "only_green",
+            "only_blue",
+            "on_colour",
+            "hsl",
+            "oklab",
+            "oklch",
+            "lab",
+            "xyz",
+            "linear",
+            "hsluv",
+            "display_p3",
+            "luma_chroma",
+            "sv_plane",
+            "rgb_f64",
+            "linear_f64",
+            "xyz_f64",
+        ] {
+            case(operation, &[c]);
+        }
+        case("lighten", &[c, &0.1]);
+        case("darken", &[c, &0.25]);
+        case("saturate", &[c, &0.2]);
+        case("desaturate", &[c, &0.3]);
+        case("rotate_hue", &[c, &30.0]);
+        case("rotate_hue", &[c, &-100.0]);
+        case("const_lighten_permille", &[c, &150]);
+        case("const_darken_permille", &[c, &150]);
+        case("step_hue", &[c, &10.0]);
+        case("step_saturation", &[c, &-0.05]);
+        case("step_lightness", &[c, &0.05]);
+        case("brighten_by", &[c, &20]);
+        case("dim_by", &[c, &20]);
+        case("wrapping_brighten_by", &[c, &100]);
+        case("wrapping_dim_by", &[c, &100]);
+        case("normalise_lightness", &[c, &0.6]);
+        case("with_luma", &[c, &0.5]);
+        case("adapt_white_point", &[c, &"D65", &"D50"]);
+        case("adapt_white_point", &[c, &"D50", &"A"]);
+        case("map_curve", &[c]);
+        case("shades", &[c, &3]);
+        case("elevate", &[c, &Colour::WHITE, &0.05, &0.11]);
+        case("breathe", &[c, &0.7, &2.0]);
+        case("mix", &[c, &other, &0.3]);
+        case("const_mix", &[c, &other, &300]);
+        case("overlay", &[c, &other, &0.12]);
+        case("abs_diff", &[c, &other]);
+        case("ensure_contrast", &[c, &other, &4.5]);
+        case("with_chroma_of", &[c, &other]);
+        case("pulse_between", &[c, &other, &0.3, &1.0]);
+        case("average", &[c, &other]);
+    }
+
+    for hue in [0.0, 75.0, 200.0, 330.0] {
+        case("from_hsl", &[&hue, &0.6, &0.4]);
+        case("from_sv_plane", &[&hue, &0.7, &0.2]);
+        case("from_oklch", &[&0.7, &0.12, &hue]);
+        case("from_hsluv", &[&hue, &80.0, &55.0]);
+        case("sequential_palette", &[&hue, &5]);
+        case("diverging_palette", &[&hue, &(hue + 150.0), &5]);
+    }
+    for t in [0.0, 0.2, 0.55, 1.0] {
+        case("hue_slider", &[&t]);
+        case("rainbow_cycle", &[&t, &1.0, &0.8, &0.6]);
+        case("temperature", &[&t]);
+        case("traffic_light", &[&t]);
+    }
+    for [a, b, c] in [[0.5, 0.1, -0.1], [0.8, -0.05, 0.05], [0.3, 0.0, 0.2]] {
+        case("from_oklab", &[&a, &b, &c]);
+        case("from_lab", &[&(a * 100.0), &(b * 200.0), &(c * 200.0)]);
+        case("from_xyz", &[&(a / 2.0), &a, &(a / 3.0)]);
+        case("from_linear", &[&a, &(a / 2.0), &(b + c + 0.1)]);
+        case("from_display_p3", &[&a, &(a / 2.0), &(b + c + 0.1)]);
+        case("from_luma_chroma", &[&a, &b, &c]);
+    }
+    for s in [
+        "#3498db",
+        "#abc",
+        "color(srgb 0.2 0.4 0.6)",
+        "color(display-p3 0.2 0.4 0.6)",
+        "color(srgb-linear 0.2 0.4 0.6)",
+        "color(xyz-d65 0.2 0.4 0.6)",
+    ] {
+        case("parse", &[&s]);
+    }
+    cases
+}
+
+fn evaluate(case: &[String]) -> Vec<Colour> {
+    let colour = |i: usize| -> Colour { case[i].parse().unwrap() };
+    let float = |i: usize| -> f32 { case[i].parse().unwrap() };
+    let integer = |i: usize| -> u16 { case[i].parse().unwrap() };
+    let white_point = |i: usize| match case[i].as_str() {
+        "D50" => WhitePoint::D50,
+        "D65" => WhitePoint::D65,
+        "D75" => WhitePoint::D75,
+        "A" => WhitePoint::A,
+        "E" => WhitePoint::E,
+        name => panic!("unknown white point {name}"),
+    };
+    let one = |colour: Colour| vec![colour];
+
+    match case[0].as_str() {
+        "invert" => one(colour(1).invert()),
+        "only_red" => one(colour(1).only_red()),
+        "only_green" => one(colour(1).only_green()),
+        "only_blue" => one(colour(1).only_blue()),
+        "on_colour" => one(colour(1).on_colour()),
+        "hsl" => one(colour(1).to_hsl().into()),
+        "oklab" => one(Colour::from_oklab(colour(1).to_oklab())),
+        "oklch" => one(Colour::from_oklch(colour(1).to_oklch())),
+        "lab" => one(Colour::from_lab(colour(1).to_lab())),
+        "xyz" => one(Colour::from_xyz(colour(1).to_xyz())),
+        "linear" => one(Colour::from_linear(colour(1).to_linear())),
+        "hsluv" => {
+            let [hue, saturation, lightness] = colour(1).to_hsluv();
+            one(Colour::from_hsluv(hue, saturation, lightness))
+        }
+        "display_p3" => {
+            let [red, green, blue] = colour(1).to_display_p3();
+            one(Colour::from_display_p3(red, green, blue))
+        }
+        "luma_chroma" => {
+            let (luma, chroma) = colour(1).split_luma_chroma();
+            one(Colour::from_luma_chroma(luma, chroma))
+        }
+        "sv_plane" => {
+            let (hue, x, y) = colour(1).to_sv_plane();
+            one(Colour::from_sv_plane(hue, x, y))
+        }
+        "rgb_f64" => one(Colour::from_rgb_f64(colour(1).to_rgb_f64())),
+        "linear_f64" => one(Colour::from_linear_f64(colour(1).to_linear_f64())),
+        "xyz_f64" => one(Colour::from_xyz_f64(colour(1).to_xyz_f64())),
+        "lighten" => one(colour(1).lighten(float(2))),
+        "darken" => one(colour(1).darken(float(2))),
+        "saturate" => one(colour(1).saturate(float(2))),
+        "desaturate" => one(colour(1).desaturate(float(2))),
+        "rotate_hue" => one(colour(1).rotate_hue(float(2))),
+        "const_lighten_permille" => one(colour(1).const_lighten_permille(integer(2))),
+        "const_darken_permille" => one(colour(1).const_darken_permille(integer(2))),
+        "step_hue" => one(colour(1).step_hue(float(2))),
+        "step_saturation" => one(colour(1).step_saturation(float(2))),
+        "step_lightness" => one(colour(1).step_lightness(float(2))),
+        "brighten_by" => one(colour(1).brighten_by(integer(2) as u8)),
+        "dim_by" => one(colour(1).dim_by(integer(2) as u8)),
+        "wrapping_brighten_by" => one(colour(1).wrapping_brighten_by(integer(2) as u8)),
+        "wrapping_dim_by" => one(colour(1).wrapping_dim_by(integer(2) as u8)),
+        "normalise_lightness" => one(colour(1).normalise_lightness(float(2))),
+        "with_luma" => one(colour(1).with_luma(float(2))),
+        "adapt_white_point" => one(colour(1).adapt_white_point(white_point(2), white_point(3))),
+        "map_curve" => {
+            let curve = Curve::from_points(&[(0, 16), (64, 48), (192, 208), (255, 240)]);
+            one(colour(1).map_curve(&curve))
+        }
+        "shades" => colour(1).shades(integer(2).into()),
+        "elevate" => colour(1).elevate(colour(2), &[float(3), float(4)]),
+        "breathe" => one(breathe(colour(1), float(2), float(3))),
+        "mix" => one(colour(1).mix(colour(2), float(3))),
+        "const_mix" => one(colour(1).const_mix(colour(2), integer(3))),
+        "overlay" => one(colour(1).overlay(colour(2), float(3))),
+        "abs_diff" => one(colour(1).abs_diff(colour(2))),
+        "ensure_contrast" => one(colour(1).ensure_contrast(colour(2), float(3))),
+        "with_chroma_of" => one(colour(1).with_chroma_of(colour(2))),
+        "pulse_between" => one(pulse_between(colour(1), colour(2), float(3), float(4))),
+        "average" => one(Colour::average(&[colour(1), colour(2)]).unwrap()),
+        "from_hsl" => one(Colour::from_hsl(float(1), float(2), float(3))),
+        "from_sv_plane" => one(Colour::from_sv_plane(float(1), float(2), float(3))),
+        "from_oklch" => one(Colour::from_oklch([float(1), float(2), float(3)])),
+        "from_hsluv" => one(Colour::from_hsluv(float(1), float(2), float(3))),
+        "sequential_palette" => sequential_palette(float(1), integer(2).into()),
+        "diverging_palette" => diverging_palette(float(1), float(2), integer(3).into()),
+        "hue_slider" => one(Colour::hue_slider(float(1))),
+        "rainbow_cycle" => one(rainbow_cycle(float(1), float(2), float(3), float(4))),
+        "temperature" => one(Gradient::temperature().at(float(1))),
+        "traffic_light" => one(Gradient::traffic_light().at(float(1))),
+        "from_oklab" => one(Colour::from_oklab([float(1), float(2), float(3)])),
+        "from_lab" => one(Colour::from_lab([float(1), float(2), float(3)])),
+        "from_xyz" => one(Colour::from_xyz([float(1), float(2), float(3)])),
+        "from_linear" => one(Colour::from_linear([float(1), float(2), float(3)])),
+        "from_display_p3" => one(Colour::from_display_p3(float(1), float(2), float(3))),
+        "from_luma_chroma" => one(Colour::from_luma_chroma(float(1), [float(2), float(3)])),
+        "parse" => one(colour(1)),
+        operation => panic!("unknown operation {operation}"),
+    }
+}
+
+fn format_output(colours: &[Colour]) -> String {
+    let hex: Vec<_> = colours
+        .iter()
+        .map(|colour| format!("{:06x}", colour.as_u32()))
+        .collect();
+    hex.join(" ")
+}
+
+/// Read the table, as its version and a map from each case to its output
+fn read_table() -> (u32, BTreeMap<Vec<String>, String>) {
+    let table = fs::read_to_string(table_path()).unwrap();
+    let mut lines = table.lines();
+    let version = lines
+        .next()
+        .and_then(|line| line.strip_prefix("# version "))
+        .expect("the table starts with its version")
+        .parse()
+        .unwrap();
+
+    let rows = lines
+        .map(|line| {
+            let (output, case) = line.split_once('\t').unwrap();
+            (
+                case.split('\t').map(str::to_owned).collect(),
+                output.to_owned(),
+            )
+        })
+        .collect();
+    (version, rows)
+}
+
+#[test]
+fn outputs_match_table() {
+    let (version, rows) = read_table();
+    assert_eq!(
+        CONFORMANCE_VERSION, version,
+        "the table is for a different version, regenerate it",
+    );
+
+    let mut changed = Vec::new();
+    for case in cases() {
+        let expected = rows
+            .get(&case)
+            .unwrap_or_else(|| panic!("{case:?} isn't in the table, regenerate it"));
+        let actual = format_output(&evaluate(&case));
+        if &actual != expected {
+            changed.push(format!("{}: {expected} -> {actual}", case.join(" ")));
+        }
+    }
+    assert!(
+        changed.is_empty(),
+        "pinned outputs changed, bump CONFORMANCE_VERSION and regenerate if this is intended:\n{}",
+        changed.join("\n"),
+    );
+    assert_eq!(
+        cases().len(),
+        rows.len(),
+        "the table has extra rows, regenerate it"
+    );
+}
+
+#[test]
+fn table_is_large_enough() {
+    assert!(cases().len() >= 300);
+}
+
+#[test]
+#[ignore = "writes the table, run after bumping CONFORMANCE_VERSION"]
+fn regenerate_table() {
+    // Keep outputs from changing under the same version
+    if let Ok(table) = fs::read_to_string(table_path()) {
+        if table.starts_with(&format!("# version {CONFORMANCE_VERSION}\n")) {
+            let (_, rows) = read_table();
+            for case in cases() {
+                if let Some(expected) = rows.get(&case) {
+                    assert_eq!(
+                        expected,
+                        &format_output(&evaluate(&case)),
+                        "{case:?} changed without bumping CONFORMANCE_VERSION",
+                    );
+                }
+            }
+        }
+    }
+
+    let mut table = format!("# version {CONFORMANCE_VERSION}\n");
+    for case in cases() {
+        table += &format!("{}\t{}\n", format_output(&evaluate(&case)), case.join("\t"));
+    }
+    fs::write(table_path(), table).unwrap();
+}
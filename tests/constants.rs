@@ -0,0 +1,66 @@
+use chrom::Colour;
+use std::collections::HashSet;
+
+#[test]
+fn tables_have_every_constant() {
+    assert_eq!(22, Colour::ALL_CONSTANTS.len());
+    assert_eq!(4, Colour::DISCORD_CONSTANTS.len());
+    assert_eq!(Colour::ALL_CONSTANTS.len(), Colour::constants().count());
+}
+
+#[test]
+fn names_are_unique() {
+    let tables = [Colour::ALL_CONSTANTS, Colour::DISCORD_CONSTANTS];
+    let names: HashSet<_> = tables
+        .iter()
+        .flat_map(|table| table.iter().map(|(name, _)| name))
+        .collect();
+    assert_eq!(26, names.len());
+}
+
+#[test]
+fn order_is_stable() {
+    let names: Vec<_> = Colour::constants().map(|(name, _)| name).collect();
+    assert_eq!(
+        [
+            "WHITE",
+            "BLACK",
+            "AQUA",
+            "GREEN",
+            "BLUE",
+            "YELLOW",
+            "PURPLE",
+            "GOLD",
+            "ORANGE",
+            "RED",
+            "GREY",
+            "NAVY",
+            "DARK_AQUA",
+            "DARK_GREEN",
+            "DARK_BLUE",
+            "DARK_PURPLE",
+            "DARK_GOLD",
+            "DARK_ORANGE",
+            "DARK_RED",
+            "DARK_GREY",
+            "DARK_NAVY",
+            "LIGHT_GREY",
+        ],
+        names[..],
+    );
+}
+
+#[test]
+fn values_match_constants() {
+    let table: Vec<_> = Colour::constants().collect();
+    assert!(table.contains(&("BLUE", Colour::new(0x3498db))));
+    assert!(table.contains(&("DARK_RED", Colour::new(0x992d22))));
+    assert!(Colour::DISCORD_CONSTANTS.contains(&("DISCORD_DARK_EMBED", Colour::new(0x2b2d31))));
+}
+
+#[test]
+fn constants_are_their_own_nearest() {
+    for (name, colour) in Colour::constants() {
+        assert_eq!((name, colour), colour.nearest_constant());
+    }
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use chrom::{Colour, ContrastLevel};
+use common::colours;
+
+#[test]
+fn light_or_dark() {
+    for colour in colours(9973) {
+        assert_ne!(colour.is_light(), colour.is_dark(), "{colour}");
+    }
+}
+
+#[test]
+fn best_text_colour_meets_aa() {
+    for colour in colours(9973) {
+        let text = colour.best_text_colour();
+        assert!(text.meets(ContrastLevel::Aa, colour), "{colour}");
+    }
+}
+
+#[test]
+fn on_colour_meets_aa() {
+    for (name, colour) in Colour::constants() {
+        let (container, on_container) = colour.colour_pair();
+        assert!(on_container.contrast_ratio(container) >= 4.5, "{name}");
+    }
+    for colour in colours(9973) {
+        assert!(colour.on_colour().contrast_ratio(colour) >= 4.5, "{colour}");
+    }
+}
+
+#[test]
+fn ensure_contrast_reaches_aa() {
+    for background in colours(99991) {
+        for colour in [Colour::BLUE, Colour::GREY, Colour::GOLD, background] {
+            let text = colour.ensure_contrast(background, 4.5);
+            assert!(
+                text.contrast_ratio(background) >= 4.5,
+                "{colour} on {background}"
+            );
+        }
+    }
+}
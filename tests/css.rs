@@ -0,0 +1,40 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use chrom::CssColourSpace;
+use common::colours;
+
+#[test]
+fn displays_match_strings() {
+    for colour in colours(997) {
+        let mut hex = String::new();
+        colour.write_hex(&mut hex).unwrap();
+        assert_eq!(colour.to_css_hex(), hex);
+        assert_eq!(colour.to_css_hex(), colour.hex_display().to_string());
+        assert_eq!(colour.to_css_hex(), colour.to_string());
+        assert_eq!(colour.to_css_hex()[1..], colour.hex());
+
+        let mut rgb = String::new();
+        colour.write_css_rgb(&mut rgb).unwrap();
+        assert_eq!(colour.to_css_rgb(), rgb);
+        assert_eq!(colour.to_css_rgb(), colour.css_rgb_display().to_string());
+
+        let mut ansi = String::new();
+        colour.write_ansi_fg(&mut ansi).unwrap();
+        assert_eq!(ansi, colour.ansi_fg_display().to_string());
+        let mut ansi = String::new();
+        colour.write_ansi_bg(&mut ansi).unwrap();
+        assert_eq!(ansi, colour.ansi_bg_display().to_string());
+    }
+}
+
+#[test]
+fn css_color_round_trips() {
+    for space in CssColourSpace::ALL {
+        for colour in colours(997) {
+            let css = colour.to_css_color(space);
+            assert_eq!(Ok(colour), css.parse(), "{space} {colour}");
+        }
+    }
+}
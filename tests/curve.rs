@@ -0,0 +1,13 @@
+mod common;
+
+use chrom::Curve;
+use common::colours;
+
+#[test]
+fn identity_and_invert() {
+    let invert = Curve::from_points(&[(0, 255), (255, 0)]);
+    for colour in colours(9973) {
+        assert_eq!(colour.invert(), colour.map_curve(&invert), "{colour}");
+        assert_eq!(colour, colour.map_curve(&Curve::identity()), "{colour}");
+    }
+}
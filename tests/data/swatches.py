@@ -0,0 +1,39 @@
+"""Write swatches.aco, following the Color Swatches section of Adobe's
+Photoshop File Formats Specification rather than chrom's own writer.
+
+A version 1 section lists each colour as a colour space and four channels,
+then a version 2 section repeats them, each followed by its name as a 32-bit
+count of UTF-16 code units, including the terminating null, and the code
+units. Every value is big endian, and RGB (colour space 0) channels are
+scaled from 0-255 to 0-65535.
+
+Run from this directory with `python3 swatches.py`.
+"""
+
+import struct
+
+RGB = 0
+SWATCHES = [
+    ("RGB Red", (255, 0, 0)),
+    ("RGB Yellow", (255, 255, 0)),
+    ("Brand Blue", (0x34, 0x98, 0xDB)),
+    ("50% Gray \N{ARTIST PALETTE}", (128, 128, 128)),
+]
+
+
+def colour(rgb):
+    return struct.pack(">5H", RGB, *(channel * 65535 // 255 for channel in rgb), 0)
+
+
+def name(text):
+    units = (text + "\0").encode("utf-16-be")
+    return struct.pack(">I", len(units) // 2) + units
+
+
+data = struct.pack(">2H", 1, len(SWATCHES))
+data += b"".join(colour(rgb) for _, rgb in SWATCHES)
+data += struct.pack(">2H", 2, len(SWATCHES))
+data += b"".join(colour(rgb) + name(text) for text, rgb in SWATCHES)
+
+with open("swatches.aco", "wb") as file:
+    file.write(data)
@@ -0,0 +1,19 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use chrom::Distance;
+use common::colours;
+
+#[test]
+fn zero_tolerance_is_unique_exact() {
+    // Every colour is repeated at least twice
+    let colours: Vec<_> = colours(9973).take(700).cycle().take(2000).collect();
+    for distance in [Distance::DeltaE, Distance::Rgb] {
+        let (mut a, mut b) = (colours.clone(), colours.clone());
+        chrom::dedup(&mut a, 0.0, distance);
+        chrom::unique_exact(&mut b);
+        assert_eq!(700, a.len());
+        assert_eq!(b, a);
+    }
+}
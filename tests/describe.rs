@@ -0,0 +1,146 @@
+use chrom::{BasicColour, Colour};
+
+#[test]
+fn representatives_classify_as_themselves() {
+    use BasicColour::*;
+    for basic in [
+        Black, White, Grey, Red, Orange, Yellow, Green, Blue, Purple, Pink, Brown,
+    ] {
+        assert_eq!(basic, basic.representative().classify());
+    }
+}
+
+#[test]
+fn classify() {
+    let constants = [
+        (Colour::WHITE, BasicColour::White),
+        (Colour::BLACK, BasicColour::Black),
+        (Colour::AQUA, BasicColour::Green),
+        (Colour::GREEN, BasicColour::Green),
+        (Colour::BLUE, BasicColour::Blue),
+        (Colour::YELLOW, BasicColour::Yellow),
+        (Colour::PURPLE, BasicColour::Purple),
+        (Colour::GOLD, BasicColour::Yellow),
+        (Colour::ORANGE, BasicColour::Orange),
+        (Colour::RED, BasicColour::Red),
+        (Colour::GREY, BasicColour::Grey),
+        (Colour::NAVY, BasicColour::Blue),
+        (Colour::DARK_AQUA, BasicColour::Green),
+        (Colour::DARK_GREEN, BasicColour::Green),
+        (Colour::DARK_BLUE, BasicColour::Blue),
+        (Colour::DARK_PURPLE, BasicColour::Purple),
+        (Colour::DARK_GOLD, BasicColour::Brown),
+        (Colour::DARK_ORANGE, BasicColour::Orange),
+        (Colour::DARK_RED, BasicColour::Red),
+        (Colour::DARK_GREY, BasicColour::Grey),
+        (Colour::DARK_NAVY, BasicColour::Blue),
+        (Colour::LIGHT_GREY, BasicColour::Grey),
+    ];
+    for (colour, basic) in constants {
+        assert_eq!(basic, colour.classify(), "{colour}");
+    }
+    let tricky = [
+        // Browns against oranges and dark yellows
+        (0x8b4513, BasicColour::Brown),
+        (0xa0522d, BasicColour::Brown),
+        (0xcd853f, BasicColour::Brown),
+        (0xd2b48c, BasicColour::Brown),
+        (0x6f4e37, BasicColour::Brown),
+        (0xb8860b, BasicColour::Brown),
+        (0x808000, BasicColour::Brown),
+        (0xd2691e, BasicColour::Orange),
+        (0xff8c00, BasicColour::Orange),
+        (0xff7f50, BasicColour::Orange),
+        (0xffd700, BasicColour::Yellow),
+        (0xf0e68c, BasicColour::Yellow),
+        (0xbdb76b, BasicColour::Yellow),
+        // Reds against pinks and purples
+        (0x800000, BasicColour::Red),
+        (0xa52a2a, BasicColour::Red),
+        (0xdc143c, BasicColour::Red),
+        (0xfa8072, BasicColour::Pink),
+        (0xffc0cb, BasicColour::Pink),
+        (0xff1493, BasicColour::Pink),
+        (0xff00ff, BasicColour::Pink),
+        (0xc71585, BasicColour::Purple),
+        (0x4b0082, BasicColour::Purple),
+        (0x8a2be2, BasicColour::Purple),
+        // Greens against blues
+        (0x9acd32, BasicColour::Green),
+        (0x40e0d0, BasicColour::Blue),
+        (0x008080, BasicColour::Blue),
+        (0x87ceeb, BasicColour::Blue),
+        (0x000080, BasicColour::Blue),
+        // Near greys, blacks and whites
+        (0x708090, BasicColour::Grey),
+        (0x0a0a12, BasicColour::Black),
+        (0x1a1a1a, BasicColour::Black),
+        (0xf5f5f5, BasicColour::White),
+        (0xfffff0, BasicColour::White),
+        (0xe6e6fa, BasicColour::White),
+    ];
+    for (value, basic) in tricky {
+        assert_eq!(basic, Colour::new(value).classify(), "{value:06x}");
+    }
+}
+
+#[test]
+fn describe_constants() {
+    let constants = [
+        (Colour::WHITE, "white"),
+        (Colour::BLACK, "black"),
+        (Colour::AQUA, "vivid cyan"),
+        (Colour::GREEN, "vivid spring green"),
+        (Colour::BLUE, "vivid azure"),
+        (Colour::YELLOW, "vivid yellow"),
+        (Colour::PURPLE, "desaturated violet"),
+        (Colour::GOLD, "vivid yellow"),
+        (Colour::ORANGE, "vivid orange"),
+        (Colour::RED, "vivid red"),
+        (Colour::GREY, "grey"),
+        (Colour::NAVY, "dark greyish azure"),
+        (Colour::DARK_AQUA, "dark vivid cyan"),
+        (Colour::DARK_GREEN, "dark vivid spring green"),
+        (Colour::DARK_BLUE, "dark vivid azure"),
+        (Colour::DARK_PURPLE, "dark desaturated violet"),
+        (Colour::DARK_GOLD, "vivid orange"),
+        (Colour::DARK_ORANGE, "dark vivid orange"),
+        (Colour::DARK_RED, "dark vivid red"),
+        (Colour::DARK_GREY, "grey"),
+        (Colour::DARK_NAVY, "dark greyish azure"),
+        (Colour::LIGHT_GREY, "light grey"),
+    ];
+    for (colour, description) in constants {
+        assert_eq!(description, colour.description().to_string(), "{colour}");
+    }
+}
+
+#[test]
+fn constant_emoji() {
+    for (colour, emoji) in [
+        (Colour::WHITE, '⬜'),
+        (Colour::BLACK, '⬛'),
+        (Colour::AQUA, '🟩'),
+        (Colour::GREEN, '🟩'),
+        (Colour::BLUE, '🟦'),
+        (Colour::YELLOW, '🟨'),
+        (Colour::PURPLE, '🟪'),
+        (Colour::GOLD, '🟨'),
+        (Colour::ORANGE, '🟧'),
+        (Colour::RED, '🟥'),
+        (Colour::GREY, '⬜'),
+        (Colour::NAVY, '⬛'),
+        (Colour::DARK_AQUA, '🟩'),
+        (Colour::DARK_GREEN, '🟩'),
+        (Colour::DARK_BLUE, '🟦'),
+        (Colour::DARK_PURPLE, '🟪'),
+        (Colour::DARK_GOLD, '🟧'),
+        (Colour::DARK_ORANGE, '🟫'),
+        (Colour::DARK_RED, '🟥'),
+        (Colour::DARK_GREY, '⬜'),
+        (Colour::DARK_NAVY, '⬛'),
+        (Colour::LIGHT_GREY, '⬜'),
+    ] {
+        assert_eq!(emoji, colour.to_colour_emoji(), "{colour}");
+    }
+}
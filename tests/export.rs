@@ -0,0 +1,103 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use chrom::{export, Colour};
+use common::colours;
+use export::ParseAcoError;
+
+/// Laid out the way Photoshop saves swatches: a version 1 section, then a
+/// version 2 section repeating the colours with names. It's written by
+/// `data/swatches.py` from Adobe's specification, independently of
+/// [`export::to_aco`], and one name needs a UTF-16 surrogate pair.
+const SWATCHES: &[u8] = include_bytes!("data/swatches.aco");
+
+#[test]
+fn gpl_round_trip() {
+    let names: Vec<_> = colours(9973).map(|colour| colour.to_string()).collect();
+    let palette: Vec<_> = colours(9973)
+        .zip(&names)
+        .enumerate()
+        .map(|(i, (colour, name))| (colour, (i % 3 != 0).then_some(name.as_str())))
+        .collect();
+
+    let parsed = export::from_gpl(&export::to_gpl(&palette, "Round trip")).unwrap();
+    assert_eq!(Some("Round trip"), parsed.name.as_deref());
+    let parsed: Vec<_> = parsed
+        .colours
+        .iter()
+        .map(|(colour, name)| (*colour, name.as_deref()))
+        .collect();
+    assert_eq!(palette, parsed);
+}
+
+#[test]
+fn gpl_names_stay_on_one_line() {
+    let gpl = export::to_gpl(&[(Colour::RED, Some("two\nlines"))], "also\r\ntwo");
+    let parsed = export::from_gpl(&gpl).unwrap();
+    assert_eq!(Some("also  two"), parsed.name.as_deref());
+    assert_eq!(
+        vec![(Colour::RED, Some("two lines".to_owned()))],
+        parsed.colours
+    );
+}
+
+#[test]
+fn gpl_from_gimp() {
+    let gpl = "GIMP Palette\r\nName: Default\r\nColumns: 3\r\n#\r\n# Comment\r\n\r\n255 255 255\tWhite\r\n  0   0   0 Black  \r\n";
+    let parsed = export::from_gpl(gpl).unwrap();
+    assert_eq!(Some("Default"), parsed.name.as_deref());
+    assert_eq!(
+        vec![
+            (Colour::WHITE, Some("White".to_owned())),
+            (Colour::BLACK, Some("Black".to_owned())),
+        ],
+        parsed.colours,
+    );
+}
+
+#[test]
+fn aco_round_trip() {
+    let palette: Vec<_> = colours(9973).collect();
+    assert_eq!(
+        Ok(palette.clone()),
+        export::from_aco(&export::to_aco(&palette))
+    );
+    assert_eq!(Ok(vec![]), export::from_aco(&export::to_aco(&[])));
+}
+
+#[test]
+fn aco_fixture() {
+    let expected = vec![
+        Colour::from_rgb(255, 0, 0),
+        Colour::from_rgb(255, 255, 0),
+        Colour::BLUE,
+        Colour::new(0x808080),
+    ];
+    assert_eq!(Ok(expected.clone()), export::from_aco(SWATCHES));
+
+    // Just the version 2 section
+    assert_eq!(Ok(expected.clone()), export::from_aco(&SWATCHES[44..]));
+
+    // Writing the same colours gives the version 1 section
+    assert_eq!(&SWATCHES[..44], export::to_aco(&expected));
+}
+
+#[test]
+fn aco_truncated() {
+    for end in [0, 1, 3, 4, 20, 43] {
+        assert_eq!(
+            Err(ParseAcoError::Truncated),
+            export::from_aco(&SWATCHES[..end]),
+            "{end}"
+        );
+    }
+    for end in [50, 60, 100, SWATCHES.len() - 1] {
+        let version_2 = &SWATCHES[44..end];
+        assert_eq!(
+            Err(ParseAcoError::Truncated),
+            export::from_aco(version_2),
+            "{end}"
+        );
+    }
+}
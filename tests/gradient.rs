@@ -0,0 +1,17 @@
+#![cfg(feature = "alloc")]
+
+use chrom::{Colour, ColourStop, Gradient};
+
+#[test]
+fn reversed_mirrors() {
+    let stops = [
+        ColourStop::new(0.0, Colour::RED),
+        ColourStop::new(0.25, Colour::BLUE),
+    ];
+    let gradient = Gradient::from_stops(stops).unwrap();
+    let reversed = gradient.reversed();
+    for i in 0..=20 {
+        let t = i as f32 / 20.0;
+        assert_eq!(gradient.at(t), reversed.at(1.0 - t), "{t}");
+    }
+}
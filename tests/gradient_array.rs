@@ -0,0 +1,56 @@
+//! Gradients backed by arrays and slices, which need neither `std` nor
+//! `alloc`
+
+use chrom::{Colour, ColourStop, Gradient, GradientError};
+
+const STOPS: [ColourStop; 3] = [
+    ColourStop::new(0.0, Colour::BLACK),
+    ColourStop::new(0.5, Colour::BLUE),
+    ColourStop::new(1.0, Colour::WHITE),
+];
+
+#[test]
+fn steps_array_from_stops() {
+    let gradient = Gradient::from_stops(STOPS).unwrap();
+    assert_eq!(
+        [Colour::BLACK, Colour::BLUE, Colour::WHITE],
+        gradient.steps_array()
+    );
+    assert_eq!([Colour::BLACK], gradient.steps_array::<1>());
+    assert_eq!([Colour::BLACK; 0], gradient.steps_array::<0>());
+
+    let steps: [Colour; 5] = gradient.steps_array();
+    for (i, colour) in steps.into_iter().enumerate() {
+        assert_eq!(gradient.at(i as f32 / 4.0), colour, "step {i}");
+    }
+}
+
+#[test]
+fn steps_array_from_slice() {
+    let gradient = Gradient::from_stops(&STOPS[1..]).unwrap();
+    assert_eq!(&STOPS[1..], gradient.stops());
+    assert_eq!([Colour::BLUE, Colour::WHITE], gradient.steps_array());
+    assert_eq!(Err(GradientError::Empty), Gradient::from_stops(&STOPS[..0]));
+}
+
+#[test]
+fn steps_array_of_presets() {
+    const GREYSCALE: Gradient<[ColourStop; 2]> = Gradient::greyscale();
+    assert_eq!(
+        [Colour::BLACK, Colour::new(0x808080), Colour::WHITE],
+        GREYSCALE.steps_array()
+    );
+
+    for gradient in [
+        Gradient::traffic_light(),
+        Gradient::battery(),
+        Gradient::temperature(),
+    ] {
+        let [start, middle, end] = gradient.steps_array();
+        let stops = gradient.stops();
+        assert_eq!(
+            [stops[0].colour, stops[1].colour, stops[2].colour],
+            [start, middle, end]
+        );
+    }
+}
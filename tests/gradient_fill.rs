@@ -0,0 +1,199 @@
+#![cfg(feature = "alloc")]
+
+use chrom::{codec, BufferSizeError, Colour, ColourStop, Gradient};
+
+fn gradient() -> Gradient {
+    Gradient::from_stops(vec![
+        ColourStop::new(0.0, Colour::RED),
+        ColourStop::new(0.3, Colour::GOLD),
+        ColourStop::new(0.3, Colour::BLUE),
+        ColourStop::new(0.8, Colour::WHITE),
+        ColourStop::new(1.0, Colour::BLACK),
+    ])
+    .unwrap()
+}
+
+/// The position along a linear gradient of the centre of a pixel, as
+/// documented on `Gradient::fill_linear`
+fn linear_t(x: usize, y: usize, width: usize, height: usize, angle: f32) -> f32 {
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let (w, h) = (width as f32, height as f32);
+    let (x, y) = (x as f32 + 0.5, y as f32 + 0.5);
+    ((x - w / 2.0) * sin - (y - h / 2.0) * cos) / ((w * sin).abs() + (h * cos).abs()) + 0.5
+}
+
+const SIZES: [(usize, usize); 4] = [(1, 1), (7, 3), (32, 32), (100, 41)];
+
+#[test]
+fn linear_matches_at() {
+    let gradient = gradient();
+    for (width, height) in SIZES {
+        for angle in [0.0, 30.0, 45.0, 90.0, 135.0, 180.0, 270.0, -60.0, 400.0] {
+            let mut buffer = vec![Colour::BLACK; width * height];
+            gradient
+                .fill_linear(&mut buffer, width, height, angle)
+                .unwrap();
+            for y in 0..height {
+                for x in 0..width {
+                    let t = linear_t(x, y, width, height, angle);
+                    assert_eq!(
+                        gradient.at(t),
+                        buffer[y * width + x],
+                        "{width}x{height} {angle} ({x}, {y})"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn every_segment_matches_at() {
+    // Stops which don't reach the ends, with hard stops and stops very close
+    // together
+    let gradient = Gradient::from_stops(vec![
+        ColourStop::new(0.2, Colour::RED),
+        ColourStop::new(0.2, Colour::GOLD),
+        ColourStop::new(0.25, Colour::BLUE),
+        ColourStop::new(0.250_001, Colour::DARK_NAVY),
+        ColourStop::new(0.5, Colour::WHITE),
+        ColourStop::new(0.7, Colour::BLACK),
+        ColourStop::new(0.7, Colour::GREEN),
+    ])
+    .unwrap();
+    let width = 2000;
+    let mut buffer = vec![Colour::BLACK; width];
+    gradient.fill_linear(&mut buffer, width, 1, 90.0).unwrap();
+    for (x, &colour) in buffer.iter().enumerate() {
+        assert_eq!(gradient.at(linear_t(x, 0, width, 1, 90.0)), colour, "{x}");
+    }
+}
+
+#[test]
+fn linear_directions() {
+    let gradient = Gradient::new(Colour::BLACK, Colour::WHITE);
+    let mut buffer = [Colour::BLACK; 9];
+    let red = |buffer: &[Colour], indices: [usize; 3]| indices.map(|i| buffer[i].red());
+
+    // Bottom to top
+    gradient.fill_linear(&mut buffer, 3, 3, 0.0).unwrap();
+    let [top, middle, bottom] = red(&buffer, [1, 4, 7]);
+    assert!(top > middle && middle > bottom);
+    assert_eq!(buffer[0], buffer[2]);
+    // Left to right
+    gradient.fill_linear(&mut buffer, 3, 3, 90.0).unwrap();
+    let [left, middle, right] = red(&buffer, [3, 4, 5]);
+    assert!(left < middle && middle < right);
+    assert_eq!(buffer[1], buffer[7]);
+    // The corners are at the ends
+    let mut buffer = vec![Colour::BLACK; 100 * 50];
+    gradient.fill_linear(&mut buffer, 100, 50, 135.0).unwrap();
+    assert!(buffer[0].red() <= 3 && buffer[buffer.len() - 1].red() >= 252);
+}
+
+#[test]
+fn radial_matches_at() {
+    let gradient = gradient();
+    for (width, height) in SIZES {
+        for (cx, cy, radius) in [
+            (0.0, 0.0, 10.0),
+            (3.5, 1.5, 2.0),
+            (16.0, 16.0, 16.0),
+            (-5.0, 50.0, 80.0),
+        ] {
+            let mut buffer = vec![Colour::BLACK; width * height];
+            gradient
+                .fill_radial(&mut buffer, width, height, cx, cy, radius)
+                .unwrap();
+            for y in 0..height {
+                for x in 0..width {
+                    let distance = (x as f32 + 0.5 - cx).hypot(y as f32 + 0.5 - cy);
+                    assert_eq!(
+                        gradient.at(distance / radius),
+                        buffer[y * width + x],
+                        "{width}x{height} ({x}, {y})"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn radial_without_radius() {
+    let gradient = gradient();
+    let mut buffer = [Colour::BLUE; 12];
+    gradient
+        .fill_radial(&mut buffer, 4, 3, 1.0, 1.0, 0.0)
+        .unwrap();
+    assert_eq!([Colour::BLACK; 12], buffer);
+}
+
+#[test]
+fn rgb24_matches_colours() {
+    let gradient = gradient();
+    for (width, height) in SIZES {
+        let mut colours = vec![Colour::BLACK; width * height];
+        let mut bytes = vec![0; width * height * 3];
+
+        gradient
+            .fill_linear(&mut colours, width, height, 75.0)
+            .unwrap();
+        gradient
+            .fill_linear_rgb24(&mut bytes, width, height, 75.0)
+            .unwrap();
+        assert_eq!(codec::encode_rgb24(&colours), bytes);
+
+        gradient
+            .fill_radial(&mut colours, width, height, 2.0, 1.0, 9.0)
+            .unwrap();
+        gradient
+            .fill_radial_rgb24(&mut bytes, width, height, 2.0, 1.0, 9.0)
+            .unwrap();
+        assert_eq!(codec::encode_rgb24(&colours), bytes);
+    }
+}
+
+#[test]
+fn wrong_buffer_size() {
+    let gradient = gradient();
+    let mut colours = [Colour::BLACK; 12];
+    let mut bytes = [0; 12];
+    let error = |width, height, length| {
+        Err(BufferSizeError {
+            width,
+            height,
+            length,
+        })
+    };
+
+    assert_eq!(
+        error(4, 4, 12),
+        gradient.fill_linear(&mut colours, 4, 4, 0.0)
+    );
+    assert_eq!(
+        error(2, 5, 12),
+        gradient.fill_radial(&mut colours, 2, 5, 0.0, 0.0, 1.0)
+    );
+    assert_eq!(
+        error(4, 3, 12),
+        gradient.fill_linear_rgb24(&mut bytes, 4, 3, 0.0)
+    );
+    assert_eq!(
+        error(2, 3, 12),
+        gradient.fill_radial_rgb24(&mut bytes, 2, 3, 0.0, 0.0, 1.0)
+    );
+    assert_eq!(
+        error(usize::MAX, 2, 12),
+        gradient.fill_linear(&mut colours, usize::MAX, 2, 0.0)
+    );
+    // Nothing was written
+    assert_eq!([Colour::BLACK; 12], colours);
+    assert_eq!([0; 12], bytes);
+
+    assert_eq!(Ok(()), gradient.fill_linear(&mut [], 0, 5, 0.0));
+    assert_eq!(
+        Ok(()),
+        gradient.fill_radial_rgb24(&mut [], 5, 0, 0.0, 0.0, 1.0)
+    );
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use chrom::Colour;
+use common::{channel_difference, colours};
+
+#[test]
+fn constants() {
+    let constants = [
+        (Colour::WHITE, 0.0, 0.000, 1.000, 1.000),
+        (Colour::BLACK, 0.0, 0.000, 0.000, 0.000),
+        (Colour::AQUA, 168.1, 0.757, 0.420, 0.737),
+        (Colour::GREEN, 138.6, 0.856, 0.645, 0.949),
+        (Colour::BLUE, 204.1, 0.699, 0.531, 0.859),
+        (Colour::YELLOW, 51.5, 0.988, 0.678, 0.996),
+        (Colour::PURPLE, 282.6, 0.389, 0.531, 0.714),
+        (Colour::GOLD, 48.1, 0.890, 0.502, 0.945),
+        (Colour::ORANGE, 28.2, 0.797, 0.518, 0.902),
+        (Colour::RED, 358.9, 0.826, 0.594, 0.929),
+        (Colour::GREY, 183.5, 0.087, 0.618, 0.651),
+        (Colour::NAVY, 210.0, 0.288, 0.286, 0.369),
+        (Colour::DARK_AQUA, 168.1, 0.766, 0.284, 0.502),
+        (Colour::DARK_GREEN, 145.0, 0.635, 0.333, 0.545),
+        (Colour::DARK_BLUE, 203.8, 0.644, 0.353, 0.580),
+        (Colour::DARK_PURPLE, 282.1, 0.437, 0.376, 0.541),
+        (Colour::DARK_GOLD, 36.7, 0.865, 0.408, 0.761),
+        (Colour::DARK_ORANGE, 23.9, 1.000, 0.329, 0.659),
+        (Colour::DARK_RED, 5.5, 0.636, 0.367, 0.600),
+        (Colour::DARK_GREY, 202.5, 0.040, 0.608, 0.624),
+        (Colour::DARK_NAVY, 210.0, 0.290, 0.243, 0.314),
+        (Colour::LIGHT_GREY, 180.0, 0.031, 0.745, 0.753),
+    ];
+    for (colour, hue, saturation, lightness, value) in constants {
+        assert!((colour.hue() - hue).abs() < 0.05, "{colour}");
+        assert!((colour.saturation() - saturation).abs() < 1e-3, "{colour}");
+        assert!((colour.lightness() - lightness).abs() < 1e-3, "{colour}");
+        assert!((colour.value() - value).abs() < 1e-3, "{colour}");
+    }
+}
+
+#[test]
+fn warm_or_cool_unless_grey() {
+    for colour in colours(9973) {
+        let grey = colour.saturation() == 0.0;
+        assert_eq!(!grey, colour.is_warm() != colour.is_cool(), "{colour}");
+    }
+}
+
+#[test]
+fn hsl_round_trips() {
+    for colour in colours(97) {
+        assert_eq!(colour, Colour::from(colour.to_hsl()), "{colour}");
+    }
+}
+
+#[test]
+fn hsv_round_trips() {
+    for colour in colours(97) {
+        assert_eq!(colour, Colour::from(colour.to_hsv()), "{colour}");
+    }
+}
+
+#[test]
+fn sv_plane_round_trips() {
+    for colour in colours(997) {
+        let (hue, x, y) = colour.to_sv_plane();
+        assert_eq!(colour, Colour::from_sv_plane(hue, x, y), "{colour}");
+    }
+}
+
+#[test]
+fn const_lighten_and_darken_are_close() {
+    for colour in colours(4999) {
+        for amount in (0..=1000).step_by(50) {
+            let float = amount as f32 / 1000.0;
+            let (lighter, const_lighter) =
+                (colour.lighten(float), colour.const_lighten_permille(amount));
+            assert!(
+                channel_difference(lighter, const_lighter) <= 2,
+                "{lighter} != {const_lighter}"
+            );
+            let (darker, const_darker) =
+                (colour.darken(float), colour.const_darken_permille(amount));
+            assert!(
+                channel_difference(darker, const_darker) <= 2,
+                "{darker} != {const_darker}"
+            );
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use chrom::Colour;
+use std::collections::BTreeMap;
+
+/// Part of the snapshot test vectors published by the HSLuv project, mapping
+/// hex colours to their HSLuv components
+fn snapshot() -> BTreeMap<String, [f32; 3]> {
+    serde_json::from_str(include_str!("data/hsluv-snapshot-rev4.json")).unwrap()
+}
+
+#[test]
+fn to_hsluv_matches_snapshot() {
+    for (hex, expected) in snapshot() {
+        let [h, s, l] = Colour::from_hex_str(&hex).unwrap().to_hsluv();
+        // Hue is meaningless for greys, and unstable when they are nearly grey
+        if expected[1] > 1e-6 {
+            assert!((h - expected[0]).abs() < 1e-3, "{hex} hue {h}");
+        }
+        assert!((s - expected[1]).abs() < 1e-3, "{hex} saturation {s}");
+        assert!((l - expected[2]).abs() < 1e-3, "{hex} lightness {l}");
+    }
+}
+
+#[test]
+fn from_hsluv_matches_snapshot() {
+    for (hex, [h, s, l]) in snapshot() {
+        let colour = Colour::from_hex_str(&hex).unwrap();
+        assert_eq!(colour, Colour::from_hsluv(h, s, l), "{hex}");
+    }
+}
@@ -0,0 +1,39 @@
+mod common;
+
+use chrom::Colour;
+use common::{channel_difference, colours};
+
+#[test]
+fn luma_chroma_round_trips() {
+    for colour in colours(997) {
+        let (luma, chroma) = colour.split_luma_chroma();
+        let result = Colour::from_luma_chroma(luma, chroma);
+        assert!(channel_difference(colour, result) <= 1, "{colour}");
+    }
+}
+
+#[test]
+fn with_luma_keeps_hue() {
+    for colour in [
+        Colour::AQUA,
+        Colour::GREEN,
+        Colour::BLUE,
+        Colour::YELLOW,
+        Colour::PURPLE,
+        Colour::GOLD,
+        Colour::ORANGE,
+        Colour::RED,
+        Colour::DARK_AQUA,
+        Colour::DARK_GREEN,
+        Colour::DARK_BLUE,
+        Colour::DARK_PURPLE,
+        Colour::DARK_GOLD,
+        Colour::DARK_ORANGE,
+        Colour::DARK_RED,
+    ] {
+        for luma in [0.2, 0.4, 0.6, 0.8] {
+            let result = colour.with_luma(luma);
+            assert!((result.hue() - colour.hue()).abs() < 3.0, "{colour} {luma}");
+        }
+    }
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use chrom::Colour;
+use common::{channel_difference, colours, pairs};
+
+/// Material Design's dark theme surfaces at 1dp, 2dp, 4dp, 8dp and 24dp, with
+/// the opacity of the white overlay on `#121212` for each
+const MATERIAL: [(f32, u32); 5] = [
+    (0.05, 0x1e1e1e),
+    (0.07, 0x222222),
+    (0.09, 0x272727),
+    (0.12, 0x2e2e2e),
+    (0.16, 0x383838),
+];
+
+#[test]
+fn mixes_start_and_end_at_each_colour() {
+    for (a, b) in pairs(9973) {
+        assert_eq!(a, a.mix_oklab(b, 0.0), "{a} {b}");
+        assert_eq!(b, a.mix_oklab(b, 1.0), "{a} {b}");
+        assert_eq!(a, a.mix_linear(b, 0.0), "{a} {b}");
+        assert_eq!(b, a.mix_linear(b, 1.0), "{a} {b}");
+        assert_eq!(a, a.overlay(b, 0.0), "{a} {b}");
+        assert_eq!(b, a.overlay(b, 1.0), "{a} {b}");
+        assert_eq!(a, a.mix_subtractive(b, 0.0), "{a} {b}");
+        assert_eq!(b, a.mix_subtractive(b, 1.0), "{a} {b}");
+    }
+}
+
+#[test]
+fn const_mix_is_close_to_mix() {
+    for (a, b) in pairs(4999) {
+        for weight in (0..=1000).step_by(50) {
+            let float = a.mix(b, weight as f32 / 1000.0);
+            let int = a.const_mix(b, weight);
+            assert!(channel_difference(float, int) <= 2, "{float} != {int}");
+        }
+    }
+}
+
+#[test]
+fn flatten_is_mix() {
+    for (a, b) in pairs(9973) {
+        for alpha in [0, 1, 64, 128, 254, 255] {
+            let flat = a.with_alpha(alpha).flatten(b);
+            let mixed = a.mix(b, 1.0 - f32::from(alpha) / 255.0);
+            assert_eq!(mixed, flat, "{a} {b} {alpha}");
+        }
+    }
+}
+
+#[test]
+fn mix_subtractive_with_itself() {
+    for a in colours(9973) {
+        for t in [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0] {
+            assert_eq!(a, a.mix_subtractive(a, t), "{a} {t}");
+        }
+    }
+}
+
+#[test]
+fn mix_matches_material() {
+    let surface = Colour::new(0x121212);
+    for (opacity, value) in MATERIAL {
+        let mixed = surface.mix(Colour::WHITE, opacity);
+        assert!(
+            channel_difference(Colour::new(value), mixed) <= 1,
+            "{mixed}"
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn elevate_is_lighter_than_material() {
+    let surface = Colour::new(0x121212);
+    let opacities = MATERIAL.map(|(opacity, _)| opacity);
+    let elevated = surface.elevate(Colour::WHITE, &opacities);
+    let lighter: Vec<_> = elevated
+        .iter()
+        .zip(MATERIAL)
+        .map(|(colour, (_, value))| {
+            let material = Colour::new(value);
+            assert_eq!(colour.red(), colour.blue(), "{colour}");
+            assert_eq!(colour.red(), colour.green(), "{colour}");
+            colour.red() - material.red()
+        })
+        .collect();
+    assert_eq!(vec![37, 44, 48, 53, 57], lighter);
+}
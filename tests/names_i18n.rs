@@ -0,0 +1,176 @@
+#![cfg(feature = "names-i18n")]
+
+use chrom::{Colour, Locale};
+
+fn css(name: &str) -> Option<Colour> {
+    Colour::from_name(name)
+}
+
+#[test]
+fn every_name_is_found() {
+    for locale in Locale::ALL {
+        for &(name, colour) in locale.names() {
+            assert_eq!(
+                Some(colour),
+                Colour::from_name_localised(name, locale),
+                "{name}"
+            );
+            assert_eq!(
+                Some(colour),
+                Colour::from_name_localised(&name.to_uppercase(), locale),
+                "{name}"
+            );
+            assert!(Colour::from_name_any_locale(name).is_some(), "{name}");
+        }
+    }
+}
+
+#[test]
+fn names_are_sorted() {
+    for locale in Locale::ALL {
+        let names = locale.names();
+        assert!(
+            names.windows(2).all(|pair| pair[0].0 < pair[1].0),
+            "{locale:?}"
+        );
+    }
+}
+
+#[test]
+fn english() {
+    assert_eq!(
+        css("red"),
+        Colour::from_name_localised("Red", Locale::English)
+    );
+    assert_eq!(
+        css("lightblue"),
+        Colour::from_name_localised("light blue", Locale::English)
+    );
+    assert_eq!(None, Colour::from_name_localised("rot", Locale::English));
+}
+
+#[test]
+fn german() {
+    let name = |name| Colour::from_name_localised(name, Locale::German);
+    assert_eq!(css("red"), name("rot"));
+    assert_eq!(css("green"), name("Grün"));
+    assert_eq!(css("green"), name("grun"));
+    assert_eq!(css("white"), name("weiß"));
+    assert_eq!(css("white"), name("WEISS"));
+    assert_eq!(css("turquoise"), name("Türkis"));
+    assert_eq!(css("navy"), name("Marineblau"));
+    assert_eq!(None, name("red"));
+}
+
+#[test]
+fn french() {
+    let name = |name| Colour::from_name_localised(name, Locale::French);
+    assert_eq!(css("red"), name("rouge"));
+    assert_eq!(css("darkblue"), name("bleu foncé"));
+    assert_eq!(css("darkblue"), name("Bleu-Fonce"));
+    assert_eq!(css("orchid"), name("ORCHIDÉE"));
+    assert_eq!(css("gold"), name("or"));
+    assert_eq!(None, name("rot"));
+}
+
+#[test]
+fn spanish() {
+    let name = |name| Colour::from_name_localised(name, Locale::Spanish);
+    assert_eq!(css("red"), name("rojo"));
+    assert_eq!(css("red"), name("Roja"));
+    assert_eq!(css("brown"), name("marrón"));
+    assert_eq!(css("purple"), name("Púrpura"));
+    assert_eq!(css("navy"), name("azul marino"));
+    assert_eq!(css("indigo"), name("índigo"));
+    assert_eq!(None, name("rosso"));
+}
+
+#[test]
+fn italian() {
+    let name = |name| Colour::from_name_localised(name, Locale::Italian);
+    assert_eq!(css("red"), name("rosso"));
+    assert_eq!(css("red"), name("rossa"));
+    assert_eq!(css("maroon"), name("bordò"));
+    assert_eq!(css("navy"), name("Blu Notte"));
+    assert_eq!(None, name("rojo"));
+}
+
+#[test]
+fn portuguese() {
+    let name = |name| Colour::from_name_localised(name, Locale::Portuguese);
+    assert_eq!(css("red"), name("vermelho"));
+    assert_eq!(css("salmon"), name("salmão"));
+    assert_eq!(css("maroon"), name("Bordô"));
+    assert_eq!(css("fuchsia"), name("fúcsia"));
+    assert_eq!(None, name("rouge"));
+}
+
+#[test]
+fn russian() {
+    let name = |name| Colour::from_name_localised(name, Locale::Russian);
+    assert_eq!(css("red"), name("красный"));
+    assert_eq!(css("red"), name("КРАСНАЯ"));
+    assert_eq!(css("yellow"), name("жёлтый"));
+    assert_eq!(css("yellow"), name("желтый"));
+    assert_eq!(css("navy"), name("тёмно-синий"));
+    assert_eq!(css("blue"), name("синий"));
+    assert_eq!(None, name("red"));
+}
+
+#[test]
+fn decomposed_diacritics() {
+    assert_eq!(
+        css("green"),
+        Colour::from_name_localised("gru\u{308}n", Locale::German)
+    );
+}
+
+#[test]
+fn ambiguous_names() {
+    // "violet" is its own colour in English but purple in French
+    assert_eq!(
+        css("violet"),
+        Colour::from_name_localised("violet", Locale::English)
+    );
+    assert_eq!(
+        css("purple"),
+        Colour::from_name_localised("violet", Locale::French)
+    );
+    assert_eq!(css("violet"), Colour::from_name_any_locale("violet"));
+
+    // "pink" is a pale pink in English, but a vivid one in German
+    assert_eq!(css("pink"), Colour::from_name_any_locale("pink"));
+    assert_eq!(
+        css("hotpink"),
+        Colour::from_name_localised("pink", Locale::German)
+    );
+
+    // "celeste" is sky blue in Spanish but light blue in Italian
+    assert_eq!(
+        css("skyblue"),
+        Colour::from_name_localised("celeste", Locale::Spanish)
+    );
+    assert_eq!(
+        css("lightblue"),
+        Colour::from_name_localised("celeste", Locale::Italian)
+    );
+    assert_eq!(css("skyblue"), Colour::from_name_any_locale("celeste"));
+
+    // "marron" is brown in French, not CSS maroon
+    assert_eq!(css("brown"), Colour::from_name_any_locale("marron"));
+    assert_eq!(css("maroon"), Colour::from_name_any_locale("maroon"));
+
+    // "rosa" is pink in every language that has it
+    for locale in [
+        Locale::German,
+        Locale::Spanish,
+        Locale::Italian,
+        Locale::Portuguese,
+    ] {
+        assert_eq!(
+            css("pink"),
+            Colour::from_name_localised("rosa", locale),
+            "{locale:?}"
+        );
+    }
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use chrom::{Colour, Gradient, ParseColourError, Rgba, CSS_NAMES};
+use common::colours;
+
+#[test]
+fn every_css_name_parses() {
+    for (name, colour) in CSS_NAMES {
+        assert_eq!(Ok(colour), name.parse(), "{name}");
+        assert_eq!(Ok(colour), name.to_uppercase().parse(), "{name}");
+    }
+}
+
+#[test]
+fn rgb_matches_hex() {
+    for colour in colours(9973) {
+        let (red, green, blue) = (colour.red(), colour.green(), colour.blue());
+        assert_eq!(Ok(colour), format!("rgb({red}, {green}, {blue})").parse());
+        assert_eq!(Ok(colour), format!("rgb({red} {green} {blue})").parse());
+        assert_eq!(Ok(colour), Colour::parse(&colour.to_css_rgb()));
+        let percent = |c: u8| format!("{}%", c as f32 / 2.55);
+        let css = format!("rgb({} {} {})", percent(red), percent(green), percent(blue));
+        assert_eq!(Ok(colour), css.parse(), "{css}");
+    }
+}
+
+#[test]
+fn rgb_errors() {
+    for input in [
+        "rgb()",
+        "rgb(1, 2)",
+        "rgb(1, 2, 3, 4)",
+        "rgb(1 2 3 4)",
+        "rgb(1, 2 3)",
+        "rgb(1, , 3)",
+        "rgb(red, 0, 0)",
+        "rgb(1, 2, 3",
+        "rgb(inf, 0, 0)",
+    ] {
+        assert_eq!(
+            Err(ParseColourError::InvalidSyntax),
+            Colour::parse(input),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn names_errors() {
+    assert_eq!(
+        Err(ParseColourError::UnknownName("transparent".to_owned())),
+        Colour::parse("transparent"),
+    );
+    assert_eq!(
+        Err(ParseColourError::InvalidSyntax),
+        Colour::parse("rebecca purple")
+    );
+    assert_eq!(Err(ParseColourError::InvalidSyntax), Colour::parse(""));
+    assert_eq!(
+        "unknown colour name \"blurple\"",
+        Colour::parse("blurple").unwrap_err().to_string(),
+    );
+}
+
+#[test]
+fn gradients_with_new_formats() {
+    let gradient: Gradient = "linear-gradient(to right, rgb(52 152 219) 0%, tomato 100%)"
+        .parse()
+        .unwrap();
+    assert_eq!(Colour::BLUE, gradient.at(0.0));
+    assert_eq!(Colour::new(0xff6347), gradient.at(1.0));
+}
+
+#[test]
+fn hex_str_matches_parse() {
+    for value in (0..0x1000).step_by(7) {
+        for hex in [format!("{value:03x}"), format!("{value:06x}")] {
+            let expected = format!("#{hex}").parse::<Colour>().ok();
+            assert_eq!(expected, Colour::from_hex_str(&hex), "{hex}");
+            assert_eq!(expected, Colour::from_hex_str(&format!("#{hex}")), "{hex}");
+            assert_eq!(expected, Colour::from_hex_str(&hex.to_uppercase()), "{hex}");
+        }
+    }
+    for hex in [
+        "#",
+        "#f",
+        "#ffff",
+        "#fffffff",
+        "#ffffffff",
+        "##fff",
+        " #fff",
+        "#fff ",
+    ] {
+        assert_eq!(None, Colour::from_hex_str(hex), "{hex:?}");
+    }
+}
+
+#[test]
+fn hex_str_round_trips() {
+    for colour in colours(9973) {
+        let hex = colour.to_string();
+        assert_eq!(Some(colour), Colour::from_hex_str(&hex), "{hex}");
+    }
+}
+
+#[test]
+fn rgba_round_trips() {
+    for value in (0..=u32::MAX).step_by(99991) {
+        let rgba = Rgba::from_u32(value);
+        assert_eq!(Ok(rgba), rgba.to_string().parse(), "{value:08x}");
+    }
+}
@@ -0,0 +1,152 @@
+//! Conversions checked against published reference values
+//!
+//! XYZ and L\*a\*b\* use the sRGB matrix and D65 white point from CSS Color 4,
+//! and Oklab uses the matrices from Björn Ottosson's definition. The Delta E
+//! values are from an implementation of CIEDE2000 checked against Sharma, Wu
+//! and Dalal's test data.
+
+mod common;
+
+use chrom::{Colour, Palette};
+use common::colours;
+
+fn assert_close(expected: [f32; 3], actual: [f32; 3], tolerance: f32, what: &str) {
+    for (e, a) in expected.into_iter().zip(actual) {
+        assert!(
+            (e - a).abs() < tolerance,
+            "{what}: expected {expected:?}, got {actual:?}"
+        );
+    }
+}
+
+#[test]
+fn xyz() {
+    for (colour, xyz) in [
+        (Colour::new(0xff0000), [0.4124, 0.2126, 0.0193]),
+        (Colour::new(0x00ff00), [0.3576, 0.7152, 0.1192]),
+        (Colour::new(0x0000ff), [0.1805, 0.0722, 0.9505]),
+        (Colour::WHITE, [0.9505, 1.0, 1.0891]),
+        (Colour::new(0x808080), [0.2052, 0.2159, 0.2351]),
+    ] {
+        assert_close(xyz, colour.to_xyz(), 1e-3, &colour.to_string());
+    }
+}
+
+#[test]
+fn lab() {
+    for (colour, lab) in [
+        (Colour::new(0xff0000), [53.2371, 80.0901, 67.2033]),
+        (Colour::new(0x00ff00), [87.7355, -86.1816, 83.1866]),
+        (Colour::new(0x0000ff), [32.3009, 79.1953, -107.8555]),
+        (Colour::WHITE, [100.0, 0.0, 0.0]),
+        (Colour::BLACK, [0.0, 0.0, 0.0]),
+        (Colour::new(0x808080), [53.5850, 0.0, 0.0]),
+        (Colour::BLUE, [60.1585, -6.0876, -42.2281]),
+        (Colour::GOLD, [80.8592, 1.9217, 80.7498]),
+    ] {
+        assert_close(lab, colour.to_lab(), 2e-2, &colour.to_string());
+    }
+}
+
+#[test]
+fn oklab() {
+    for (colour, oklab) in [
+        (Colour::new(0xff0000), [0.62796, 0.22486, 0.12585]),
+        (Colour::new(0x00ff00), [0.86644, -0.23389, 0.17950]),
+        (Colour::new(0x0000ff), [0.45201, -0.03246, -0.31153]),
+        (Colour::WHITE, [1.0, 0.0, 0.0]),
+        (Colour::BLACK, [0.0, 0.0, 0.0]),
+        (Colour::new(0x808080), [0.59987, 0.0, 0.0]),
+        (Colour::BLUE, [0.65309, -0.06181, -0.11968]),
+        (Colour::DARK_NAVY, [0.35641, -0.01403, -0.03649]),
+    ] {
+        assert_close(oklab, colour.to_oklab(), 1e-3, &colour.to_string());
+    }
+}
+
+#[test]
+fn delta_e() {
+    for (a, b, expected) in [
+        (Colour::BLACK, Colour::WHITE, 100.0),
+        (Colour::BLUE, Colour::DARK_BLUE, 19.4014),
+        (Colour::RED, Colour::ORANGE, 23.8124),
+        (Colour::new(0x808080), Colour::new(0x7f7f80), 0.7051),
+        (Colour::GOLD, Colour::new(0xfee75c), 9.4058),
+    ] {
+        let distance = a.delta_e(b);
+        assert!((expected - distance).abs() < 1e-2, "{a} {b}: {distance}");
+        assert_eq!(distance, b.delta_e(a), "{a} {b}");
+    }
+}
+
+#[test]
+fn nearest_is_perceptual() {
+    // Closest to gold by RGB distance, but it looks closest to yellow
+    let colour = Colour::new(0xd2e82d);
+    let rgb_distance = |other: Colour| {
+        [
+            colour.red().abs_diff(other.red()),
+            colour.green().abs_diff(other.green()),
+            colour.blue().abs_diff(other.blue()),
+        ]
+        .map(u32::from)
+        .iter()
+        .map(|d| d * d)
+        .sum::<u32>()
+    };
+    assert!(rgb_distance(Colour::GOLD) < rgb_distance(Colour::YELLOW));
+
+    let palette = Palette::<2>::from([Colour::GOLD, Colour::YELLOW]);
+    assert_eq!(Some(Colour::YELLOW), palette.nearest(colour));
+    assert_eq!(("YELLOW", Colour::YELLOW), colour.nearest_constant());
+}
+
+/// The exact sRGB to XYZ matrix from CSS Color 4, as fractions
+const CSS_LINEAR_TO_XYZ: [[f64; 3]; 3] = [
+    [
+        506_752.0 / 1_228_815.0,
+        87_881.0 / 245_763.0,
+        12_673.0 / 70_218.0,
+    ],
+    [
+        87_098.0 / 409_605.0,
+        175_762.0 / 245_763.0,
+        12_673.0 / 175_545.0,
+    ],
+    [
+        7_918.0 / 409_605.0,
+        87_881.0 / 737_289.0,
+        1_001_167.0 / 1_053_270.0,
+    ],
+];
+
+#[test]
+fn xyz_uses_css_colour_4_matrix() {
+    for (i, primary) in [
+        Colour::new(0xff0000),
+        Colour::new(0x00ff00),
+        Colour::new(0x0000ff),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let column = CSS_LINEAR_TO_XYZ.map(|row| row[i] as f32);
+        assert_close(column, primary.to_xyz(), 1e-6, &primary.to_string());
+    }
+
+    // The D65 white from its chromaticity, x = 0.3127 and y = 0.3290
+    let white = [0.3127 / 0.3290, 1.0, (1.0 - 0.3127 - 0.3290) / 0.3290];
+    assert_close(white, Colour::WHITE.to_xyz(), 1e-6, "white");
+    assert_close([100.0, 0.0, 0.0], Colour::WHITE.to_lab(), 1e-3, "white");
+}
+
+#[test]
+fn xyz_and_display_p3_agree() {
+    for colour in colours(4999) {
+        assert_eq!(colour, Colour::from_xyz(colour.to_xyz()), "{colour}");
+        assert_eq!(colour, Colour::from_lab(colour.to_lab()), "{colour}");
+        let [r, g, b] = colour.to_display_p3();
+        assert_eq!(colour, Colour::from_display_p3(r, g, b), "{colour}");
+    }
+    assert_close([1.0; 3], Colour::WHITE.to_display_p3(), 1e-5, "white");
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "rgb")]
+
+mod common;
+
+use chrom::{Colour, Rgba};
+use common::colours;
+use rgb::{RGB8, RGBA8};
+
+#[test]
+fn rgb8_round_trip() {
+    for colour in colours(997) {
+        let pixel = RGB8::from(colour);
+        assert_eq!(
+            [colour.red(), colour.green(), colour.blue()],
+            [pixel.r, pixel.g, pixel.b]
+        );
+        assert_eq!(colour, Colour::from(pixel));
+    }
+}
+
+#[test]
+fn rgba8_round_trip() {
+    for value in (0..=u32::MAX).step_by(99_991) {
+        let rgba = Rgba::from_u32(value);
+        let pixel = RGBA8::from(rgba);
+        assert_eq!(RGBA8::from(value.to_be_bytes()), pixel);
+        assert_eq!(rgba, Rgba::from(pixel));
+    }
+    assert_eq!(
+        RGBA8::new(52, 152, 219, 255),
+        Colour::BLUE.with_alpha(255).into()
+    );
+    assert_eq!(Rgba::TRANSPARENT, RGBA8::new(0, 0, 0, 0).into());
+}
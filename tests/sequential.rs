@@ -0,0 +1,39 @@
+#![cfg(feature = "alloc")]
+
+use chrom::{diverging_palette, sequential_palette};
+
+#[test]
+fn sequential_palettes_get_lighter() {
+    for hue in (0..360).step_by(15) {
+        for n in 2..=12 {
+            let palette = sequential_palette(hue as f32, n);
+            for pair in palette.windows(2) {
+                assert!(pair[0].to_oklab()[0] < pair[1].to_oklab()[0], "{hue} {n}");
+                assert!(pair[0].delta_e(pair[1]) > 5.0, "{hue} {n}");
+            }
+        }
+    }
+}
+
+#[test]
+fn diverging_palettes_are_lightest_in_the_middle() {
+    for hue_a in (0..360).step_by(30) {
+        for offset in (90..=270).step_by(30) {
+            let hue_b = ((hue_a + offset) % 360) as f32;
+            for n in 2..=12 {
+                let palette = diverging_palette(hue_a as f32, hue_b, n);
+                let lightness: Vec<_> = palette.iter().map(|colour| colour.to_oklab()[0]).collect();
+                let middle = n / 2;
+                for i in 0..n - 1 {
+                    let message = format!("{hue_a} {hue_b} {n}");
+                    assert!(palette[i].delta_e(palette[i + 1]) > 5.0, "{message}");
+                    if i + 1 < middle || (i + 1 == middle && n % 2 == 1) {
+                        assert!(lightness[i] < lightness[i + 1], "{message}");
+                    } else if i >= middle {
+                        assert!(lightness[i] > lightness[i + 1], "{message}");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+mod common;
+
+use chrom::Colour;
+use common::colours;
+
+#[test]
+fn step_hue_undoes() {
+    for colour in colours(997).filter(|colour| colour.saturation() != 0.0) {
+        for degrees in [0.1, 1.0, 5.0, 15.0, 90.0, 400.0] {
+            let stepped = colour.step_hue(degrees);
+            assert_ne!(colour, stepped, "{colour} by {degrees}");
+            assert_eq!(colour, stepped.step_hue(-degrees), "{colour} by {degrees}");
+            assert_eq!(
+                colour,
+                colour.step_hue(-degrees).step_hue(degrees),
+                "{colour} by {degrees}"
+            );
+        }
+    }
+}
+
+#[test]
+fn step_hue_keeps_saturation_and_lightness() {
+    for colour in colours(997) {
+        let stepped = colour.step_hue(10.0);
+        assert_eq!(colour.saturation(), stepped.saturation(), "{colour}");
+        assert_eq!(colour.lightness(), stepped.lightness(), "{colour}");
+        // The step is rounded to the nearest hue there is, and there are
+        // `6 * chroma` of them
+        let chroma = f32::from(colour.red().max(colour.green()).max(colour.blue()))
+            - f32::from(colour.red().min(colour.green()).min(colour.blue()));
+        if chroma != 0.0 {
+            let error = (stepped.hue() - colour.hue() - 10.0).rem_euclid(360.0);
+            let error = error.min(360.0 - error);
+            assert!(
+                error <= (30.0 / chroma).max(60.0 / chroma - 10.0) + 1e-3,
+                "{colour}"
+            );
+        }
+    }
+}
+
+#[test]
+fn step_hue_goes_round() {
+    let colour = Colour::from_rgb(129, 128, 128);
+    let mut stepped = colour;
+    for i in 1..6 {
+        stepped = stepped.step_hue(1.0);
+        assert_ne!(colour, stepped, "step {i}");
+    }
+    assert_eq!(colour, stepped.step_hue(1.0));
+}
+
+#[test]
+fn step_saturation_undoes() {
+    for colour in colours(997) {
+        for amount in [0.001, 0.01, 0.05, 0.2] {
+            let stepped = colour.step_saturation(amount);
+            if stepped.saturation() < colour.saturation() + amount {
+                // Clamped
+                continue;
+            }
+            assert_eq!(
+                colour,
+                stepped.step_saturation(-amount),
+                "{colour} by {amount}"
+            );
+        }
+    }
+}
+
+#[test]
+fn step_saturation_never_sticks() {
+    for colour in colours(997).step_by(50) {
+        if colour.lightness() == 0.0 || colour.lightness() == 1.0 {
+            assert_eq!(colour, colour.step_saturation(0.001));
+            continue;
+        }
+        let mut stepped = colour;
+        while stepped.step_saturation(0.001) != stepped {
+            let next = stepped.step_saturation(0.001);
+            assert!(
+                next.saturation() > stepped.saturation(),
+                "{colour} stuck at {stepped}"
+            );
+            stepped = next;
+        }
+        assert!(stepped.saturation() > 0.999, "{colour} stuck at {stepped}");
+        while stepped.step_saturation(-0.001) != stepped {
+            let next = stepped.step_saturation(-0.001);
+            assert!(
+                next.saturation() < stepped.saturation(),
+                "{colour} stuck at {stepped}"
+            );
+            stepped = next;
+        }
+        // Colours with an odd sum of largest and smallest channels can't be grey
+        let (max, min) = (
+            stepped.red().max(stepped.blue()),
+            stepped.red().min(stepped.blue()),
+        );
+        assert!(
+            max.max(stepped.green()) - min.min(stepped.green()) <= 1,
+            "{colour} stuck at {stepped}"
+        );
+    }
+}
+
+#[test]
+fn step_lightness_undoes() {
+    for colour in colours(997) {
+        for amount in [0.001, 0.01, 0.05, 0.2] {
+            let lightness = colour.lightness();
+            if lightness + amount <= 0.5 {
+                let stepped = colour.step_lightness(amount);
+                assert_eq!(
+                    colour,
+                    stepped.step_lightness(-amount),
+                    "{colour} by {amount}"
+                );
+            }
+            if lightness - amount >= 0.5 {
+                let stepped = colour.step_lightness(-amount);
+                assert_eq!(
+                    colour,
+                    stepped.step_lightness(amount),
+                    "{colour} by {amount}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn step_lightness_never_sticks() {
+    for colour in colours(997).step_by(50) {
+        let mut stepped = colour;
+        while stepped != Colour::WHITE {
+            let next = stepped.step_lightness(0.001);
+            assert!(
+                next.lightness() > stepped.lightness(),
+                "{colour} stuck at {stepped}"
+            );
+            stepped = next;
+        }
+        while stepped != Colour::BLACK {
+            let next = stepped.step_lightness(-0.001);
+            assert!(
+                next.lightness() < stepped.lightness(),
+                "{colour} stuck at {stepped}"
+            );
+            stepped = next;
+        }
+    }
+}
+
+#[test]
+fn steps_match_naive_adjustments() {
+    let close = |a: Colour, b: Colour| {
+        a.red().abs_diff(b.red()) <= 2
+            && a.green().abs_diff(b.green()) <= 2
+            && a.blue().abs_diff(b.blue()) <= 2
+    };
+    for colour in colours(997) {
+        let stepped = colour.step_lightness(0.1);
+        assert!(close(colour.lighten(0.1), stepped), "{colour} {stepped}");
+        let stepped = colour.step_saturation(-0.1);
+        assert!(close(colour.desaturate(0.1), stepped), "{colour} {stepped}");
+    }
+}
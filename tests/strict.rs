@@ -0,0 +1,50 @@
+use chrom::Colour;
+
+#[test]
+#[should_panic(expected = "attempt to add with overflow in the red channel: 200 + 100")]
+fn add_overflow() {
+    let _ = Colour::from_rgb(200, 0, 0).strict() + Colour::from_rgb(100, 0, 0).strict();
+}
+
+#[test]
+#[should_panic(expected = "attempt to add with overflow in the green channel: 152 + 152")]
+fn add_assign_overflow() {
+    let mut colour = Colour::BLUE.strict();
+    colour += Colour::BLUE.strict();
+}
+
+#[test]
+#[should_panic(expected = "attempt to subtract with overflow in the green channel: 0 - 152")]
+fn sub_overflow() {
+    let _ = Colour::from_rgb(255, 0, 255).strict() - Colour::BLUE.strict();
+}
+
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow in the red channel: 16 * 16")]
+fn mul_overflow() {
+    let _ = Colour::from_rgb(16, 0, 0).strict() * Colour::from_rgb(16, 0, 0).strict();
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero in the green channel: 152 / 0")]
+fn div_by_zero() {
+    let _ = Colour::BLUE.strict() / Colour::from_rgb(1, 0, 1).strict();
+}
+
+#[test]
+fn no_overflow() {
+    let colour = Colour::from_rgb(10, 20, 30).strict();
+    assert_eq!(
+        Colour::from_rgb(255, 255, 255),
+        (colour + Colour::from_rgb(245, 235, 225).strict()).0
+    );
+    assert_eq!(Colour::BLACK, (colour - colour).0);
+    assert_eq!(
+        Colour::from_rgb(250, 240, 210),
+        (colour * Colour::from_rgb(25, 12, 7).strict()).0
+    );
+    assert_eq!(
+        Colour::from_rgb(5, 6, 30),
+        (colour / Colour::from_rgb(2, 3, 1).strict()).0
+    );
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use chrom::Colour;
+use common::colours;
+use std::{
+    io::Write,
+    panic::{self, AssertUnwindSafe},
+};
+
+const BLUE: &str = "\x1b[38;2;52;152;219m";
+const RED: &str = "\x1b[38;2;237;66;69m";
+const GOLD: &str = "\x1b[38;2;241;196;15m";
+const RESET: &str = "\x1b[39m";
+
+fn output(bytes: Vec<u8>) -> String {
+    String::from_utf8(bytes).unwrap()
+}
+
+#[test]
+fn resets_on_drop() {
+    let mut bytes = Vec::new();
+    {
+        let mut guard = Colour::BLUE.set_fg_guard(&mut bytes).unwrap();
+        write!(guard, "text").unwrap();
+    }
+    assert_eq!(format!("{BLUE}text{RESET}"), output(bytes));
+}
+
+#[test]
+fn nested_guards_restore_their_parent() {
+    let mut bytes = Vec::new();
+    {
+        let mut blue = Colour::BLUE.set_fg_guard(&mut bytes).unwrap();
+        write!(blue, "a").unwrap();
+        {
+            let mut red = blue.nested(Colour::RED).unwrap();
+            write!(red, "b").unwrap();
+            {
+                let mut gold = red.nested(Colour::GOLD).unwrap();
+                write!(gold, "c").unwrap();
+            }
+            write!(red, "d").unwrap();
+        }
+        write!(blue, "e").unwrap();
+    }
+    assert_eq!(
+        format!("{BLUE}a{RED}b{GOLD}c{RED}d{BLUE}e{RESET}"),
+        output(bytes),
+    );
+}
+
+#[test]
+fn resets_when_unwinding() {
+    let mut bytes = Vec::new();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut blue = Colour::BLUE.set_fg_guard(&mut bytes).unwrap();
+        let mut red = blue.nested(Colour::RED).unwrap();
+        write!(red, "before").unwrap();
+        panic!("in the middle of coloured output");
+    }));
+    assert!(result.is_err());
+    assert_eq!(format!("{BLUE}{RED}before{BLUE}{RESET}"), output(bytes));
+}
+
+#[test]
+fn owned_writer() {
+    let guard = Colour::GOLD.set_fg_guard(Vec::new()).unwrap();
+    assert_eq!(Colour::GOLD, guard.colour());
+    drop(guard);
+}
+
+#[test]
+fn ansi256_matches_palette_index() {
+    let palette: Vec<_> = (16..=255).map(Colour::from_ansi256).collect();
+    for colour in colours(49999) {
+        let index = colour.to_palette_index(&palette) + 16;
+        assert_eq!(index, colour.to_ansi256(), "{colour}");
+    }
+}
+
+#[test]
+fn ansi256_round_trips() {
+    for index in 16..=255 {
+        assert_eq!(index, Colour::from_ansi256(index).to_ansi256());
+    }
+}
+
+#[cfg(feature = "ansi")]
+#[test]
+fn painted_nests_in_guards() {
+    let mut bytes = Vec::new();
+    {
+        let mut guard = Colour::BLUE.set_fg_guard(&mut bytes).unwrap();
+        write!(guard, "{} blue", Colour::RED.paint("red")).unwrap();
+    }
+    // The text after the painted span is still blue
+    assert_eq!(
+        format!("{BLUE}{RED}red{RESET}{BLUE} blue{RESET}"),
+        output(bytes)
+    );
+}
+
+#[test]
+fn resets_in_text_restore_the_guard() {
+    let mut bytes = Vec::new();
+    {
+        let mut guard = Colour::BLUE.set_fg_guard(&mut bytes).unwrap();
+        guard
+            .write_all(b"a\x1b[0mb\x1b[1;31mc\x1b[mdRESET\x1b[39")
+            .unwrap();
+    }
+    assert_eq!(
+        format!("{BLUE}a\x1b[0m{BLUE}b\x1b[1;31mc\x1b[m{BLUE}dRESET\x1b[39{RESET}"),
+        output(bytes),
+    );
+}
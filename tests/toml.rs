@@ -0,0 +1,157 @@
+#![cfg(all(feature = "serde", feature = "alloc"))]
+
+use chrom::Colour;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Theme {
+    #[serde(with = "chrom::serde::hex_string")]
+    accent: Colour,
+    #[serde(with = "chrom::serde::rgb_struct")]
+    background: Colour,
+}
+
+#[test]
+fn hex_string_round_trip() {
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Config {
+        #[serde(with = "chrom::serde::hex_string")]
+        colour: Colour,
+    }
+
+    let config = Config {
+        colour: Colour::BLUE,
+    };
+    let toml = toml::to_string(&config).unwrap();
+    assert_eq!("colour = \"#3498db\"\n", toml);
+    assert_eq!(config, toml::from_str(&toml).unwrap());
+}
+
+#[test]
+fn rgb_struct_round_trip() {
+    let theme = Theme {
+        accent: Colour::BLUE,
+        background: Colour::DARK_NAVY,
+    };
+    let toml = toml::to_string(&theme).unwrap();
+    assert_eq!(theme, toml::from_str(&toml).unwrap());
+}
+
+#[test]
+fn accepts_either_representation() {
+    let theme: Theme = toml::from_str(
+        r##"
+        accent = { r = 52, g = 152, b = 219 }
+        background = "#2c3e50"
+        "##,
+    )
+    .unwrap();
+    assert_eq!(
+        Theme {
+            accent: Colour::BLUE,
+            background: Colour::DARK_NAVY,
+        },
+        theme
+    );
+}
+
+#[test]
+fn accepts_integers() {
+    let theme: Theme = toml::from_str("accent = 3447003\nbackground = 0x2c3e50").unwrap();
+    assert_eq!(Colour::BLUE, theme.accent);
+    assert_eq!(Colour::DARK_NAVY, theme.background);
+}
+
+#[test]
+fn rejects_invalid_values() {
+    for toml in [
+        "accent = \"#3498dbff\"\nbackground = 0",
+        "accent = 16777216\nbackground = 0",
+        "accent = -1\nbackground = 0",
+        "accent = { r = 256, g = 0, b = 0 }\nbackground = 0",
+        "accent = { r = 0, g = 0 }\nbackground = 0",
+        "accent = 1979-05-27\nbackground = 0",
+    ] {
+        assert!(toml::from_str::<Theme>(toml).is_err(), "{toml}");
+    }
+}
+
+#[test]
+fn default_representation_rejects_out_of_range_values() {
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Config {
+        colour: Colour,
+    }
+
+    let config = Config {
+        colour: Colour::BLUE,
+    };
+    let toml = toml::to_string(&config).unwrap();
+    assert_eq!("colour = 3447003\n", toml);
+    assert_eq!(config, toml::from_str(&toml).unwrap());
+
+    for toml in ["colour = 16777216", "colour = 0xffffffff", "colour = -1"] {
+        assert!(toml::from_str::<Config>(toml).is_err(), "{toml}");
+    }
+}
+
+#[test]
+fn default_representation_accepts_strings() {
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Config {
+        colour: Colour,
+    }
+
+    for toml in [
+        "colour = \"#3498db\"",
+        "colour = \"#3498DB\"",
+        "colour = { r = 52, g = 152, b = 219 }",
+    ] {
+        let config: Config = toml::from_str(toml).unwrap();
+        assert_eq!(Colour::BLUE, config.colour, "{toml}");
+    }
+    assert!(toml::from_str::<Config>("colour = \"#3498d\"").is_err());
+}
+
+#[test]
+fn int_round_trip() {
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Config {
+        #[serde(with = "chrom::serde::int")]
+        colour: Colour,
+    }
+
+    let config = Config {
+        colour: Colour::BLUE,
+    };
+    let toml = toml::to_string(&config).unwrap();
+    assert_eq!("colour = 3447003\n", toml);
+    assert_eq!(config, toml::from_str(&toml).unwrap());
+    let config: Config = toml::from_str("colour = \"#3498db\"").unwrap();
+    assert_eq!(Colour::BLUE, config.colour);
+    assert!(toml::from_str::<Config>("colour = 16777216").is_err());
+}
+
+#[test]
+fn optional_round_trip() {
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Config {
+        #[serde(default, with = "chrom::serde::optional")]
+        border: Option<Colour>,
+    }
+
+    for (border, toml) in [
+        (None, "border = \"transparent\"\n"),
+        (Some(Colour::BLUE), "border = \"#3498db\"\n"),
+    ] {
+        let config = Config { border };
+        assert_eq!(toml, toml::to_string(&config).unwrap());
+        assert_eq!(config, toml::from_str(toml).unwrap());
+    }
+
+    let config: Config = toml::from_str("border = \"none\"").unwrap();
+    assert_eq!(None, config.border);
+    let config: Config = toml::from_str("").unwrap();
+    assert_eq!(None, config.border);
+    assert!(toml::from_str::<Config>("border = \"#3498d\"").is_err());
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use chrom::Colour;
+use common::colours;
+
+const OVERBRIGHT: [[f32; 3]; 8] = [
+    [1.6, 0.4, 0.0],
+    [1.5, 0.3, 0.05],
+    [3.0, 0.2, 0.1],
+    [0.2, 0.5, 2.0],
+    [0.05, 0.1, 4.0],
+    [2.0, 0.2, 1.5],
+    [0.1, 1.3, 0.2],
+    [0.05, 0.6, 1.2],
+];
+
+/// The Oklch hue of linear light RGB, which can be overbright
+fn hue(rgb: [f32; 3]) -> f32 {
+    let [r, g, b] = rgb.map(f64::from);
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+    let a = 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s;
+    let b = 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s;
+    b.atan2(a).to_degrees().rem_euclid(360.0) as f32
+}
+
+fn hue_shift(a: f32, b: f32) -> f32 {
+    let shift = (a - b).abs();
+    shift.min(360.0 - shift)
+}
+
+fn check_hue(rgb: [f32; 3], colour: Colour) {
+    let [_, chroma, output] = colour.to_oklch();
+    assert!(chroma > 0.02, "{rgb:?} became grey {colour}");
+    let shift = hue_shift(hue(rgb), output);
+    assert!(shift < 2.0, "{rgb:?} became {colour}, hue shifted {shift}");
+}
+
+#[test]
+fn clipping_preserves_hue() {
+    for rgb in OVERBRIGHT {
+        check_hue(rgb, Colour::from_linear_clipped_preserve_hue(rgb));
+    }
+}
+
+#[test]
+fn clamping_shifts_hue() {
+    let shifted = OVERBRIGHT
+        .iter()
+        .filter(|&&rgb| hue_shift(hue(rgb), Colour::from_linear(rgb).to_oklch()[2]) > 2.0)
+        .count();
+    assert!(shifted >= OVERBRIGHT.len() / 2);
+}
+
+#[test]
+fn reinhard_preserves_hue() {
+    for rgb in OVERBRIGHT {
+        check_hue(rgb, Colour::from_linear_reinhard(rgb));
+        let brighter = rgb.map(|c| c * 4.0);
+        check_hue(brighter, Colour::from_linear_reinhard(brighter));
+    }
+}
+
+#[test]
+fn in_gamut_is_unchanged() {
+    for colour in colours(7919) {
+        let clipped = Colour::from_linear_clipped_preserve_hue(colour.to_linear());
+        assert_eq!(colour, clipped);
+    }
+}
+
+#[test]
+fn overbright_clips_towards_white() {
+    assert_eq!(
+        Colour::WHITE,
+        Colour::from_linear_clipped_preserve_hue([2.0; 3])
+    );
+    assert_eq!(
+        Colour::WHITE,
+        Colour::from_linear_clipped_preserve_hue([50.0, 40.0, 1.0])
+    );
+
+    let mut last = Colour::from_linear_clipped_preserve_hue([1.2, 0.2, 0.1]);
+    for scale in [1.5, 2.0, 4.0, 8.0] {
+        let next =
+            Colour::from_linear_clipped_preserve_hue([1.2 * scale, 0.2 * scale, 0.1 * scale]);
+        assert!(next.to_oklch()[0] >= last.to_oklch()[0], "{last} {next}");
+        assert!(next.to_oklch()[1] <= last.to_oklch()[1], "{last} {next}");
+        last = next;
+    }
+}
+
+#[test]
+fn reinhard_greys() {
+    assert_eq!(Colour::BLACK, Colour::from_linear_reinhard([0.0; 3]));
+    let mut last = Colour::BLACK;
+    for value in [0.1, 0.5, 1.0, 4.0, 100.0, 1e6] {
+        let grey = Colour::from_linear_reinhard([value; 3]);
+        assert_eq!(grey.red(), grey.green());
+        assert_eq!(grey.green(), grey.blue());
+        assert!(grey.red() >= last.red());
+        last = grey;
+    }
+}
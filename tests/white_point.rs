@@ -0,0 +1,13 @@
+mod common;
+
+use chrom::WhitePoint;
+use common::colours;
+
+#[test]
+fn adapting_to_the_same_white_point_does_nothing() {
+    for colour in colours(9973) {
+        for white in [WhitePoint::D65, WhitePoint::D50, WhitePoint::A] {
+            assert_eq!(colour, colour.adapt_white_point(white, white), "{colour}");
+        }
+    }
+}